@@ -0,0 +1,55 @@
+//! Provides the assets embedded by the `bundled-assets` feature.
+//!
+//! The files are copied from the directory in `FOMANTIC_UI_ASSETS_DIR`,
+//! nothing is downloaded. On docs.rs, empty files are used instead.
+use std::{
+    env,
+    fs,
+    path::PathBuf,
+};
+
+const FOMANTIC_VERSION: &str = "2.9.3";
+const JQUERY_VERSION: &str = "3.7.1";
+
+/// Environment variable naming a directory that contains the assets.
+const ASSETS_DIR: &str = "FOMANTIC_UI_ASSETS_DIR";
+
+/// The embedded files.
+const ASSETS: &[&str] =
+    &["jquery.min.js", "semantic.min.css", "semantic.min.js"];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={ASSETS_DIR}");
+    if env::var_os("CARGO_FEATURE_BUNDLED_ASSETS").is_none() {
+        return;
+    }
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    // the documentation does not need the contents
+    if env::var_os("DOCS_RS").is_some() {
+        for name in ASSETS {
+            fs::write(out_dir.join(name), "").unwrap();
+        }
+        return;
+    }
+    let Some(source) = env::var_os(ASSETS_DIR).map(PathBuf::from) else {
+        panic!(
+            "The bundled-assets feature requires {ASSETS_DIR} to be set to a \
+             directory containing jquery.min.js {JQUERY_VERSION}, \
+             semantic.min.css and semantic.min.js {FOMANTIC_VERSION}."
+        );
+    };
+    for name in ASSETS {
+        let file = source.join(name);
+        println!("cargo:rerun-if-changed={}", file.display());
+        if let Err(e) = fs::copy(&file, out_dir.join(name)) {
+            panic!(
+                "The bundled-assets feature could not read {}: \
+                 {e}\n{ASSETS_DIR} has to contain jquery.min.js \
+                 {JQUERY_VERSION}, semantic.min.css and semantic.min.js \
+                 {FOMANTIC_VERSION}.",
+                file.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,82 @@
+#!/usr/bin/env python3
+"""Generates src/style/icon_name.rs from the icons of the fomantic-ui CSS.
+
+Usage: scripts/gen_icon_names.py [assets/semantic.min.css]
+
+Every `i.icon.<name>:before` rule of the stylesheet becomes a variant of
+`IconName`, eg. `i.icon.check.square.outline:before` becomes
+`CheckSquareOutline` displayed as `check square outline icon`.
+"""
+import os
+import re
+import sys
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+CSS = os.path.join(ROOT, "assets", "semantic.min.css")
+OUTPUT = os.path.join(ROOT, "src", "style", "icon_name.rs")
+
+
+def variant(name):
+    words = re.split(r"[ \-]", name)
+    ident = "".join(w[:1].upper() + w[1:] for w in words)
+    return "N" + ident if ident[0].isdigit() else ident
+
+
+def main():
+    css = open(sys.argv[1] if len(sys.argv) > 1 else CSS).read()
+    names = sorted(
+        {
+            " ".join(m.split("."))
+            for m in re.findall(r"i\.icon\.([a-z0-9.\-]+):before", css)
+        }
+    )
+    out = [
+        "// Generated by scripts/gen_icon_names.py, do not edit.",
+        "",
+        "/// Icons of the `fomantic-ui` icon set.",
+        "///",
+        "/// Displayed as the class string of the icon, eg. `check circle "
+        "icon`.",
+        "#[derive(Clone, Copy, PartialEq, Eq, Debug)]",
+        "pub enum IconName {",
+    ]
+    for name in names:
+        out.append(f"    /// `{name}`.")
+        out.append(f"    {variant(name)},")
+    out += [
+        "    /// An icon that is not listed, eg. of a custom icon font.",
+        "    Custom(&'static str),",
+        "}",
+        "",
+        "impl IconName {",
+        "    /// Returns the name of the icon without the `icon` class.",
+        "    pub fn name(&self) -> &'static str {",
+        "        match *self {",
+    ]
+    for name in names:
+        out.append(f'            Self::{variant(name)} => "{name}",')
+    out += [
+        "            Self::Custom(name) => name,",
+        "        }",
+        "    }",
+        "}",
+        "",
+        "impl std::fmt::Display for IconName {",
+        "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {",
+        '        write!(f, "{} icon", self.name())',
+        "    }",
+        "}",
+        "",
+        "impl From<&'static str> for IconName {",
+        "    fn from(name: &'static str) -> Self {",
+        "        Self::Custom(name)",
+        "    }",
+        "}",
+        "",
+    ]
+    with open(OUTPUT, "w") as f:
+        f.write("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
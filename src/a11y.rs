@@ -0,0 +1,288 @@
+//! Accessibility helpers shared by the modules and components.
+//!
+//! - [FocusTrap] keeps the keyboard focus inside a modal while it is shown.
+//! - [Aria] holds the labelling attributes of generated markup, eg. of
+//!   dropdowns, tables and toast containers.
+//! - [set_reduced_motion] shortens or disables the animations of all
+//!   modules, eg. for users that prefer reduced motion.
+use crate::{
+    jq::JQuery,
+    FomanticError,
+};
+use gloo::events::{
+    EventListener,
+    EventListenerOptions,
+};
+use std::cell::Cell;
+use wasm_bindgen::JsCast;
+
+/// Selector of the elements that can receive the keyboard focus.
+pub const FOCUSABLE: &str =
+    "a[href], area[href], button:not([disabled]), \
+     input:not([disabled]):not([type=hidden]), select:not([disabled]), \
+     textarea:not([disabled]), iframe, [contenteditable], \
+     [tabindex]:not([tabindex='-1'])";
+
+/// Returns the visible elements within the container that can receive the
+/// keyboard focus, in document order.
+fn focusable(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(elements) = container.query_selector_all(FOCUSABLE) else {
+        return vec![];
+    };
+    (0..elements.length())
+        .filter_map(|i| elements.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        // hidden elements have no offset parent
+        .filter(|element| element.offset_parent().is_some())
+        .collect()
+}
+
+/// Keeps the keyboard focus within an element, so `Tab` and `Shift+Tab`
+/// cycle through its focusable elements instead of leaving it.
+///
+/// The focus is trapped until the trap is dropped.
+pub struct FocusTrap {
+    _listener: EventListener,
+    restore: Option<web_sys::HtmlElement>,
+}
+
+impl FocusTrap {
+    /// Traps the focus within the container and focuses its first focusable
+    /// element.
+    ///
+    /// The previously focused element is focused again when the trap is
+    /// dropped.
+    pub fn new(container: &web_sys::Element) -> Self {
+        let restore = gloo::utils::document()
+            .active_element()
+            .and_then(|element| element.dyn_into().ok());
+        if let Some(first) = focusable(container).first() {
+            let _ = first.focus();
+        }
+        Self {
+            _listener: Self::listen(container.clone(), || true),
+            restore,
+        }
+    }
+
+    /// Traps the focus within the container while `active` returns `true`,
+    /// without moving or restoring the focus.
+    ///
+    /// Used for modules that focus and restore on their own, eg. modals.
+    pub fn while_active(
+        container: &web_sys::Element,
+        active: impl Fn() -> bool + 'static,
+    ) -> Self {
+        Self {
+            _listener: Self::listen(container.clone(), active),
+            restore: None,
+        }
+    }
+
+    /// Moves the focus to the other end of the container when it would
+    /// leave it.
+    fn listen(
+        container: web_sys::Element,
+        active: impl Fn() -> bool + 'static,
+    ) -> EventListener {
+        EventListener::new_with_options(
+            &gloo::utils::document(),
+            "keydown",
+            EventListenerOptions::enable_prevent_default(),
+            move |event| {
+                let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>()
+                else {
+                    return;
+                };
+                if event.key() != "Tab" || !active() {
+                    return;
+                }
+                let elements = focusable(&container);
+                let (Some(first), Some(last)) =
+                    (elements.first(), elements.last())
+                else {
+                    event.prevent_default();
+                    return;
+                };
+                let current = gloo::utils::document().active_element();
+                let inside = container.contains(current.as_deref());
+                let current = current.as_deref();
+                let target = if event.shift_key() {
+                    (!inside || current == Some(first.as_ref())).then_some(last)
+                } else {
+                    (!inside || current == Some(last.as_ref())).then_some(first)
+                };
+                if let Some(target) = target {
+                    event.prevent_default();
+                    let _ = target.focus();
+                }
+            },
+        )
+    }
+}
+
+impl Drop for FocusTrap {
+    fn drop(&mut self) {
+        if let Some(element) = &self.restore {
+            let _ = element.focus();
+        }
+    }
+}
+
+/// ARIA attributes labelling an element for assistive technologies.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Aria {
+    /// Text labelling the element, `aria-label`.
+    pub label: Option<String>,
+    /// Id of the element labelling the element, `aria-labelledby`.
+    pub labelled_by: Option<String>,
+    /// Id of the element describing the element, `aria-describedby`.
+    pub described_by: Option<String>,
+}
+
+impl Aria {
+    /// Labels the element with the given text.
+    pub fn label(label: &str) -> Self {
+        Self {
+            label: Some(label.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Labels the element with the element of the given id.
+    pub fn labelled_by(id: &str) -> Self {
+        Self {
+            labelled_by: Some(id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Describes the element with the element of the given id.
+    pub fn described_by(mut self, id: &str) -> Self {
+        self.described_by = Some(id.to_string());
+        self
+    }
+
+    /// Sets the attributes on the elements of the selection.
+    pub fn apply(&self, selection: &JQuery) {
+        for (attribute, value) in [
+            ("aria-label", &self.label),
+            ("aria-labelledby", &self.labelled_by),
+            ("aria-describedby", &self.described_by),
+        ] {
+            if let Some(value) = value {
+                selection.attr(attribute, value);
+            }
+        }
+    }
+}
+
+impl From<&str> for Aria {
+    fn from(label: &str) -> Self {
+        Self::label(label)
+    }
+}
+
+/// How the animations of the modules are played, see [set_reduced_motion].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ReducedMotion {
+    /// Plays the animations as configured.
+    #[default]
+    Off,
+    /// Shortens the animations to [SHORTENED_DURATION].
+    Shorten,
+    /// Skips the animations.
+    Disable,
+    /// Shortens the animations if the system prefers reduced motion.
+    System,
+}
+
+/// Duration of the animations in milliseconds with
+/// [ReducedMotion::Shorten].
+pub const SHORTENED_DURATION: u32 = 100;
+
+/// Media query matching if the system prefers reduced motion.
+const PREFERS_REDUCED_MOTION: &str = "(prefers-reduced-motion: reduce)";
+
+/// Id of the style element overriding the durations of the animations.
+const STYLE_ID: &str = "fomantic-ui-reduced-motion";
+
+thread_local! {
+    /// The setting of [set_reduced_motion].
+    static REDUCED_MOTION: Cell<ReducedMotion> =
+        const { Cell::new(ReducedMotion::Off) };
+}
+
+/// Whether the system prefers reduced motion.
+pub fn prefers_reduced_motion() -> bool {
+    matches!(
+        gloo::utils::window().match_media(PREFERS_REDUCED_MOTION),
+        Ok(Some(media)) if media.matches()
+    )
+}
+
+/// Shortens or disables the animations of all modules.
+///
+/// The durations of the CSS animations used by the transitions are
+/// overridden on the page, which applies to every module. The leptos
+/// components additionally pass the reduced duration to the modules, see
+/// [transition_duration].
+pub fn set_reduced_motion(
+    reduced_motion: ReducedMotion,
+) -> Result<(), FomanticError> {
+    REDUCED_MOTION.set(reduced_motion);
+    let document = gloo::utils::document();
+    let style = match document.get_element_by_id(STYLE_ID) {
+        Some(style) => style,
+        None => {
+            let style = document
+                .create_element("style")
+                .map_err(FomanticError::from_js)?;
+            style.set_id(STYLE_ID);
+            document
+                .head()
+                .ok_or_else(|| FomanticError::Js("No head element".into()))?
+                .append_child(&style)
+                .map_err(FomanticError::from_js)?;
+            style
+        }
+    };
+    // the animations still have to end, so the modules finish showing
+    let rule = |duration: u32| {
+        format!(
+            ".ui.animating.transition {{ animation-duration: {duration}ms \
+             !important; }}"
+        )
+    };
+    let css = match reduced_motion {
+        ReducedMotion::Off => String::new(),
+        ReducedMotion::Shorten => rule(SHORTENED_DURATION),
+        ReducedMotion::Disable => rule(1),
+        ReducedMotion::System => format!(
+            "@media {PREFERS_REDUCED_MOTION} {{ {} }}",
+            rule(SHORTENED_DURATION)
+        ),
+    };
+    style.set_text_content(Some(&css));
+    Ok(())
+}
+
+/// Returns the setting of [set_reduced_motion].
+pub fn reduced_motion() -> ReducedMotion {
+    REDUCED_MOTION.get()
+}
+
+/// Returns the duration in milliseconds to pass to a module instead of the
+/// given one, following [set_reduced_motion].
+pub fn transition_duration(duration: Option<u32>) -> Option<u32> {
+    let shortened = || {
+        Some(duration.map_or(SHORTENED_DURATION, |d| d.min(SHORTENED_DURATION)))
+    };
+    match REDUCED_MOTION.get() {
+        ReducedMotion::Off => duration,
+        ReducedMotion::Shorten => shortened(),
+        ReducedMotion::Disable => Some(0),
+        ReducedMotion::System if prefers_reduced_motion() => shortened(),
+        ReducedMotion::System => duration,
+    }
+}
@@ -1,10 +1,144 @@
 /// Configuration for a Action.
+use crate::{
+    jq::JQuery,
+    macros::module_config,
+    style::{
+        Classes,
+        Color,
+        IconName,
+        Size,
+    },
+    Key,
+};
+use std::{
+    cell::{
+        Cell,
+        OnceCell,
+        RefCell,
+    },
+    future::Future,
+    rc::Rc,
+};
 use wasm_bindgen::prelude::*;
 
+/// The kind of an [Action].
+///
+/// The kind applies the classes `fomantic-ui` uses to route clicks to the
+/// `onApprove` and `onDeny` callbacks of eg. a [Modal](crate::modules::modal::Modal).
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum ActionKind {
+    /// A plain action that is neither approving nor denying.
+    #[default]
+    Neutral,
+    /// A positive action that triggers `onApprove`.
+    Approve,
+    /// A negative action that triggers `onDeny`.
+    Deny,
+    /// An acknowledging action that triggers `onApprove`.
+    Ok,
+    /// A cancelling action that triggers `onDeny`.
+    Cancel,
+}
+
+impl ActionKind {
+    /// Whether the action triggers the `onApprove` callback.
+    pub fn is_approving(&self) -> bool {
+        matches!(self, Self::Approve | Self::Ok)
+    }
+
+    /// Whether the action triggers the `onDeny` callback.
+    pub fn is_denying(&self) -> bool {
+        matches!(self, Self::Deny | Self::Cancel)
+    }
+}
+
+impl std::fmt::Display for ActionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Neutral => write!(f, ""),
+            Self::Approve => write!(f, "positive approve"),
+            Self::Deny => write!(f, "negative deny"),
+            Self::Ok => write!(f, "positive ok"),
+            Self::Cancel => write!(f, "negative cancel"),
+        }
+    }
+}
+
+/// Escapes the text for use in html.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// An item of a dropdown [Action].
+pub struct ActionItem {
+    text: String,
+    icon: Option<IconName>,
+    click: Box<dyn FnMut() -> bool>,
+}
+
+impl ActionItem {
+    /// Creates a new item with the given text, which is shown as is, not as
+    /// html.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            icon: None,
+            click: Box::new(|| true),
+        }
+    }
+
+    /// Sets the icon of the item.
+    pub fn with_icon(mut self, icon: impl Into<IconName>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the handler that is fired when the item is chosen. If the handler
+    /// returns `true`, the modal containing the action is closed.
+    pub fn click<H: FnMut() -> bool + 'static>(mut self, click: H) -> Self {
+        self.click = Box::new(click);
+        self
+    }
+
+    /// The markup of the item, `idx` is used to identify it on click.
+    fn markup(&self, idx: usize) -> String {
+        let icon = self
+            .icon
+            .as_ref()
+            .map(|i| format!("<i class=\"{i}\"></i>"))
+            .unwrap_or_default();
+        format!(
+            "<div class=\"item\" data-value=\"{idx}\">{icon}{}</div>",
+            escape_html(&self.text)
+        )
+    }
+}
+
 /// Defines an action that can be used in eg. [Modal](crate::modules::modal::Modal) or [Toast](crate::modules::toast::Toast).
 pub struct Action {
-    pub(crate) click: Closure<dyn Fn() -> bool>,
+    pub(crate) click: Closure<dyn FnMut() -> bool>,
     pub(crate) js_config: JsActionConfig,
+    pub(crate) shortcut: Option<Key>,
+    pub(crate) icon: Option<IconName>,
+    pub(crate) handle: Rc<RefCell<Option<ActionHandle>>>,
+    show_loading: Rc<Cell<bool>>,
+    text: String,
+    items: Rc<RefCell<Vec<ActionItem>>>,
+    kind: ActionKind,
+    color: Option<Color>,
+    size: Option<Size>,
+    class: Option<String>,
 }
 
 impl Action {
@@ -12,33 +146,360 @@ impl Action {
     pub fn new() -> Self {
         let js_config = JsActionConfig::new();
         let click = Closure::new(|| true);
-        Self { js_config, click }
+        Self {
+            js_config,
+            click,
+            shortcut: None,
+            icon: None,
+            handle: Rc::new(RefCell::new(None)),
+            show_loading: Rc::new(Cell::new(true)),
+            text: String::new(),
+            items: Rc::new(RefCell::new(vec![])),
+            kind: ActionKind::default(),
+            color: None,
+            size: None,
+            class: None,
+        }
     }
 
     /// Sets the text shown on the action.
-    pub fn with_text(self, text: &str) -> Self {
-        self.js_config.set_text(text);
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self.update_text();
         self
     }
 
+    /// Turns the action into a dropdown that offers the given items.
+    ///
+    /// Clicking the action opens the menu, choosing an item fires its handler.
+    /// Dropdown actions are available in [Modal](crate::modules::modal::Modal)s.
+    pub fn with_items(self, items: Vec<ActionItem>) -> Self {
+        *self.items.borrow_mut() = items;
+        self.update_text();
+        self.update_class();
+        let handle = Rc::clone(&self.handle);
+        let items = Rc::clone(&self.items);
+        let mut item_listener: Option<Closure<dyn FnMut(web_sys::Event)>> =
+            None;
+        self.click(move || {
+            let Some(handle) = handle.borrow().clone() else {
+                return false;
+            };
+            if item_listener.is_none() {
+                let items = Rc::clone(&items);
+                let item_handle = handle.clone();
+                let listener = Closure::new(move |event: web_sys::Event| {
+                    let idx = event
+                        .current_target()
+                        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+                        .and_then(|e| e.get_attribute("data-value"))
+                        .and_then(|v| v.parse::<usize>().ok());
+                    let close = idx
+                        .and_then(|idx| {
+                            items
+                                .borrow_mut()
+                                .get_mut(idx)
+                                .map(|item| (item.click)())
+                        })
+                        .unwrap_or(false);
+                    if close {
+                        item_handle.close();
+                    }
+                });
+                handle.init_dropdown(&listener);
+                item_listener = Some(listener);
+            }
+            false
+        })
+    }
+
+    /// Composes the text of the action and passes it to JavaScript.
+    fn update_text(&self) {
+        let items = self.items.borrow();
+        if items.is_empty() {
+            self.js_config.set_text(&self.text);
+            return;
+        }
+        let menu = items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| item.markup(idx))
+            .collect::<String>();
+        self.js_config.set_text(&format!(
+            "{} <i class=\"dropdown icon\"></i><div \
+             class=\"menu\">{menu}</div>",
+            escape_html(&self.text)
+        ));
+    }
+
     /// Sets the CSS class name of the action.
-    pub fn with_class(self, class: &str) -> Self {
-        self.js_config.set_class(class);
+    ///
+    /// The class is appended to the classes derived from the [ActionKind],
+    /// [Color] and [Size].
+    pub fn with_class(mut self, class: impl Into<Classes>) -> Self {
+        self.class = Some(class.into().to_string());
+        self.update_class();
+        self
+    }
+
+    /// Sets the kind of the action.
+    pub fn with_kind(mut self, kind: ActionKind) -> Self {
+        self.kind = kind;
+        self.update_class();
+        self
+    }
+
+    /// Sets the color of the action.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self.update_class();
+        self
+    }
+
+    /// Sets the size of the action.
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self.update_class();
         self
     }
 
+    /// Returns the kind of the action.
+    pub fn kind(&self) -> ActionKind {
+        self.kind
+    }
+
+    /// Adds a class to the button of the action, eg. to find it on the page.
+    #[cfg(feature = "toast")]
+    pub(crate) fn add_class(&mut self, class: &str) {
+        self.class = Some(match self.class.take() {
+            Some(classes) => format!("{classes} {class}"),
+            None => class.to_string(),
+        });
+        self.update_class();
+    }
+
+    /// Composes the class string of the action and passes it to JavaScript.
+    fn update_class(&self) {
+        let class = [
+            Some(self.kind.to_string()),
+            self.color.map(|c| c.to_string()),
+            self.size.map(|s| s.to_string()),
+            (!self.items.borrow().is_empty())
+                .then(|| "floating dropdown".to_string()),
+            self.class.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|c| !c.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+        self.js_config.set_class(&class);
+    }
+
     /// Sets the icon of the action.
-    pub fn with_icon(self, icon: &str) -> Self {
-        self.js_config.set_icon(icon);
+    pub fn with_icon(mut self, icon: impl Into<IconName>) -> Self {
+        let icon = icon.into();
+        self.js_config.set_icon(icon.name());
+        self.icon = Some(icon);
         self
     }
 
+    /// Sets a keyboard shortcut that triggers the action.
+    ///
+    /// The shortcut is only active while the modal or toast containing the
+    /// action is shown.
+    pub fn with_shortcut(mut self, key: Key) -> Self {
+        self.shortcut = Some(key);
+        self
+    }
+
+    /// Whether the action shows a loading state while an async handler is
+    /// pending. Defaults to `true`.
+    pub fn show_loading(self, show_loading: bool) -> Self {
+        self.show_loading.set(show_loading);
+        self
+    }
+
+    /// Sets an async handler that is fired on click.
+    ///
+    /// When used in a [Modal](crate::modules::modal::Modal), the modal stays
+    /// open while the returned future is pending and is closed once it resolves
+    /// to `true`. In the meantime, the action is disabled and shows a loading
+    /// state, see [Action::show_loading].
+    ///
+    /// Actions that are not part of a modal close immediately.
+    pub fn click_async<H, F>(self, mut click: H) -> Self
+    where
+        H: FnMut() -> F + 'static,
+        F: Future<Output = bool> + 'static,
+    {
+        let handle = Rc::clone(&self.handle);
+        let show_loading = Rc::clone(&self.show_loading);
+        let pending = Rc::new(Cell::new(false));
+        self.click(move || {
+            let Some(handle) = handle.borrow().clone() else {
+                let future = click();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let _ = future.await;
+                });
+                return true;
+            };
+            if pending.replace(true) {
+                return false;
+            }
+            let future = click();
+            let show_loading = show_loading.get();
+            let pending = Rc::clone(&pending);
+            if show_loading {
+                handle.set_loading(true);
+            }
+            wasm_bindgen_futures::spawn_local(async move {
+                let close = future.await;
+                if show_loading {
+                    handle.set_loading(false);
+                }
+                pending.set(false);
+                if close {
+                    handle.close();
+                }
+            });
+            false
+        })
+    }
+
     /// Sets the handler that is fired on click.
-    pub fn click<H: Fn() -> bool + 'static>(mut self, click: H) -> Self {
+    pub fn click<H: FnMut() -> bool + 'static>(mut self, click: H) -> Self {
         self.click = Closure::new(click);
         self.js_config.set_click(&self.click);
         self
     }
+
+    /// Sets a handler that is fired only on the first click.
+    ///
+    /// Useful for handlers that consume their captured values, eg. sending
+    /// on a oneshot channel. Further clicks, eg. while the modal is kept open,
+    /// are ignored and do not close it.
+    pub fn click_once<H: FnOnce() -> bool + 'static>(self, click: H) -> Self {
+        let mut click = Some(click);
+        self.click(move || click.take().is_some_and(|click| click()))
+    }
+}
+
+impl Default for Action {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A handle to the button of an [Action] that has been added to a [Modal](crate::modules::modal::Modal).
+///
+/// The handle can be used to change the action after the modal is shown.
+/// Changes before the modal has been created are ignored.
+#[derive(Clone)]
+pub struct ActionHandle {
+    root: Rc<OnceCell<JQuery>>,
+    index: usize,
+    icon: Option<IconName>,
+}
+
+impl ActionHandle {
+    /// Creates a handle to the action at `index` inside the element that is
+    /// set in `root` once it has been created.
+    #[cfg(feature = "modal")]
+    pub(crate) fn new(
+        root: Rc<OnceCell<JQuery>>,
+        index: usize,
+        icon: Option<IconName>,
+    ) -> Self {
+        Self { root, index, icon }
+    }
+
+    /// Queries the button element of the action, `None` until the modal has
+    /// been created.
+    fn button(&self) -> Option<JQuery> {
+        let root = self.root.get()?;
+        Some(root.find(".actions > .button").eq(self.index as i32))
+    }
+
+    /// Enables the action.
+    pub fn enable(&self) {
+        self.set_enabled(true);
+    }
+
+    /// Disables the action, so it can not be clicked.
+    pub fn disable(&self) {
+        self.set_enabled(false);
+    }
+
+    /// Sets whether the action can be clicked.
+    ///
+    /// An action that is loading stays disabled until the loading state is
+    /// removed.
+    pub fn set_enabled(&self, enabled: bool) {
+        let Some(button) = self.button() else {
+            return;
+        };
+        button.toggle_class("disabled", !enabled);
+        button.prop("disabled", !enabled || button.has_class("loading"));
+    }
+
+    /// Returns whether the action can be clicked.
+    pub fn is_enabled(&self) -> bool {
+        self.button()
+            .is_none_or(|button| !button.has_class("disabled"))
+    }
+
+    /// Shows or removes the loading state of the action.
+    ///
+    /// While loading, the action is disabled so it can not be clicked again.
+    /// Afterwards it is only enabled again if it has not been disabled by
+    /// [ActionHandle::set_enabled].
+    pub fn set_loading(&self, loading: bool) {
+        let Some(button) = self.button() else {
+            return;
+        };
+        button.toggle_class("loading", loading);
+        button.prop("disabled", loading || button.has_class("disabled"));
+    }
+
+    /// Initializes the dropdown of the action and opens it.
+    fn init_dropdown(
+        &self,
+        item_listener: &Closure<dyn FnMut(web_sys::Event)>,
+    ) {
+        let Some(button) = self.button() else {
+            return;
+        };
+        button.on("click", ".menu > .item", item_listener);
+        let config = DropdownActionConfig::new();
+        config.action("hide");
+        button.init("dropdown", config.as_ref());
+        button.behavior("dropdown", "show");
+    }
+
+    /// Returns whether the action shows the loading state.
+    pub fn is_loading(&self) -> bool {
+        self.button()
+            .is_some_and(|button| button.has_class("loading"))
+    }
+
+    /// Hides the modal that contains the action.
+    fn close(&self) {
+        if let Some(root) = self.root.get() {
+            root.behavior("modal", "hide");
+        }
+    }
+
+    /// Changes the text shown on the action.
+    pub fn set_text(&self, text: &str) {
+        let Some(button) = self.button() else {
+            return;
+        };
+        button.text(text);
+        if let Some(icon) = self.icon {
+            button.prepend(&format!("<i class=\"{icon}\"></i>"));
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -66,6 +527,32 @@ extern "C" {
 
     /// Set the click handler.
     #[wasm_bindgen(method, setter, js_name = "click")]
-    pub(crate) fn set_click(this: &JsActionConfig, click: &Closure<dyn Fn() -> bool>);
+    pub(crate) fn set_click(
+        this: &JsActionConfig,
+        click: &Closure<dyn FnMut() -> bool>,
+    );
+}
 
+module_config! {
+    /// Settings of the dropdown of an action.
+    struct DropdownActionConfig(JsDropdownActionConfig) {
+        /// Set the action that is performed when an item is chosen.
+        action: &str = "action",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape_html;
+
+    #[test]
+    fn escapes_html_special_characters() {
+        assert_eq!(escape_html("Save & close"), "Save &amp; close");
+        assert_eq!(
+            escape_html("<b class=\"x\">it's</b>"),
+            "&lt;b class=&quot;x&quot;&gt;it&#39;s&lt;/b&gt;"
+        );
+        assert_eq!(escape_html("Ünïcode ✓"), "Ünïcode ✓");
+        assert_eq!(escape_html(""), "");
+    }
 }
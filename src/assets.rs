@@ -0,0 +1,47 @@
+//! The `fomantic-ui` and jQuery assets embedded into the binary.
+//!
+//! The build script copies `fomantic-ui` 2.9.3 and jQuery 3.7.1 from the
+//! directory in the `FOMANTIC_UI_ASSETS_DIR` environment variable, which has
+//! to be set when the feature is enabled. The CSS refers to the icon fonts
+//! relatively, so they still have to be served at
+//! `themes/default/assets/fonts` when icons are used.
+use wasm_bindgen::prelude::*;
+
+/// The minified jQuery script.
+pub const JQUERY_JS: &str =
+    include_str!(concat!(env!("OUT_DIR"), "/jquery.min.js"));
+
+/// The minified `fomantic-ui` stylesheet.
+pub const FOMANTIC_CSS: &str =
+    include_str!(concat!(env!("OUT_DIR"), "/semantic.min.css"));
+
+/// The minified `fomantic-ui` script.
+pub const FOMANTIC_JS: &str =
+    include_str!(concat!(env!("OUT_DIR"), "/semantic.min.js"));
+
+/// Id of the element holding the embedded stylesheet, used to inject the
+/// assets only once.
+const STYLE_ID: &str = "fomantic-ui-bundled-css";
+
+/// Appends the embedded stylesheet and scripts to the `<head>` of the
+/// document, so no CDN tags are required.
+///
+/// The scripts are executed synchronously, so the modules can be used
+/// right after this function returns. Calling it again has no effect.
+pub fn inject_assets() -> Result<(), JsValue> {
+    let document = gloo::utils::document();
+    if document.get_element_by_id(STYLE_ID).is_some() {
+        return Ok(());
+    }
+    let head = gloo::utils::head();
+    let style = document.create_element("style")?;
+    style.set_id(STYLE_ID);
+    style.set_text_content(Some(FOMANTIC_CSS));
+    head.append_child(&style)?;
+    for source in [JQUERY_JS, FOMANTIC_JS] {
+        let script = document.create_element("script")?;
+        script.set_text_content(Some(source));
+        head.append_child(&script)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,218 @@
+//! The JavaScript library the bindings are driving.
+//!
+//! By default, the modules are driven through jQuery, the way `fomantic-ui`
+//! 2.9 exposes them. A [Backend] installed with [set_backend] replaces
+//! jQuery for all selections and modules created afterwards on the current
+//! thread, eg. to target a build of `fomantic-ui` without jQuery. The
+//! public API of the modules stays the same.
+//!
+//! ```no_run
+//! use fomantic_ui::{
+//!     backend::{
+//!         self,
+//!         Backend,
+//!         Selection,
+//!     },
+//!     jq::JQuery,
+//!     FomanticError,
+//! };
+//! use wasm_bindgen::JsValue;
+//!
+//! struct Vanilla;
+//!
+//! impl Backend for Vanilla {
+//!     fn select(&self, selector: &str) -> Result<JQuery, FomanticError> {
+//!         // eg. wrap document.querySelectorAll in a Selection
+//!         # unimplemented!()
+//!     }
+//!
+//!     fn wrap(
+//!         &self,
+//!         element: &web_sys::Element,
+//!     ) -> Result<JQuery, FomanticError> {
+//!         # unimplemented!()
+//!     }
+//!
+//!     fn invoke(
+//!         &self,
+//!         module: &str,
+//!         args: &[&JsValue],
+//!     ) -> Result<JQuery, FomanticError> {
+//!         // eg. call the module function of the vanilla API
+//!         # unimplemented!()
+//!     }
+//! }
+//!
+//! backend::set_backend(Vanilla);
+//! ```
+use crate::{
+    jq::JQuery,
+    FomanticError,
+};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+use wasm_bindgen::prelude::*;
+
+/// The operations the bindings perform on a selection of elements.
+///
+/// Implemented by the jQuery objects of the default backend. Selections of
+/// a custom backend are wrapped with [JQuery::from_selection].
+pub trait Selection: std::fmt::Debug {
+    /// The amount of elements in the selection.
+    fn length(&self) -> u32;
+
+    /// Finds the descendants matching the given selector.
+    fn find(&self, selector: &str) -> JQuery;
+
+    /// Returns the closest ancestor matching the given selector, starting
+    /// with the element itself.
+    fn closest(&self, selector: &str) -> JQuery;
+
+    /// Reduces the selection to the element at the given index.
+    fn eq(&self, index: i32) -> JQuery;
+
+    /// Returns the element at the given index.
+    fn element(&self, index: i32) -> Option<web_sys::Element>;
+
+    /// Sets an attribute of the elements.
+    fn attr(&self, name: &str, value: &str);
+
+    /// Adds or removes the given class.
+    fn toggle_class(&self, class: &str, state: bool);
+
+    /// Whether any of the elements has the given class.
+    fn has_class(&self, class: &str) -> bool;
+
+    /// Sets a boolean property of the elements, eg. `disabled`.
+    fn prop(&self, name: &str, value: bool);
+
+    /// Sets the text content of the elements.
+    fn text(&self, text: &str);
+
+    /// Inserts the given html at the beginning of the elements.
+    fn prepend(&self, html: &str);
+
+    /// Attaches data to the elements.
+    fn set_data(&self, key: &str, value: &JsValue);
+
+    /// Returns the data attached to the first element.
+    fn data(&self, key: &str) -> JsValue;
+
+    /// Triggers the given event on the elements.
+    fn trigger(&self, event: &str);
+
+    /// Attaches an event handler to the descendants matching the given
+    /// selector.
+    fn on(
+        &self,
+        event: &str,
+        selector: &str,
+        handler: &Closure<dyn FnMut(web_sys::Event)>,
+    );
+
+    /// Removes the elements from the DOM.
+    fn remove(&self);
+
+    /// Calls the method with the given name, eg. the plugin of a module.
+    fn call(
+        &self,
+        method: &str,
+        args: &[&JsValue],
+    ) -> Result<JsValue, FomanticError>;
+
+    /// Initializes the given module, eg. `dropdown`, with the settings.
+    fn init(
+        &self,
+        module: &str,
+        settings: &JsValue,
+    ) -> Result<(), FomanticError> {
+        self.call(module, &[settings]).map(|_| ())
+    }
+
+    /// Invokes a behavior of the given module, eg. `set selected`, with
+    /// the given arguments.
+    fn behavior(
+        &self,
+        module: &str,
+        behavior: &str,
+        args: &[&JsValue],
+    ) -> Result<JsValue, FomanticError> {
+        let behavior = JsValue::from_str(behavior);
+        let args = std::iter::once(&behavior)
+            .chain(args.iter().copied())
+            .collect::<Vec<_>>();
+        self.call(module, &args)
+    }
+}
+
+/// Creates the selections and module instances of the bindings, see the
+/// [module documentation](self).
+pub trait Backend {
+    /// Queries the elements matching the given selector.
+    fn select(&self, selector: &str) -> Result<JQuery, FomanticError>;
+
+    /// Wraps the given element.
+    fn wrap(&self, element: &web_sys::Element)
+        -> Result<JQuery, FomanticError>;
+
+    /// Calls the module function that creates elements, eg. `$.toast(..)`
+    /// or `$.modal('alert', ..)`, and returns the created element.
+    fn invoke(
+        &self,
+        module: &str,
+        args: &[&JsValue],
+    ) -> Result<JQuery, FomanticError>;
+
+    /// Whether the given module is available. Defaults to `true`.
+    fn has_module(&self, module: &str) -> bool {
+        let _ = module;
+        true
+    }
+
+    /// Deep merges the settings into the defaults of the module, see
+    /// [settings](crate::settings). Returns `false` if the module has no
+    /// defaults, the default.
+    fn extend_defaults(
+        &self,
+        module: &str,
+        settings: &JsValue,
+    ) -> Result<bool, FomanticError> {
+        let _ = (module, settings);
+        Ok(false)
+    }
+
+    /// Returns the defaults of the module, `None` if it has none, the
+    /// default.
+    fn module_defaults(
+        &self,
+        module: &str,
+    ) -> Result<Option<js_sys::Object>, FomanticError> {
+        let _ = module;
+        Ok(None)
+    }
+}
+
+thread_local! {
+    /// The backend installed by [set_backend].
+    static BACKEND: RefCell<Option<Rc<dyn Backend>>> =
+        const { RefCell::new(None) };
+}
+
+/// Replaces jQuery with the given backend on the current thread.
+///
+/// Existing selections keep the backend they have been created with.
+pub fn set_backend(backend: impl Backend + 'static) {
+    BACKEND.set(Some(Rc::new(backend)));
+}
+
+/// Removes the backend installed by [set_backend], using jQuery again.
+pub fn reset_backend() {
+    BACKEND.set(None);
+}
+
+/// Returns the backend installed by [set_backend].
+pub(crate) fn custom() -> Option<Rc<dyn Backend>> {
+    BACKEND.with_borrow(Clone::clone)
+}
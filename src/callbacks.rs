@@ -0,0 +1,135 @@
+//! Ownership of the closures passed to the JavaScript side of a module.
+//!
+//! `fomantic-ui` keeps a reference to every function passed in its settings.
+//! Replacing a [Closure] after initialization would drop the function the
+//! module still calls. A [CallbackRegistry] instead passes a stable function
+//! per event that dispatches to the current handler, so handlers can be
+//! replaced at any time.
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+use wasm_bindgen::prelude::*;
+
+/// A handler of a module event, receiving the first argument of the
+/// callback and returning its result.
+type Handler = Box<dyn FnMut(JsValue) -> JsValue>;
+
+/// The handler of an event and the function passed to JavaScript.
+struct Entry {
+    event: String,
+    handler: Rc<RefCell<Option<Handler>>>,
+    /// Created when first passed to JavaScript.
+    function: Option<Closure<dyn FnMut(JsValue) -> JsValue>>,
+}
+
+/// Calls the handler in the slot with the given argument.
+fn run(handler: &RefCell<Option<Handler>>, argument: JsValue) -> JsValue {
+    // taken out while running, so the handler can replace itself
+    let Some(mut current) = handler.borrow_mut().take() else {
+        return JsValue::UNDEFINED;
+    };
+    let result = current(argument);
+    handler.borrow_mut().get_or_insert(current);
+    result
+}
+
+/// Owns the closures of a module instance, by event name, eg. `onShow`.
+///
+/// All closures are dropped with the registry or on [CallbackRegistry::clear].
+#[derive(Default)]
+pub struct CallbackRegistry {
+    entries: RefCell<Vec<Entry>>,
+}
+
+impl CallbackRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the handler of the given event and returns the function to be
+    /// passed to JavaScript.
+    ///
+    /// The function stays the same when the handler is set again.
+    pub fn set<H>(&self, event: &str, handler: H) -> js_sys::Function
+    where
+        H: FnMut(JsValue) -> JsValue + 'static,
+    {
+        self.replace(event, handler);
+        self.function(event)
+            .expect("the event has been registered by replace")
+    }
+
+    /// Replaces the handler of the given event, registering it if missing.
+    ///
+    /// Returns `false` if the event has not been registered before, in
+    /// which case the function has yet to be passed to JavaScript.
+    pub fn replace<H>(&self, event: &str, handler: H) -> bool
+    where
+        H: FnMut(JsValue) -> JsValue + 'static,
+    {
+        let mut entries = self.entries.borrow_mut();
+        if let Some(entry) = entries.iter().find(|e| e.event == event) {
+            *entry.handler.borrow_mut() = Some(Box::new(handler));
+            return true;
+        }
+        entries.push(Entry {
+            event: event.to_string(),
+            handler: Rc::new(RefCell::new(Some(Box::new(handler)))),
+            function: None,
+        });
+        false
+    }
+
+    /// Returns the function of the given event.
+    ///
+    /// With the `mock` feature, a placeholder is returned while a
+    /// [MockBackend](crate::mock::MockBackend) is installed, use
+    /// [CallbackRegistry::dispatch] to call the handler.
+    pub fn function(&self, event: &str) -> Option<js_sys::Function> {
+        let mut entries = self.entries.borrow_mut();
+        let entry = entries.iter_mut().find(|e| e.event == event)?;
+        #[cfg(feature = "mock")]
+        if crate::mock::is_installed() {
+            return Some(JsValue::UNDEFINED.unchecked_into());
+        }
+        let function = entry.function.get_or_insert_with(|| {
+            let handler = Rc::clone(&entry.handler);
+            Closure::new(move |argument| run(&handler, argument))
+        });
+        Some(
+            function
+                .as_ref()
+                .unchecked_ref::<js_sys::Function>()
+                .clone(),
+        )
+    }
+
+    /// Calls the handler of the given event like the module would, eg. to
+    /// test the routing of the events.
+    ///
+    /// Returns `None` if no handler is registered for the event.
+    pub fn dispatch(&self, event: &str, argument: JsValue) -> Option<JsValue> {
+        let handler = self
+            .entries
+            .borrow()
+            .iter()
+            .find(|e| e.event == event)
+            .map(|e| Rc::clone(&e.handler))?;
+        Some(run(&handler, argument))
+    }
+
+    /// Returns whether a handler is registered for the given event.
+    pub fn contains(&self, event: &str) -> bool {
+        self.entries.borrow().iter().any(|e| e.event == event)
+    }
+
+    /// Drops all handlers and their functions.
+    ///
+    /// Must only be called once the module has been destroyed, as calling
+    /// a dropped function throws.
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
@@ -0,0 +1,93 @@
+//! Builders creating `fomantic-ui` markup with `web_sys`, for apps that do
+//! not use a framework.
+//!
+//! Every builder returns a handle to the created element, that has to be
+//! inserted into the page by the caller. Event handlers registered on a
+//! handle are removed when it is dropped.
+//!
+//! ```no_run
+//! use fomantic_ui::{
+//!     dom::Button,
+//!     style::{
+//!         Color,
+//!         IconName,
+//!     },
+//! };
+//!
+//! let mut button = Button::new("Save")
+//!     .color(Color::Green)
+//!     .icon(IconName::Save)
+//!     .build()
+//!     .unwrap();
+//! button.on_click(|| web_sys::console::log_1(&"saved".into()));
+//! button.mount(&gloo::utils::body()).unwrap();
+//! ```
+
+mod button;
+mod card;
+mod form_field;
+mod menu;
+
+pub use button::{
+    Button,
+    ButtonHandle,
+};
+pub use card::{
+    Card,
+    CardHandle,
+};
+pub use form_field::{
+    FormField,
+    FormFieldHandle,
+};
+pub use menu::{
+    Menu,
+    MenuHandle,
+};
+
+use crate::{
+    style::IconName,
+    FomanticError,
+};
+
+/// Creates an element with the given tag and class.
+fn create(tag: &str, class: &str) -> Result<web_sys::Element, FomanticError> {
+    let element = gloo::utils::document()
+        .create_element(tag)
+        .map_err(FomanticError::from_js)?;
+    if !class.is_empty() {
+        element.set_class_name(class);
+    }
+    Ok(element)
+}
+
+/// Creates an element with the given tag, class and text.
+fn create_text(
+    tag: &str,
+    class: &str,
+    text: &str,
+) -> Result<web_sys::Element, FomanticError> {
+    let element = create(tag, class)?;
+    element.set_text_content(Some(text));
+    Ok(element)
+}
+
+/// Creates an icon element.
+fn create_icon(icon: IconName) -> Result<web_sys::Element, FomanticError> {
+    let element = create("i", &icon.to_string())?;
+    element
+        .set_attribute("aria-hidden", "true")
+        .map_err(FomanticError::from_js)?;
+    Ok(element)
+}
+
+/// Appends the child to the element.
+fn append(
+    element: &web_sys::Element,
+    child: &web_sys::Element,
+) -> Result<(), FomanticError> {
+    element
+        .append_child(child)
+        .map(|_| ())
+        .map_err(FomanticError::from_js)
+}
@@ -0,0 +1,150 @@
+use super::{
+    append,
+    create,
+    create_icon,
+};
+use crate::{
+    style::{
+        Classes,
+        Color,
+        IconName,
+        Size,
+    },
+    FomanticError,
+};
+use gloo::events::EventListener;
+
+/// Builds a `fomantic-ui` button.
+#[derive(Clone, Debug)]
+pub struct Button {
+    text: String,
+    icon: Option<IconName>,
+    classes: Classes,
+    disabled: bool,
+}
+
+impl Button {
+    /// Creates a button with the given text.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            icon: None,
+            classes: Classes::new(),
+            disabled: false,
+        }
+    }
+
+    /// Shows the given icon in front of the text.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the color of the button.
+    pub fn color(mut self, color: Color) -> Self {
+        self.classes = self.classes.color(color);
+        self
+    }
+
+    /// Sets the size of the button.
+    pub fn size(mut self, size: Size) -> Self {
+        self.classes = self.classes.size(size);
+        self
+    }
+
+    /// Adds classes to the button, eg. `basic` or `fluid`.
+    pub fn class(mut self, class: impl Into<Classes>) -> Self {
+        self.classes = self.classes.with(class.into());
+        self
+    }
+
+    /// Creates the button disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Creates the button element.
+    pub fn build(self) -> Result<ButtonHandle, FomanticError> {
+        let class = Classes::from("ui")
+            .with(self.classes)
+            .with_if(self.icon.is_some() && self.text.is_empty(), "icon")
+            .with_if(
+                self.icon.is_some() && !self.text.is_empty(),
+                "labeled icon",
+            )
+            .with("button");
+        let element = create("button", &class.to_string())?;
+        let handle = ButtonHandle {
+            element,
+            icon: self.icon,
+            listeners: vec![],
+        };
+        handle.set_text(&self.text);
+        handle.set_disabled(self.disabled);
+        Ok(handle)
+    }
+}
+
+/// Handle to a button created by a [Button].
+pub struct ButtonHandle {
+    element: web_sys::Element,
+    icon: Option<IconName>,
+    listeners: Vec<EventListener>,
+}
+
+impl ButtonHandle {
+    /// Returns the button element.
+    pub fn element(&self) -> &web_sys::Element {
+        &self.element
+    }
+
+    /// Changes the text of the button, keeping its icon.
+    pub fn set_text(&self, text: &str) {
+        self.element.set_text_content(Some(text));
+        if let Some(icon) = self.icon.and_then(|icon| create_icon(icon).ok()) {
+            let _ = self.element.prepend_with_node_1(&icon);
+        }
+    }
+
+    /// Sets whether the button can be clicked.
+    pub fn set_disabled(&self, disabled: bool) {
+        let _ = self
+            .element
+            .class_list()
+            .toggle_with_force("disabled", disabled);
+        let _ = if disabled {
+            self.element.set_attribute("disabled", "")
+        } else {
+            self.element.remove_attribute("disabled")
+        };
+    }
+
+    /// Returns whether the button is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.element.class_list().contains("disabled")
+    }
+
+    /// Shows or removes the loading state.
+    pub fn set_loading(&self, loading: bool) {
+        let _ = self
+            .element
+            .class_list()
+            .toggle_with_force("loading", loading);
+    }
+
+    /// Calls the handler when the button is clicked.
+    pub fn on_click<H: FnMut() + 'static>(&mut self, mut handler: H) {
+        let listener =
+            EventListener::new(&self.element, "click", move |_| handler());
+        self.listeners.push(listener);
+    }
+
+    /// Appends the button to the given parent element.
+    pub fn mount(
+        &self,
+        parent: &web_sys::Element,
+    ) -> Result<(), FomanticError> {
+        append(parent, &self.element)
+    }
+}
@@ -0,0 +1,132 @@
+use super::{
+    append,
+    create,
+    create_text,
+};
+use crate::{
+    style::{
+        Classes,
+        Color,
+    },
+    FomanticError,
+};
+
+/// Builds a `fomantic-ui` card.
+#[derive(Clone, Debug, Default)]
+pub struct Card {
+    image: Option<String>,
+    header: String,
+    meta: Option<String>,
+    description: String,
+    extra: Option<String>,
+    classes: Classes,
+}
+
+impl Card {
+    /// Creates a card with the given header.
+    pub fn new(header: &str) -> Self {
+        Self {
+            header: header.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Shows the image with the given url on top of the content.
+    pub fn image(mut self, src: &str) -> Self {
+        self.image = Some(src.to_string());
+        self
+    }
+
+    /// Sets the meta text shown below the header.
+    pub fn meta(mut self, meta: &str) -> Self {
+        self.meta = Some(meta.to_string());
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Sets the extra content shown at the bottom of the card.
+    pub fn extra(mut self, extra: &str) -> Self {
+        self.extra = Some(extra.to_string());
+        self
+    }
+
+    /// Sets the color of the card.
+    pub fn color(mut self, color: Color) -> Self {
+        self.classes = self.classes.color(color);
+        self
+    }
+
+    /// Adds classes to the card, eg. `fluid` or `raised`.
+    pub fn class(mut self, class: impl Into<Classes>) -> Self {
+        self.classes = self.classes.with(class.into());
+        self
+    }
+
+    /// Creates the card element.
+    pub fn build(self) -> Result<CardHandle, FomanticError> {
+        let class = Classes::from("ui").with(self.classes).with("card");
+        let element = create("div", &class.to_string())?;
+        if let Some(src) = self.image {
+            let image = create("div", "image")?;
+            let img = create("img", "")?;
+            img.set_attribute("src", &src)
+                .map_err(FomanticError::from_js)?;
+            append(&image, &img)?;
+            append(&element, &image)?;
+        }
+        let content = create("div", "content")?;
+        let header = create_text("div", "header", &self.header)?;
+        append(&content, &header)?;
+        if let Some(meta) = self.meta {
+            append(&content, &create_text("div", "meta", &meta)?)?;
+        }
+        let description = create_text("div", "description", &self.description)?;
+        append(&content, &description)?;
+        append(&element, &content)?;
+        if let Some(extra) = self.extra {
+            append(&element, &create_text("div", "extra content", &extra)?)?;
+        }
+        Ok(CardHandle {
+            element,
+            header,
+            description,
+        })
+    }
+}
+
+/// Handle to a card created by a [Card].
+pub struct CardHandle {
+    element: web_sys::Element,
+    header: web_sys::Element,
+    description: web_sys::Element,
+}
+
+impl CardHandle {
+    /// Returns the card element.
+    pub fn element(&self) -> &web_sys::Element {
+        &self.element
+    }
+
+    /// Changes the header.
+    pub fn set_header(&self, header: &str) {
+        self.header.set_text_content(Some(header));
+    }
+
+    /// Changes the description.
+    pub fn set_description(&self, description: &str) {
+        self.description.set_text_content(Some(description));
+    }
+
+    /// Appends the card to the given parent element.
+    pub fn mount(
+        &self,
+        parent: &web_sys::Element,
+    ) -> Result<(), FomanticError> {
+        append(parent, &self.element)
+    }
+}
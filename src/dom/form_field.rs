@@ -0,0 +1,165 @@
+use super::{
+    append,
+    create,
+    create_text,
+};
+use crate::FomanticError;
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+
+/// Builds a `fomantic-ui` form field consisting of a label and an input.
+///
+/// The field has to be placed inside a `ui form` element to be styled.
+#[derive(Clone, Debug)]
+pub struct FormField {
+    name: String,
+    label: Option<String>,
+    input_type: String,
+    placeholder: Option<String>,
+    value: String,
+    required: bool,
+}
+
+impl FormField {
+    /// Creates a text field with the given name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            label: None,
+            input_type: "text".to_string(),
+            placeholder: None,
+            value: String::new(),
+            required: false,
+        }
+    }
+
+    /// Shows the given label above the input.
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the type of the input, eg. `password` or `email`.
+    pub fn input_type(mut self, input_type: &str) -> Self {
+        self.input_type = input_type.to_string();
+        self
+    }
+
+    /// Sets the placeholder of the input.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the initial value of the input.
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = value.to_string();
+        self
+    }
+
+    /// Marks the field as required.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Creates the field element.
+    pub fn build(self) -> Result<FormFieldHandle, FomanticError> {
+        let class = if self.required {
+            "required field"
+        } else {
+            "field"
+        };
+        let element = create("div", class)?;
+        if let Some(label) = self.label {
+            let label = create_text("label", "", &label)?;
+            label
+                .set_attribute("for", &self.name)
+                .map_err(FomanticError::from_js)?;
+            append(&element, &label)?;
+        }
+        let input = create("input", "")?
+            .dyn_into::<web_sys::HtmlInputElement>()
+            .map_err(|e| FomanticError::from_js(e.into()))?;
+        input.set_type(&self.input_type);
+        input.set_name(&self.name);
+        input.set_id(&self.name);
+        input.set_value(&self.value);
+        input.set_required(self.required);
+        if let Some(placeholder) = self.placeholder {
+            input.set_placeholder(&placeholder);
+        }
+        append(&element, &input)?;
+        let error = create("div", "ui basic red pointing prompt label")?;
+        error
+            .set_attribute("hidden", "")
+            .map_err(FomanticError::from_js)?;
+        append(&element, &error)?;
+        Ok(FormFieldHandle {
+            element,
+            input,
+            error,
+            listeners: vec![],
+        })
+    }
+}
+
+/// Handle to a field created by a [FormField].
+pub struct FormFieldHandle {
+    element: web_sys::Element,
+    input: web_sys::HtmlInputElement,
+    error: web_sys::Element,
+    listeners: Vec<EventListener>,
+}
+
+impl FormFieldHandle {
+    /// Returns the field element.
+    pub fn element(&self) -> &web_sys::Element {
+        &self.element
+    }
+
+    /// Returns the input element.
+    pub fn input(&self) -> &web_sys::HtmlInputElement {
+        &self.input
+    }
+
+    /// Returns the current value of the input.
+    pub fn value(&self) -> String {
+        self.input.value()
+    }
+
+    /// Changes the value of the input.
+    pub fn set_value(&self, value: &str) {
+        self.input.set_value(value);
+    }
+
+    /// Shows the given error message below the input, or removes it.
+    pub fn set_error(&self, error: Option<&str>) {
+        let _ = self
+            .element
+            .class_list()
+            .toggle_with_force("error", error.is_some());
+        self.error.set_text_content(error);
+        let _ = match error {
+            Some(_) => self.error.remove_attribute("hidden"),
+            None => self.error.set_attribute("hidden", ""),
+        };
+    }
+
+    /// Calls the handler with the value of the input whenever it changes.
+    pub fn on_input<H: FnMut(String) + 'static>(&mut self, mut handler: H) {
+        let input = self.input.clone();
+        let listener = EventListener::new(&self.input, "input", move |_| {
+            handler(input.value())
+        });
+        self.listeners.push(listener);
+    }
+
+    /// Appends the field to the given parent element.
+    pub fn mount(
+        &self,
+        parent: &web_sys::Element,
+    ) -> Result<(), FomanticError> {
+        append(parent, &self.element)
+    }
+}
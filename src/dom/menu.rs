@@ -0,0 +1,142 @@
+use super::{
+    append,
+    create,
+    create_icon,
+};
+use crate::{
+    style::{
+        Classes,
+        Color,
+        IconName,
+    },
+    FomanticError,
+};
+use gloo::events::EventListener;
+
+/// Builds a `fomantic-ui` menu of link items.
+#[derive(Clone, Debug, Default)]
+pub struct Menu {
+    items: Vec<(String, Option<IconName>)>,
+    classes: Classes,
+    active: Option<usize>,
+}
+
+impl Menu {
+    /// Creates an empty menu.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an item with the given text.
+    pub fn item(mut self, text: &str) -> Self {
+        self.items.push((text.to_string(), None));
+        self
+    }
+
+    /// Adds an item with the given text and icon.
+    pub fn icon_item(mut self, text: &str, icon: IconName) -> Self {
+        self.items.push((text.to_string(), Some(icon)));
+        self
+    }
+
+    /// Sets the color of the active item.
+    pub fn color(mut self, color: Color) -> Self {
+        self.classes = self.classes.color(color);
+        self
+    }
+
+    /// Adds classes to the menu, eg. `secondary` or `vertical`.
+    pub fn class(mut self, class: impl Into<Classes>) -> Self {
+        self.classes = self.classes.with(class.into());
+        self
+    }
+
+    /// Marks the item at the given index as active.
+    pub fn active(mut self, index: usize) -> Self {
+        self.active = Some(index);
+        self
+    }
+
+    /// Creates the menu element.
+    pub fn build(self) -> Result<MenuHandle, FomanticError> {
+        let class = Classes::from("ui").with(self.classes).with("menu");
+        let element = create("div", &class.to_string())?;
+        let mut items = Vec::with_capacity(self.items.len());
+        for (text, icon) in self.items {
+            let item = create("a", "item")?;
+            item.set_text_content(Some(&text));
+            if let Some(icon) = icon {
+                let icon = create_icon(icon)?;
+                item.prepend_with_node_1(&icon)
+                    .map_err(FomanticError::from_js)?;
+            }
+            append(&element, &item)?;
+            items.push(item);
+        }
+        let handle = MenuHandle {
+            element,
+            items,
+            listeners: vec![],
+        };
+        handle.set_active(self.active);
+        Ok(handle)
+    }
+}
+
+/// Handle to a menu created by a [Menu].
+pub struct MenuHandle {
+    element: web_sys::Element,
+    items: Vec<web_sys::Element>,
+    listeners: Vec<EventListener>,
+}
+
+impl MenuHandle {
+    /// Returns the menu element.
+    pub fn element(&self) -> &web_sys::Element {
+        &self.element
+    }
+
+    /// Returns the item elements in order.
+    pub fn items(&self) -> &[web_sys::Element] {
+        &self.items
+    }
+
+    /// Marks the item at the given index as active, or none.
+    pub fn set_active(&self, index: Option<usize>) {
+        for (i, item) in self.items.iter().enumerate() {
+            let _ = item
+                .class_list()
+                .toggle_with_force("active", Some(i) == index);
+        }
+    }
+
+    /// Returns the index of the active item.
+    pub fn active(&self) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|item| item.class_list().contains("active"))
+    }
+
+    /// Calls the handler with the index of an item when it is clicked.
+    ///
+    /// The clicked item is not marked as active automatically, use
+    /// [MenuHandle::set_active] in the handler if required.
+    pub fn on_select<H: FnMut(usize) + 'static>(&mut self, handler: H) {
+        let handler = std::rc::Rc::new(std::cell::RefCell::new(handler));
+        for (index, item) in self.items.iter().enumerate() {
+            let handler = std::rc::Rc::clone(&handler);
+            let listener = EventListener::new(item, "click", move |_| {
+                (handler.borrow_mut())(index)
+            });
+            self.listeners.push(listener);
+        }
+    }
+
+    /// Appends the menu to the given parent element.
+    pub fn mount(
+        &self,
+        parent: &web_sys::Element,
+    ) -> Result<(), FomanticError> {
+        append(parent, &self.element)
+    }
+}
@@ -0,0 +1,228 @@
+//! Detection of the scripts loaded on the page.
+use crate::{
+    jq::jquery,
+    FomanticError,
+};
+use std::cell::RefCell;
+use wasm_bindgen::{
+    JsCast,
+    JsValue,
+};
+
+/// The modules of `fomantic-ui` that are looked up by [ensure_loaded].
+pub(crate) const MODULES: &[&str] = &[
+    "accordion",
+    "calendar",
+    "checkbox",
+    "dimmer",
+    "dropdown",
+    "embed",
+    "flyout",
+    "form",
+    "modal",
+    "nag",
+    "popup",
+    "progress",
+    "rating",
+    "search",
+    "shape",
+    "sidebar",
+    "slider",
+    "sticky",
+    "tab",
+    "toast",
+    "transition",
+    "visibility",
+];
+
+thread_local! {
+    /// The environment detected by [ensure_loaded], so the constructors of
+    /// the modules do not look up all modules again.
+    static ENVIRONMENT: RefCell<Option<Environment>> =
+        const { RefCell::new(None) };
+}
+
+/// The jQuery and `fomantic-ui` scripts found on the page.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Environment {
+    /// Version of jQuery, eg. `3.7.1`.
+    pub jquery_version: String,
+    /// Version of `fomantic-ui`, eg. `2.9.3`, if the settings of the loaded
+    /// modules expose it.
+    pub fomantic_version: Option<String>,
+    /// The `fomantic-ui` modules that are loaded.
+    pub modules: Vec<&'static str>,
+}
+
+impl Environment {
+    /// Returns whether the given module, eg. `modal`, is loaded.
+    pub fn has_module(&self, module: &str) -> bool {
+        self.modules.contains(&module)
+    }
+
+    /// Returns whether the loaded `fomantic-ui` is at least version `2.9`,
+    /// which the bindings are built for, or `None` if the version is
+    /// unknown.
+    ///
+    /// Without a [fomantic_version](Self::fomantic_version), the `flyout`
+    /// module that has been introduced with `2.9` shows that it is
+    /// supported. Its absence tells nothing, as the scripts of single
+    /// modules might be loaded.
+    pub fn supports_version_2_9(&self) -> Option<bool> {
+        match self.fomantic_version.as_deref().and_then(parse_version) {
+            Some(version) => Some(version >= (2, 9)),
+            None => self.has_module("flyout").then_some(true),
+        }
+    }
+
+    /// Returns an error if the given module is not loaded.
+    pub fn require(&self, module: &str) -> Result<(), FomanticError> {
+        if self.has_module(module) || is_plugin(&jquery()?, module) {
+            Ok(())
+        } else {
+            Err(FomanticError::ModuleMissing(module.to_string()))
+        }
+    }
+}
+
+/// Returns the major and minor number of the given version, eg. `2.9.3`.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut numbers = version.trim().trim_start_matches('v').split('.');
+    Some((numbers.next()?.parse().ok()?, numbers.next()?.parse().ok()?))
+}
+
+/// Returns the `version` found in the default settings of the given
+/// modules, `$.fn.<module>.settings`.
+fn settings_version(jquery: &JsValue, modules: &[&str]) -> Option<String> {
+    modules.iter().find_map(|module| {
+        js_sys::Reflect::get(jquery, &"fn".into())
+            .and_then(|plugins| {
+                js_sys::Reflect::get(&plugins, &(*module).into())
+            })
+            .and_then(|plugin| {
+                js_sys::Reflect::get(&plugin, &"settings".into())
+            })
+            .and_then(|settings| {
+                js_sys::Reflect::get(&settings, &"version".into())
+            })
+            .ok()?
+            .as_string()
+    })
+}
+
+/// Returns whether jQuery has a plugin with the given name.
+fn is_plugin(jquery: &JsValue, name: &str) -> bool {
+    js_sys::Reflect::get(jquery, &"fn".into())
+        .and_then(|plugins| js_sys::Reflect::get(&plugins, &name.into()))
+        .is_ok_and(|plugin| plugin.is_function())
+}
+
+/// Returns the default settings of the given module, `$.fn.<module>.settings`,
+/// if the module is loaded.
+pub(crate) fn module_defaults(
+    module: &str,
+) -> Result<Option<js_sys::Object>, FomanticError> {
+    if let Some(backend) = crate::backend::custom() {
+        return backend.module_defaults(module);
+    }
+    let plugin = js_sys::Reflect::get(&jquery()?, &"fn".into())
+        .and_then(|plugins| js_sys::Reflect::get(&plugins, &module.into()))
+        .map_err(FomanticError::from_js)?;
+    if !plugin.is_function() {
+        return Ok(None);
+    }
+    js_sys::Reflect::get(&plugin, &"settings".into())
+        .map(|settings| settings.dyn_into().ok())
+        .map_err(FomanticError::from_js)
+}
+
+/// Deep merges the given settings into the defaults of the module.
+///
+/// Returns `false` if the module is not loaded.
+pub(crate) fn extend_defaults(
+    module: &str,
+    settings: &JsValue,
+) -> Result<bool, FomanticError> {
+    if let Some(backend) = crate::backend::custom() {
+        return backend.extend_defaults(module, settings);
+    }
+    let Some(defaults) = module_defaults(module)? else {
+        return Ok(false);
+    };
+    let jquery = jquery()?;
+    let extend = js_sys::Reflect::get(&jquery, &"extend".into())
+        .map_err(FomanticError::from_js)?
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| FomanticError::JQueryMissing)?;
+    // deep, so nested settings like texts are merged too
+    extend
+        .call3(&jquery, &JsValue::TRUE, &defaults, settings)
+        .map_err(FomanticError::from_js)?;
+    Ok(true)
+}
+
+/// Checks that jQuery and `fomantic-ui` are loaded and returns the modules
+/// that are available.
+///
+/// The constructors of the modules call this before touching JavaScript, so
+/// a page that forgot to include the scripts fails with an error describing
+/// what is missing. A warning is logged if the loaded `fomantic-ui` is known
+/// to be older than the version the bindings are built for.
+///
+/// The environment is detected once and kept afterwards. Modules loaded
+/// later are still found by [Environment::require].
+pub fn ensure_loaded() -> Result<Environment, FomanticError> {
+    if let Some(environment) = ENVIRONMENT.with_borrow(Clone::clone) {
+        return Ok(environment);
+    }
+    let jquery = jquery()?;
+    let jquery_version = js_sys::Reflect::get(&jquery, &"fn".into())
+        .and_then(|plugins| js_sys::Reflect::get(&plugins, &"jquery".into()))
+        .ok()
+        .and_then(|version| version.as_string())
+        .unwrap_or_default();
+    let modules = MODULES
+        .iter()
+        .copied()
+        .filter(|module| is_plugin(&jquery, module))
+        .collect::<Vec<_>>();
+    if modules.is_empty() {
+        return Err(FomanticError::FomanticMissing);
+    }
+    let environment = Environment {
+        jquery_version,
+        fomantic_version: settings_version(&jquery, &modules),
+        modules,
+    };
+    if environment.supports_version_2_9() == Some(false) {
+        tracing::warn!(
+            "The loaded fomantic-ui is older than 2.9, which the bindings are \
+             built for. Some modules may not work as expected."
+        );
+    }
+    ENVIRONMENT.set(Some(environment.clone()));
+    Ok(environment)
+}
+
+/// Checks that the given module, eg. `modal`, is loaded.
+pub(crate) fn ensure_module(module: &str) -> Result<(), FomanticError> {
+    #[cfg(feature = "mock")]
+    if crate::mock::is_installed() {
+        return crate::mock::require(module);
+    }
+    if let Some(backend) = crate::backend::custom() {
+        return match backend.has_module(module) {
+            true => Ok(()),
+            false => Err(FomanticError::ModuleMissing(module.to_string())),
+        };
+    }
+    let cached = ENVIRONMENT.with_borrow(|environment| {
+        environment
+            .as_ref()
+            .map(|environment| environment.require(module))
+    });
+    match cached {
+        Some(result) => result,
+        None => ensure_loaded()?.require(module),
+    }
+}
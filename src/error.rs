@@ -0,0 +1,67 @@
+//! Errors of the bindings.
+use wasm_bindgen::{
+    JsCast,
+    JsValue,
+};
+
+/// An error raised when calling into jQuery or `fomantic-ui`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FomanticError {
+    /// jQuery is not loaded on the page.
+    JQueryMissing,
+    /// None of the `fomantic-ui` modules is loaded on the page.
+    FomanticMissing,
+    /// The `fomantic-ui` module with the given name is not loaded, eg. because
+    /// its script is not included.
+    ModuleMissing(String),
+    /// The given selector could not be parsed by jQuery.
+    BadSelector(String),
+    /// A JavaScript exception with the given message has been thrown.
+    Js(String),
+}
+
+impl FomanticError {
+    /// Converts a thrown JavaScript value into an error.
+    pub fn from_js(value: JsValue) -> Self {
+        let message = match value.dyn_ref::<js_sys::Error>() {
+            Some(error) => String::from(error.message()),
+            None => value.as_string().unwrap_or_else(|| format!("{value:?}")),
+        };
+        Self::Js(message)
+    }
+
+    /// Rethrows the error as JavaScript exception.
+    ///
+    /// Used by the infallible variants of the fallible functions.
+    pub(crate) fn throw(self) -> ! {
+        wasm_bindgen::throw_str(&self.to_string())
+    }
+}
+
+impl std::fmt::Display for FomanticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::JQueryMissing => write!(
+                f,
+                "jQuery is not loaded, include its script before the one of \
+                 fomantic-ui"
+            ),
+            Self::FomanticMissing => write!(
+                f,
+                "fomantic-ui is not loaded, include semantic.min.js after \
+                 jQuery"
+            ),
+            Self::ModuleMissing(module) => write!(
+                f,
+                "The fomantic-ui module {module} is not loaded, include \
+                 semantic.min.js or the script of the module"
+            ),
+            Self::BadSelector(selector) => {
+                write!(f, "Invalid selector: {selector}")
+            }
+            Self::Js(message) => write!(f, "JavaScript exception: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for FomanticError {}
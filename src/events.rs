@@ -0,0 +1,72 @@
+//! Streams of the events of modules.
+//!
+//! The callbacks of a module are forwarded into an [EventStream], which
+//! allows handling them in async code, eg. by awaiting the next event or
+//! selecting over the events of several modules.
+use futures_channel::mpsc;
+use futures_core::Stream;
+use std::{
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+/// A stream of the events of a module, eg. a
+/// [ModalEvent](crate::modules::modal::ModalEvent).
+///
+/// The stream ends once the module and all [EventSender]s are dropped.
+pub struct EventStream<E> {
+    receiver: mpsc::UnboundedReceiver<E>,
+}
+
+impl<E> EventStream<E> {
+    /// Creates a stream and the sender used by the callbacks of a module to
+    /// emit events into it.
+    pub fn channel() -> (EventSender<E>, Self) {
+        let (sender, receiver) = mpsc::unbounded();
+        (EventSender { sender }, Self { receiver })
+    }
+}
+
+impl<E> Stream for EventStream<E> {
+    type Item = E;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<E>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.receiver.size_hint()
+    }
+}
+
+/// Emits events into an [EventStream].
+pub struct EventSender<E> {
+    sender: mpsc::UnboundedSender<E>,
+}
+
+impl<E> EventSender<E> {
+    /// Emits the given event. Events are dropped if the stream has been
+    /// dropped.
+    pub fn send(&self, event: E) {
+        let _ = self.sender.unbounded_send(event);
+    }
+
+    /// Returns whether the stream has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+impl<E> Clone for EventSender<E> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Initialization of the modules found in rendered markup.
+use crate::{
+    environment::ensure_module,
+    jq::JQuery,
+    modules::InstanceGuard,
+    FomanticError,
+};
+use wasm_bindgen::JsValue;
+
+/// The modules initialized by [init_all] and the selectors of their
+/// elements.
+const MODULES: &[(&str, &str)] = &[
+    ("accordion", ".ui.accordion"),
+    #[cfg(feature = "checkbox")]
+    ("checkbox", ".ui.checkbox"),
+    #[cfg(feature = "dropdown")]
+    ("dropdown", ".ui.dropdown"),
+    #[cfg(feature = "popup")]
+    ("popup", "[data-content], [data-html]"),
+];
+
+/// Initializes the accordions, checkboxes, dropdowns and popups below the
+/// given element that are not initialized yet.
+///
+/// All elements are looked up before the first module is initialized, so
+/// the page is laid out once instead of after every module. The modules use
+/// their defaults, see [settings](crate::settings). Popups are initialized
+/// for the elements with a `data-content` or `data-html` attribute.
+///
+/// Returns a guard per initialized module, that destroys it when dropped.
+/// Fails if a module is not loaded while there are elements for it. The
+/// modules initialized before stay initialized, their guards are returned
+/// together with the error.
+///
+/// ```no_run
+/// use fomantic_ui::jq::JQuery;
+///
+/// // after rendering the page into #app
+/// let modules = match fomantic_ui::try_init_all(&JQuery::select("#app")) {
+///     Ok(modules) => modules,
+///     Err((modules, e)) => {
+///         tracing::warn!("Could not initialize all modules: {e}");
+///         modules
+///     }
+/// };
+/// // before navigating away
+/// drop(modules);
+/// ```
+pub fn try_init_all(
+    scope: &JQuery,
+) -> Result<Vec<InstanceGuard>, (Vec<InstanceGuard>, FomanticError)> {
+    let pending = MODULES
+        .iter()
+        .map(|&(module, selector)| {
+            let found = scope.find(selector);
+            let elements = (0..found.length() as i32)
+                .map(|index| found.eq(index))
+                .filter(|element| !element.is_initialized(module))
+                .collect::<Vec<_>>();
+            (module, elements)
+        })
+        .collect::<Vec<_>>();
+    let mut guards = vec![];
+    for (module, elements) in pending {
+        if elements.is_empty() {
+            continue;
+        }
+        if let Err(e) = ensure_module(module) {
+            return Err((guards, e));
+        }
+        for element in elements {
+            if let Err(e) = element.try_init(module, &JsValue::UNDEFINED) {
+                return Err((guards, e));
+            }
+            guards.push(InstanceGuard::new(element, module));
+        }
+    }
+    Ok(guards)
+}
+
+/// Initializes the modules below the given element that are not initialized
+/// yet, see [try_init_all].
+///
+/// Throws if a module is not loaded. The modules initialized before are
+/// left initialized, without a guard.
+pub fn init_all(scope: &JQuery) -> Vec<InstanceGuard> {
+    try_init_all(scope).unwrap_or_else(|(guards, e)| {
+        std::mem::forget(guards);
+        e.throw()
+    })
+}
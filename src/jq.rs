@@ -0,0 +1,592 @@
+//! A minimal jQuery wrapper that the module bindings are built on.
+//!
+//! Can be used to invoke behaviors of `fomantic-ui` modules that are not
+//! wrapped by this crate yet:
+//!
+//! ```no_run
+//! use fomantic_ui::jq::JQuery;
+//!
+//! let accordion = JQuery::select(".ui.accordion");
+//! accordion.init("accordion", &wasm_bindgen::JsValue::UNDEFINED);
+//! accordion.behavior("accordion", "open");
+//! ```
+use crate::{
+    backend::Selection,
+    FomanticError,
+};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A jQuery object as returned by JavaScript.
+    #[derive(Clone, Debug)]
+    pub(crate) type JsJQuery;
+
+    /// Queries the elements matching the given selector.
+    #[wasm_bindgen(js_name = "$", catch)]
+    fn query_selector(selector: &str) -> Result<JsJQuery, JsValue>;
+
+    /// Wraps the given element.
+    #[wasm_bindgen(js_name = "$", catch)]
+    fn query_element(element: &web_sys::Element) -> Result<JsJQuery, JsValue>;
+
+    #[wasm_bindgen(method, getter)]
+    fn length(this: &JsJQuery) -> u32;
+
+    #[wasm_bindgen(method)]
+    fn find(this: &JsJQuery, selector: &str) -> JsJQuery;
+
+    #[wasm_bindgen(method)]
+    fn closest(this: &JsJQuery, selector: &str) -> JsJQuery;
+
+    #[wasm_bindgen(method)]
+    fn eq(this: &JsJQuery, index: i32) -> JsJQuery;
+
+    #[wasm_bindgen(method)]
+    fn attr(this: &JsJQuery, name: &str, value: &str);
+
+    #[wasm_bindgen(method, js_name = "toggleClass")]
+    fn toggle_class(this: &JsJQuery, class: &str, state: bool);
+
+    #[wasm_bindgen(method, js_name = "hasClass")]
+    fn has_class(this: &JsJQuery, class: &str) -> bool;
+
+    #[wasm_bindgen(method)]
+    fn prop(this: &JsJQuery, name: &str, value: bool);
+
+    #[wasm_bindgen(method)]
+    fn text(this: &JsJQuery, text: &str);
+
+    #[wasm_bindgen(method)]
+    fn prepend(this: &JsJQuery, html: &str);
+
+    #[wasm_bindgen(method, js_name = "data")]
+    fn set_data(this: &JsJQuery, key: &str, value: &JsValue);
+
+    #[wasm_bindgen(method)]
+    fn data(this: &JsJQuery, key: &str) -> JsValue;
+
+    #[wasm_bindgen(method)]
+    fn trigger(this: &JsJQuery, event: &str);
+
+    #[wasm_bindgen(method)]
+    fn on(
+        this: &JsJQuery,
+        event: &str,
+        selector: &str,
+        handler: &Closure<dyn FnMut(web_sys::Event)>,
+    );
+
+    #[wasm_bindgen(method)]
+    fn remove(this: &JsJQuery);
+
+    #[wasm_bindgen(method)]
+    fn get(this: &JsJQuery, index: i32) -> JsValue;
+}
+
+/// Returns the global jQuery function.
+pub(crate) fn jquery() -> Result<JsValue, FomanticError> {
+    js_sys::Reflect::get(&js_sys::global(), &"jQuery".into())
+        .ok()
+        .filter(|jquery| jquery.is_function())
+        .ok_or(FomanticError::JQueryMissing)
+}
+
+/// Calls the module function of jQuery that creates elements, eg.
+/// `$.toast(..)`, or of the installed [Backend](crate::backend::Backend).
+#[cfg(any(feature = "modal", feature = "toast"))]
+pub(crate) fn invoke(
+    module: &str,
+    args: &[&JsValue],
+) -> Result<JQuery, FomanticError> {
+    if let Some(backend) = crate::backend::custom() {
+        return backend.invoke(module, args);
+    }
+    let jquery = jquery()?;
+    let function = js_sys::Reflect::get(&jquery, &module.into())
+        .map_err(FomanticError::from_js)?
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| FomanticError::ModuleMissing(module.to_string()))?;
+    function
+        .apply(&jquery, &args.iter().copied().collect())
+        .map(|created| JQuery::from(created.unchecked_into::<JsJQuery>()))
+        .map_err(FomanticError::from_js)
+}
+
+impl Selection for JsJQuery {
+    fn length(&self) -> u32 {
+        JsJQuery::length(self)
+    }
+
+    fn find(&self, selector: &str) -> JQuery {
+        JsJQuery::find(self, selector).into()
+    }
+
+    fn closest(&self, selector: &str) -> JQuery {
+        JsJQuery::closest(self, selector).into()
+    }
+
+    fn eq(&self, index: i32) -> JQuery {
+        JsJQuery::eq(self, index).into()
+    }
+
+    fn element(&self, index: i32) -> Option<web_sys::Element> {
+        self.get(index).dyn_into().ok()
+    }
+
+    fn attr(&self, name: &str, value: &str) {
+        JsJQuery::attr(self, name, value)
+    }
+
+    fn toggle_class(&self, class: &str, state: bool) {
+        JsJQuery::toggle_class(self, class, state)
+    }
+
+    fn has_class(&self, class: &str) -> bool {
+        JsJQuery::has_class(self, class)
+    }
+
+    fn prop(&self, name: &str, value: bool) {
+        JsJQuery::prop(self, name, value)
+    }
+
+    fn text(&self, text: &str) {
+        JsJQuery::text(self, text)
+    }
+
+    fn prepend(&self, html: &str) {
+        JsJQuery::prepend(self, html)
+    }
+
+    fn set_data(&self, key: &str, value: &JsValue) {
+        JsJQuery::set_data(self, key, value)
+    }
+
+    fn data(&self, key: &str) -> JsValue {
+        JsJQuery::data(self, key)
+    }
+
+    fn trigger(&self, event: &str) {
+        JsJQuery::trigger(self, event)
+    }
+
+    fn on(
+        &self,
+        event: &str,
+        selector: &str,
+        handler: &Closure<dyn FnMut(web_sys::Event)>,
+    ) {
+        JsJQuery::on(self, event, selector, handler)
+    }
+
+    fn remove(&self) {
+        JsJQuery::remove(self)
+    }
+
+    fn call(
+        &self,
+        method: &str,
+        args: &[&JsValue],
+    ) -> Result<JsValue, FomanticError> {
+        let function = js_sys::Reflect::get(self, &method.into())
+            .map_err(FomanticError::from_js)?
+            .dyn_into::<js_sys::Function>()
+            .map_err(|_| FomanticError::ModuleMissing(method.to_string()))?;
+        function
+            .apply(self, &args.iter().copied().collect())
+            .map_err(FomanticError::from_js)
+    }
+}
+
+/// A jQuery selection of elements.
+///
+/// Selections created while a [Backend](crate::backend::Backend) is
+/// installed use it instead of jQuery. With the `mock` feature, selections
+/// created while a [MockBackend](crate::mock::MockBackend) is installed
+/// record the calls instead of calling into JavaScript.
+#[derive(Clone, Debug)]
+pub struct JQuery {
+    inner: Inner,
+}
+
+/// The backend of a [JQuery] selection.
+#[derive(Clone, Debug)]
+enum Inner {
+    /// A jQuery object.
+    Js(JsJQuery),
+    /// A selection of a custom backend.
+    Custom(Rc<dyn Selection>),
+    /// The selector of a mocked selection.
+    #[cfg(feature = "mock")]
+    Mock(String),
+}
+
+impl From<JsJQuery> for JQuery {
+    fn from(js: JsJQuery) -> Self {
+        Self {
+            inner: Inner::Js(js),
+        }
+    }
+}
+
+/// Dispatches to the selection, or records the call on the mocked
+/// selection.
+macro_rules! dispatch {
+    ($self:ident, $sel:ident => $call:expr, $selector:ident => $mock:expr) => {
+        match &$self.inner {
+            Inner::Js(js) => {
+                let $sel: &dyn Selection = js;
+                $call
+            }
+            Inner::Custom(custom) => {
+                let $sel: &dyn Selection = custom.as_ref();
+                $call
+            }
+            #[cfg(feature = "mock")]
+            Inner::Mock($selector) => $mock,
+        }
+    };
+}
+
+impl JQuery {
+    /// Wraps a selection of a custom [Backend](crate::backend::Backend).
+    pub fn from_selection(selection: impl Selection + 'static) -> Self {
+        Self {
+            inner: Inner::Custom(Rc::new(selection)),
+        }
+    }
+
+    /// Creates a mocked selection of the given selector.
+    #[cfg(feature = "mock")]
+    fn mock(selector: String) -> Self {
+        Self {
+            inner: Inner::Mock(selector),
+        }
+    }
+
+    /// Returns the jQuery object, or `None` if the selection is mocked or
+    /// of a custom backend.
+    #[cfg(all(feature = "leptos", feature = "table"))]
+    pub(crate) fn as_js(&self) -> Option<&JsJQuery> {
+        match &self.inner {
+            Inner::Js(js) => Some(js),
+            _ => None,
+        }
+    }
+
+    /// Returns the selector of a mocked selection.
+    #[cfg(all(feature = "mock", feature = "models"))]
+    pub(crate) fn mock_selector(&self) -> Option<&str> {
+        match &self.inner {
+            Inner::Mock(selector) => Some(selector),
+            _ => None,
+        }
+    }
+
+    /// Queries the elements matching the given selector.
+    pub fn try_select(selector: &str) -> Result<Self, FomanticError> {
+        #[cfg(feature = "mock")]
+        if crate::mock::is_installed() {
+            return Ok(Self::mock(selector.to_string()));
+        }
+        if let Some(backend) = crate::backend::custom() {
+            return backend.select(selector);
+        }
+        jquery()?;
+        query_selector(selector)
+            .map(Self::from)
+            .map_err(|_| FomanticError::BadSelector(selector.to_string()))
+    }
+
+    /// Queries the elements matching the given selector.
+    ///
+    /// Throws if the selector is invalid, see [JQuery::try_select].
+    pub fn select(selector: &str) -> Self {
+        Self::try_select(selector).unwrap_or_else(|e| e.throw())
+    }
+
+    /// Wraps the given element.
+    pub fn try_from_element(
+        element: &web_sys::Element,
+    ) -> Result<Self, FomanticError> {
+        #[cfg(feature = "mock")]
+        if crate::mock::is_installed() {
+            return Ok(Self::mock(crate::mock::element_selector(element)));
+        }
+        if let Some(backend) = crate::backend::custom() {
+            return backend.wrap(element);
+        }
+        jquery()?;
+        query_element(element)
+            .map(Self::from)
+            .map_err(FomanticError::from_js)
+    }
+
+    /// Wraps the given element.
+    ///
+    /// Throws if jQuery is not loaded, see [JQuery::try_from_element].
+    pub fn from_element(element: &web_sys::Element) -> Self {
+        Self::try_from_element(element).unwrap_or_else(|e| e.throw())
+    }
+
+    /// The amount of elements in the selection.
+    pub fn length(&self) -> u32 {
+        dispatch!(self,
+            sel => sel.length(),
+            selector => crate::mock::length(selector))
+    }
+
+    /// Whether the selection contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+
+    /// Finds the descendants matching the given selector.
+    pub fn find(&self, selector: &str) -> Self {
+        dispatch!(self,
+            sel => sel.find(selector),
+            parent => Self::mock(format!("{parent} {selector}")))
+    }
+
+    /// Returns the closest ancestor matching the given selector, starting
+    /// with the element itself.
+    pub fn closest(&self, selector: &str) -> Self {
+        dispatch!(self,
+            sel => sel.closest(selector),
+            child => Self::mock(format!("{child}:closest({selector})")))
+    }
+
+    /// Reduces the selection to the element at the given index.
+    pub fn eq(&self, index: i32) -> Self {
+        dispatch!(self,
+            sel => sel.eq(index),
+            selector => Self::mock(format!("{selector}:eq({index})")))
+    }
+
+    /// Sets an attribute of the elements.
+    pub fn attr(&self, name: &str, value: &str) {
+        dispatch!(self,
+            sel => sel.attr(name, value),
+            selector => crate::mock::set_attr(selector, name, value))
+    }
+
+    /// Adds or removes the given class.
+    pub fn toggle_class(&self, class: &str, state: bool) {
+        dispatch!(self,
+            sel => sel.toggle_class(class, state),
+            selector => crate::mock::toggle_class(selector, class, state))
+    }
+
+    /// Whether any of the elements has the given class.
+    pub fn has_class(&self, class: &str) -> bool {
+        dispatch!(self,
+            sel => sel.has_class(class),
+            selector => crate::mock::has_class(selector, class))
+    }
+
+    /// Sets a boolean property of the elements, eg. `disabled`.
+    pub fn prop(&self, name: &str, value: bool) {
+        dispatch!(self,
+            sel => sel.prop(name, value),
+            selector => crate::mock::set_prop(selector, name, value))
+    }
+
+    /// Sets the text content of the elements.
+    pub fn text(&self, text: &str) {
+        dispatch!(self,
+            sel => sel.text(text),
+            selector => crate::mock::set_text(selector, text))
+    }
+
+    /// Inserts the given html at the beginning of the elements.
+    pub fn prepend(&self, html: &str) {
+        dispatch!(self,
+        sel => sel.prepend(html),
+        selector => crate::mock::record(crate::mock::MockCall::Prepend {
+            selector: selector.clone(),
+            html: html.to_string(),
+        }))
+    }
+
+    /// Attaches data to the elements.
+    pub fn set_data(&self, key: &str, value: &JsValue) {
+        dispatch!(self,
+        sel => sel.set_data(key, value),
+        selector => crate::mock::record(crate::mock::MockCall::Data {
+            selector: selector.clone(),
+            key: key.to_string(),
+        }))
+    }
+
+    /// Returns the data attached to the first element.
+    pub fn data(&self, key: &str) -> JsValue {
+        dispatch!(self, sel => sel.data(key), _selector => JsValue::UNDEFINED)
+    }
+
+    /// Whether the given module has been initialized on the first element
+    /// and not destroyed since.
+    pub fn is_initialized(&self, module: &str) -> bool {
+        dispatch!(self,
+            sel => !sel.data(&format!("module-{module}")).is_undefined(),
+            selector => crate::mock::is_initialized(selector, module))
+    }
+
+    /// Triggers the given event on the elements.
+    pub fn trigger(&self, event: &str) {
+        dispatch!(self,
+        sel => sel.trigger(event),
+        selector => crate::mock::record(crate::mock::MockCall::Trigger {
+            selector: selector.clone(),
+            event: event.to_string(),
+        }))
+    }
+
+    /// Attaches an event handler to the descendants matching the given
+    /// selector.
+    pub fn on(
+        &self,
+        event: &str,
+        selector: &str,
+        handler: &Closure<dyn FnMut(web_sys::Event)>,
+    ) {
+        dispatch!(self,
+        sel => sel.on(event, selector, handler),
+        parent => crate::mock::record(crate::mock::MockCall::On {
+            selector: parent.clone(),
+            event: event.to_string(),
+            target: selector.to_string(),
+        }))
+    }
+
+    /// Returns the element at the given index, `None` if there is none or
+    /// the selection is mocked.
+    pub fn element(&self, index: i32) -> Option<web_sys::Element> {
+        dispatch!(self, sel => sel.element(index), _selector => None)
+    }
+
+    /// Removes the elements from the DOM.
+    pub fn remove(&self) {
+        dispatch!(self,
+        sel => sel.remove(),
+        selector => crate::mock::record(crate::mock::MockCall::Remove {
+            selector: selector.clone(),
+        }))
+    }
+
+    /// Calls the method with the given name, eg. the plugin of a module.
+    pub fn try_call(
+        &self,
+        method: &str,
+        args: &[&JsValue],
+    ) -> Result<JsValue, FomanticError> {
+        dispatch!(self,
+        sel => sel.call(method, args),
+        selector => {
+            crate::mock::record(crate::mock::MockCall::Call {
+                selector: selector.clone(),
+                method: method.to_string(),
+            });
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Calls the method with the given name, eg. the plugin of a module.
+    /// Errors are thrown as JavaScript exception.
+    pub fn call(&self, method: &str, args: &[&JsValue]) -> JsValue {
+        self.try_call(method, args).unwrap_or_else(|e| e.throw())
+    }
+
+    /// Initializes the given module, eg. `dropdown`, with the settings.
+    pub fn try_init(
+        &self,
+        module: &str,
+        settings: &JsValue,
+    ) -> Result<(), FomanticError> {
+        dispatch!(self,
+        sel => sel.init(module, settings),
+        selector => {
+            crate::mock::record(crate::mock::MockCall::Init {
+                selector: selector.clone(),
+                module: module.to_string(),
+                settings: None,
+            });
+            Ok(())
+        })
+    }
+
+    /// Initializes the given module, eg. `dropdown`, with the settings.
+    pub fn init(&self, module: &str, settings: &JsValue) {
+        self.try_init(module, settings)
+            .unwrap_or_else(|e| e.throw())
+    }
+
+    /// Invokes a behavior of the given module, eg. `refresh`.
+    pub fn try_behavior(
+        &self,
+        module: &str,
+        behavior: &str,
+    ) -> Result<JsValue, FomanticError> {
+        dispatch!(self,
+        sel => sel.behavior(module, behavior, &[]),
+        selector => {
+            crate::mock::record_behavior(selector, module, behavior);
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Invokes a behavior of the given module, eg. `refresh`.
+    pub fn behavior(&self, module: &str, behavior: &str) -> JsValue {
+        self.try_behavior(module, behavior)
+            .unwrap_or_else(|e| e.throw())
+    }
+
+    /// Invokes a behavior of the given module that answers a question, eg.
+    /// `is active`.
+    pub fn try_behavior_bool(
+        &self,
+        module: &str,
+        behavior: &str,
+    ) -> Result<bool, FomanticError> {
+        dispatch!(self,
+        sel => sel
+            .behavior(module, behavior, &[])
+            .map(|result| result.is_truthy()),
+        selector => Ok(crate::mock::record_behavior(
+            selector, module, behavior,
+        )))
+    }
+
+    /// Invokes a behavior of the given module that answers a question, eg.
+    /// `is active`.
+    pub fn behavior_bool(&self, module: &str, behavior: &str) -> bool {
+        self.try_behavior_bool(module, behavior)
+            .unwrap_or_else(|e| e.throw())
+    }
+
+    /// Invokes a behavior of the given module that takes a value.
+    pub fn try_behavior_with(
+        &self,
+        module: &str,
+        behavior: &str,
+        value: &JsValue,
+    ) -> Result<JsValue, FomanticError> {
+        dispatch!(self,
+        sel => sel.behavior(module, behavior, &[value]),
+        selector => {
+            crate::mock::record_behavior(selector, module, behavior);
+            Ok(JsValue::UNDEFINED)
+        })
+    }
+
+    /// Invokes a behavior of the given module that takes a value.
+    pub fn behavior_with(
+        &self,
+        module: &str,
+        behavior: &str,
+        value: &JsValue,
+    ) -> JsValue {
+        self.try_behavior_with(module, behavior, value)
+            .unwrap_or_else(|e| e.throw())
+    }
+}
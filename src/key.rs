@@ -0,0 +1,74 @@
+//! Keyboard keys.
+
+/// A keyboard key, eg. triggering an [Action](crate::Action).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// The enter key.
+    Enter,
+    /// The escape key.
+    Escape,
+    /// The space bar.
+    Space,
+    /// The backspace key.
+    Backspace,
+    /// The delete key.
+    Delete,
+    /// The up arrow key.
+    ArrowUp,
+    /// The down arrow key.
+    ArrowDown,
+    /// The left arrow key.
+    ArrowLeft,
+    /// The right arrow key.
+    ArrowRight,
+    /// A key that produces the given character, compared case insensitive.
+    Char(char),
+}
+
+impl Key {
+    /// Whether the given [KeyboardEvent.key](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key) value matches this key.
+    pub fn matches(&self, key: &str) -> bool {
+        match self {
+            Self::Enter => key == "Enter",
+            Self::Escape => key == "Escape",
+            Self::Space => key == " ",
+            Self::Backspace => key == "Backspace",
+            Self::Delete => key == "Delete",
+            Self::ArrowUp => key == "ArrowUp",
+            Self::ArrowDown => key == "ArrowDown",
+            Self::ArrowLeft => key == "ArrowLeft",
+            Self::ArrowRight => key == "ArrowRight",
+            Self::Char(c) => {
+                let mut chars = key.chars();
+                matches!(
+                    (chars.next(), chars.next()),
+                    (Some(k), None) if k.to_lowercase().eq(c.to_lowercase())
+                )
+            }
+        }
+    }
+}
+
+/// Selector of the elements that take text input.
+#[cfg(any(feature = "modal", feature = "toast"))]
+const EDITABLE: &str =
+    "input, textarea, select, [contenteditable]:not([contenteditable=false])";
+
+/// Returns the keyboard event that may trigger a shortcut, `None` if it
+/// is typed into an editable element, eg. an `input`.
+#[cfg(any(feature = "modal", feature = "toast"))]
+pub(crate) fn shortcut_event(
+    event: &web_sys::Event,
+) -> Option<&web_sys::KeyboardEvent> {
+    use wasm_bindgen::JsCast;
+
+    let editable = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|element| element.closest(EDITABLE).ok().flatten())
+        .is_some();
+    if editable {
+        return None;
+    }
+    event.dyn_ref()
+}
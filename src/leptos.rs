@@ -1,14 +1,240 @@
 //! Leptos components.
+//!
+//! The components render plain HTML and initialize the `fomantic-ui` modules
+//! in effects, which only run in the browser after mounting or hydration.
+//! This makes them usable with server side rendering. Enable `leptos-csr`,
+//! `leptos-hydrate` or `leptos-ssr` matching the rendering mode of the app.
+//!
+//! With [FomanticConfig::lazy_init] set, the modules are only initialized
+//! once their element is scrolled into the viewport, which keeps pages with
+//! many components responsive.
+//!
+//! With `leptos-islands`, only islands are hydrated, so the components have
+//! to be used inside of an island of the app. `PopupIsland` is provided as
+//! ready to use island. The feature enables [FomanticConfig::lazy_init] by
+//! default. Context is not shared between islands, so a [FomanticProvider]
+//! has to be placed in each island that needs a configuration.
 
+// the columns and editors store boxed closures of rows
+#![allow(clippy::type_complexity)]
+
+mod advertisement;
+#[cfg(feature = "bundled-assets")]
+mod assets;
+mod breadcrumb;
+mod card;
+#[cfg(feature = "checkbox")]
 mod checkbox;
+mod comment;
+#[cfg(feature = "dropdown")]
+mod dropdown;
+mod feed;
+mod form_field;
+mod grid;
+mod icon;
+mod input;
+mod item;
 mod label;
+#[cfg(any(
+    feature = "checkbox",
+    feature = "dropdown",
+    feature = "popup",
+    feature = "slider"
+))]
+mod lazy;
+#[cfg(feature = "modal")]
+mod modal;
+mod pagination;
+mod placeholder;
+#[cfg(feature = "popup")]
+mod popup;
+mod provider;
+#[cfg(feature = "table")]
+mod remote_table;
+#[cfg(feature = "checkbox")]
+mod selection;
+#[cfg(feature = "slider")]
+mod slider;
+mod steps;
+#[cfg(feature = "table")]
 mod table;
+#[cfg(feature = "table")]
+mod table_aggregation;
+#[cfg(feature = "table")]
+mod table_column;
+#[cfg(feature = "table")]
+mod table_column_chooser;
+#[cfg(feature = "table")]
+mod table_editor;
+#[cfg(feature = "table")]
+mod table_export;
+#[cfg(feature = "table")]
+mod table_group;
+#[cfg(feature = "table")]
+mod table_key;
+#[cfg(feature = "table")]
+mod table_resize;
+#[cfg(feature = "table")]
 mod table_row;
+#[cfg(feature = "table")]
+mod table_selection;
+#[cfg(feature = "table")]
+mod table_sort;
+#[cfg(feature = "table")]
+mod table_sort_function;
+mod theme;
+#[cfg(feature = "toast")]
+mod toast_container;
+#[cfg(feature = "transition")]
+mod transition;
 
-pub use checkbox::Checkbox;
-pub use label::Label;
+pub use crate::models::{
+    SortDirection,
+    SortState,
+};
+pub use advertisement::{
+    AdUnit,
+    Advertisement,
+};
+#[cfg(feature = "bundled-assets")]
+pub use assets::FomanticAssets;
+pub use breadcrumb::{
+    Breadcrumb,
+    BreadcrumbDivider,
+    BreadcrumbSection,
+};
+pub use card::{
+    Card,
+    CardButtons,
+    CardContent,
+    CardDescription,
+    CardGroup,
+    CardHeader,
+    CardMeta,
+};
+#[cfg(feature = "checkbox")]
+pub use checkbox::{
+    Checkbox,
+    CheckboxGroup,
+    CheckboxVariant,
+    RadioGroup,
+    RadioOption,
+};
+pub use comment::{
+    Comment,
+    CommentGroup,
+};
+#[cfg(feature = "dropdown")]
+pub use dropdown::{
+    Dropdown,
+    DropdownEvent,
+    DropdownOption,
+    MultiSelect,
+};
+pub use feed::{
+    Feed,
+    FeedEvent,
+};
+pub use form_field::FormField;
+pub use grid::{
+    Grid,
+    GridColumn,
+    GridRow,
+};
+pub use icon::Icon;
+pub use input::Input;
+pub use item::{
+    Item,
+    ItemGroup,
+};
+pub use label::{
+    Label,
+    LabelVariant,
+};
+#[cfg(feature = "modal")]
+pub use modal::{
+    use_modal,
+    ModalHandle,
+};
+pub use pagination::Pagination;
+#[cfg(feature = "table")]
+pub use placeholder::placeholder_rows;
+pub use placeholder::{
+    Placeholder,
+    PlaceholderHeader,
+    PlaceholderImage,
+    PlaceholderLength,
+    PlaceholderLine,
+};
+#[cfg(all(feature = "popup", feature = "leptos-islands"))]
+pub use popup::PopupIsland;
+#[cfg(feature = "popup")]
+pub use popup::{
+    Popup,
+    PopupContent,
+    PopupPosition,
+    PopupTrigger,
+};
+pub use provider::{
+    use_fomantic_config,
+    FomanticConfig,
+    FomanticProvider,
+    FomanticTexts,
+};
+#[cfg(feature = "table")]
+pub use remote_table::RemoteTable;
+#[cfg(feature = "checkbox")]
+pub use selection::{
+    AllKeysSelection,
+    KeySelection,
+};
+#[cfg(feature = "slider")]
+pub use slider::Slider;
+pub use steps::{
+    Step,
+    StepController,
+    StepPane,
+    Steps,
+};
+#[cfg(feature = "table")]
 pub use table::{
     Table,
     TableSortingAlgorithm,
+    TableStacking,
+};
+#[cfg(feature = "table")]
+pub use table_aggregation::Aggregation;
+#[cfg(feature = "table")]
+pub use table_column::TableColumn;
+#[cfg(feature = "table")]
+pub use table_editor::CellEditor;
+#[cfg(feature = "table")]
+pub use table_export::{
+    ExportFormat,
+    TableExport,
 };
+#[cfg(feature = "table")]
+pub use table_group::TableGroupBy;
+#[cfg(feature = "table")]
+use table_key::RowKey;
+#[cfg(feature = "table")]
+pub use table_key::TableKey;
+#[cfg(feature = "table")]
 pub use table_row::TableRow;
+#[cfg(feature = "table")]
+pub use table_sort::TableSortMode;
+#[cfg(feature = "table")]
+pub use table_sort_function::register_sort_function;
+pub use theme::{
+    use_theme,
+    ThemeHandle,
+};
+#[cfg(feature = "toast")]
+pub use toast_container::{
+    use_toast,
+    ToastContainer,
+    ToastContent,
+    ToastHandle,
+};
+#[cfg(feature = "transition")]
+pub use transition::Transition;
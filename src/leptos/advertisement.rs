@@ -0,0 +1,116 @@
+use leptos::prelude::*;
+
+/// The standard sizes of an [Advertisement].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AdUnit {
+    /// 300x250.
+    MediumRectangle,
+    /// 336x280.
+    LargeRectangle,
+    /// 240x400.
+    VerticalRectangle,
+    /// 180x150.
+    SmallRectangle,
+    /// 468x60.
+    Banner,
+    /// 120x240.
+    VerticalBanner,
+    /// 930x180.
+    TopBanner,
+    /// 234x60.
+    HalfBanner,
+    /// 120x90.
+    Button,
+    /// 125x125.
+    SquareButton,
+    /// 120x60.
+    SmallButton,
+    /// 120x600.
+    Skyscraper,
+    /// 160x600.
+    WideSkyscraper,
+    /// 728x90.
+    Leaderboard,
+    /// 970x90.
+    LargeLeaderboard,
+    /// 970x250.
+    Billboard,
+    /// 980x120.
+    Panorama,
+    /// 580x400.
+    Netboard,
+    /// 300x600.
+    HalfPage,
+    /// 250x250.
+    Square,
+    /// 200x200.
+    SmallSquare,
+    /// 320x50.
+    MobileBanner,
+    /// 320x50.
+    MobileLeaderboard,
+}
+
+impl std::fmt::Display for AdUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::MediumRectangle => "medium rectangle",
+            Self::LargeRectangle => "large rectangle",
+            Self::VerticalRectangle => "vertical rectangle",
+            Self::SmallRectangle => "small rectangle",
+            Self::Banner => "banner",
+            Self::VerticalBanner => "vertical banner",
+            Self::TopBanner => "top banner",
+            Self::HalfBanner => "half banner",
+            Self::Button => "button",
+            Self::SquareButton => "square button",
+            Self::SmallButton => "small button",
+            Self::Skyscraper => "skyscraper",
+            Self::WideSkyscraper => "wide skyscraper",
+            Self::Leaderboard => "leaderboard",
+            Self::LargeLeaderboard => "large leaderboard",
+            Self::Billboard => "billboard",
+            Self::Panorama => "panorama",
+            Self::Netboard => "netboard",
+            Self::HalfPage => "half page",
+            Self::Square => "square",
+            Self::SmallSquare => "small square",
+            Self::MobileBanner => "mobile banner",
+            Self::MobileLeaderboard => "mobile leaderboard",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Reserves a slot of a standard size for an advertisement.
+#[component]
+pub fn Advertisement(
+    /// The size of the slot.
+    unit: AdUnit,
+    /// Centers the slot in its container.
+    #[prop(optional)]
+    centered: bool,
+    /// Shows the slot with the given text instead of the children, eg. while
+    /// designing the page.
+    #[prop(optional, into)]
+    test: Option<String>,
+    /// The advertisement, eg. the script tag of an ad network.
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let mut class = format!("ui {unit}");
+    if centered {
+        class.push_str(" centered");
+    }
+    if test.is_some() {
+        class.push_str(" test");
+    }
+    class.push_str(" ad");
+    let children = test.is_none().then_some(children).flatten();
+
+    view! {
+        <div class=class data-text=test>
+            { children.map(|children| children()) }
+        </div>
+    }
+}
@@ -0,0 +1,21 @@
+use crate::assets::{
+    FOMANTIC_CSS,
+    FOMANTIC_JS,
+    JQUERY_JS,
+};
+use leptos::prelude::*;
+
+/// Renders the embedded `fomantic-ui` and jQuery assets.
+///
+/// Place it before any other component, eg. in the `<head>` of the shell when
+/// rendering on the server or at the top of the app otherwise. See
+/// [inject_assets](crate::assets::inject_assets) for apps that are not
+/// rendered by leptos completely.
+#[component]
+pub fn FomanticAssets() -> impl IntoView {
+    view! {
+        <style inner_html=FOMANTIC_CSS></style>
+        <script inner_html=JQUERY_JS></script>
+        <script inner_html=FOMANTIC_JS></script>
+    }
+}
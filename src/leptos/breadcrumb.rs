@@ -0,0 +1,129 @@
+use crate::style::Size;
+use leptos::prelude::*;
+
+/// The divider shown between the sections of a [Breadcrumb].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum BreadcrumbDivider {
+    /// A slash, the default.
+    #[default]
+    Slash,
+    /// A chevron icon pointing to the right.
+    Chevron,
+}
+
+/// A section of a [Breadcrumb].
+#[derive(Clone, PartialEq, Debug)]
+pub struct BreadcrumbSection {
+    /// The text of the section.
+    pub label: String,
+    /// The address the section links to.
+    pub href: Option<String>,
+}
+
+impl<L: ToString, H: ToString> From<(L, H)> for BreadcrumbSection {
+    fn from((label, href): (L, H)) -> Self {
+        Self {
+            label: label.to_string(),
+            href: Some(href.to_string()),
+        }
+    }
+}
+
+/// Derives the sections from the segments of the given path.
+#[cfg(feature = "leptos-router")]
+fn sections_from_path(path: &str, home: &str) -> Vec<BreadcrumbSection> {
+    let mut href = String::new();
+    std::iter::once(BreadcrumbSection {
+        label: home.to_string(),
+        href: Some("/".to_string()),
+    })
+    .chain(path.split('/').filter(|s| !s.is_empty()).map(|segment| {
+        href.push('/');
+        href.push_str(segment);
+        BreadcrumbSection {
+            label: segment.replace(['-', '_'], " "),
+            href: Some(href.clone()),
+        }
+    }))
+    .collect()
+}
+
+/// A `fomantic-ui` breadcrumb. The last section is shown as active.
+///
+/// With the `leptos-router` feature enabled, the sections are derived from
+/// the current route if none are given.
+#[component]
+pub fn Breadcrumb(
+    /// The sections, eg. from `(label, href)` pairs.
+    #[prop(optional, into)]
+    sections: Option<Signal<Vec<BreadcrumbSection>>>,
+    /// The divider between the sections.
+    #[prop(optional)]
+    divider: BreadcrumbDivider,
+    /// Size of the breadcrumb.
+    #[prop(optional)]
+    size: Option<Size>,
+) -> impl IntoView {
+    let sections = match sections {
+        Some(sections) => Signal::derive(move || sections.get()),
+        #[cfg(feature = "leptos-router")]
+        None => {
+            let location = leptos_router::hooks::use_location();
+            let home = super::use_fomantic_config().texts.home;
+            Signal::derive(move || {
+                location
+                    .pathname
+                    .with(|path| sections_from_path(path, &home))
+            })
+        }
+        #[cfg(not(feature = "leptos-router"))]
+        None => Signal::derive(Vec::new),
+    };
+    let class = match size {
+        Some(size) => format!("ui {size} breadcrumb"),
+        None => "ui breadcrumb".to_string(),
+    };
+
+    let items = move || {
+        let sections = sections.get();
+        let last = sections.len().saturating_sub(1);
+        sections
+            .into_iter()
+            .enumerate()
+            .map(|(idx, section)| {
+                let divider = (idx > 0).then(|| match divider {
+                    BreadcrumbDivider::Slash => view! {
+                        <span class="divider">"/"</span>
+                    }
+                    .into_any(),
+                    BreadcrumbDivider::Chevron => view! {
+                        <i class="right chevron icon divider"></i>
+                    }
+                    .into_any(),
+                });
+                let section = match section.href {
+                    Some(href) if idx != last => view! {
+                        <a class="section" href=href>{ section.label }</a>
+                    }
+                    .into_any(),
+                    _ => view! {
+                        <div class="section" class:active=idx == last>
+                            { section.label }
+                        </div>
+                    }
+                    .into_any(),
+                };
+                view! {
+                    { divider }
+                    { section }
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class=class>
+            { items }
+        </div>
+    }
+}
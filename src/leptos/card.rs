@@ -0,0 +1,175 @@
+use crate::style::{
+    Color,
+    ColumnWidth,
+};
+use leptos::prelude::*;
+
+/// A `fomantic-ui` card.
+#[component]
+pub fn Card(
+    /// Color of the card.
+    #[prop(optional)]
+    color: Option<Color>,
+    /// Takes the full width of its container.
+    #[prop(optional)]
+    fluid: bool,
+    /// Centers the card in its container.
+    #[prop(optional)]
+    centered: bool,
+    /// Raises the card above the page.
+    #[prop(optional)]
+    raised: bool,
+    /// Makes the whole card a link to the given address.
+    #[prop(optional, into)]
+    href: Option<String>,
+    /// The contents of the card.
+    children: Children,
+) -> impl IntoView {
+    let mut class = "ui".to_string();
+    if let Some(color) = color {
+        class.push_str(&format!(" {color}"));
+    }
+    if fluid {
+        class.push_str(" fluid");
+    }
+    if centered {
+        class.push_str(" centered");
+    }
+    if raised {
+        class.push_str(" raised");
+    }
+    class.push_str(" card");
+
+    match href {
+        Some(href) => view! {
+            <a class=class href=href>
+                { children() }
+            </a>
+        }
+        .into_any(),
+        None => view! {
+            <div class=class>
+                { children() }
+            </div>
+        }
+        .into_any(),
+    }
+}
+
+/// A group of [Card]s sharing the same size.
+#[component]
+pub fn CardGroup(
+    /// Amount of cards per row.
+    #[prop(optional)]
+    count: Option<ColumnWidth>,
+    /// Reduces the amount of cards per row on smaller screens.
+    #[prop(optional)]
+    doubling: bool,
+    /// Shows the cards below each other on mobile.
+    #[prop(optional)]
+    stackable: bool,
+    /// Centers the cards in the group.
+    #[prop(optional)]
+    centered: bool,
+    /// The cards of the group.
+    children: Children,
+) -> impl IntoView {
+    let mut class = "ui".to_string();
+    if let Some(count) = count {
+        class.push_str(&format!(" {}", count.as_word()));
+    }
+    if doubling {
+        class.push_str(" doubling");
+    }
+    if stackable {
+        class.push_str(" stackable");
+    }
+    if centered {
+        class.push_str(" centered");
+    }
+    class.push_str(" cards");
+
+    view! {
+        <div class=class>
+            { children() }
+        </div>
+    }
+}
+
+/// A content section of a [Card].
+#[component]
+pub fn CardContent(
+    /// Shows the content as extra content at the bottom of the card.
+    #[prop(optional)]
+    extra: bool,
+    /// The contents of the section.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class="content" class:extra=extra>
+            { children() }
+        </div>
+    }
+}
+
+/// The header of a [Card].
+#[component]
+pub fn CardHeader(
+    /// The header text.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class="header">
+            { children() }
+        </div>
+    }
+}
+
+/// Metadata of a [Card], eg. a date.
+#[component]
+pub fn CardMeta(
+    /// The metadata.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class="meta">
+            { children() }
+        </div>
+    }
+}
+
+/// The description of a [Card].
+#[component]
+pub fn CardDescription(
+    /// The description text.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class="description">
+            { children() }
+        </div>
+    }
+}
+
+/// Buttons at the bottom of a [Card] that share its width.
+#[component]
+pub fn CardButtons(
+    /// Amount of buttons.
+    #[prop(optional)]
+    count: Option<ColumnWidth>,
+    /// The buttons.
+    children: Children,
+) -> impl IntoView {
+    let class = match count {
+        Some(count) => format!("ui {} buttons", count.as_word()),
+        None => "ui buttons".to_string(),
+    };
+
+    view! {
+        <div class="extra content">
+            <div class=class>
+                { children() }
+            </div>
+        </div>
+    }
+}
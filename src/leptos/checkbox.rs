@@ -1,24 +1,120 @@
-use crate::models::Selectable;
+use super::{
+    lazy::init_module,
+    AllKeysSelection,
+    KeySelection,
+};
+use crate::{
+    callbacks::CallbackRegistry,
+    jq::JQuery,
+    models::{
+        Selectable,
+        SelectionSet,
+        SelectionState,
+    },
+    modules::InstanceGuard,
+    style::Classes,
+};
 use leptos::{
-    html::ElementDescriptor,
-    *,
+    html,
+    prelude::*,
+};
+use std::{
+    hash::Hash,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
 };
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = Object)]
+    type JsCheckboxConfig;
+    /// Configuration constructor.
+    #[wasm_bindgen(constructor, js_class = Object)]
+    fn new() -> JsCheckboxConfig;
+    /// Sets the handler that is called before the checkbox is checked,
+    /// returning `false` cancels the change.
+    #[wasm_bindgen(method, setter, js_name = "beforeChecked")]
+    fn set_before_checked(this: &JsCheckboxConfig, handler: &js_sys::Function);
+    /// Sets the handler that is called before the checkbox is unchecked,
+    /// returning `false` cancels the change.
+    #[wasm_bindgen(method, setter, js_name = "beforeUnchecked")]
+    fn set_before_unchecked(
+        this: &JsCheckboxConfig,
+        handler: &js_sys::Function,
+    );
+}
+
+/// Counter used to generate unique ids of checkboxes and names of
+/// [RadioGroup]s.
+static CHECKBOX_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The appearance of a [Checkbox].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum CheckboxVariant {
+    /// A regular checkbox, the default.
+    #[default]
+    Plain,
+    /// A switch to toggle an option on or off.
+    Toggle,
+    /// A slider to toggle an option on or off.
+    Slider,
+}
+
+impl std::fmt::Display for CheckboxVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Plain => write!(f, ""),
+            Self::Toggle => write!(f, "toggle"),
+            Self::Slider => write!(f, "slider"),
+        }
+    }
+}
 
 /// A checkbox with data attached.
+///
+/// Shown as indeterminate while the data is only partially selected, see
+/// [Selectable::is_indeterminate].
+///
+/// If `init` is set, the `fomantic-ui` checkbox module is initialized on
+/// mount, enabling its animations and the `before_checked` and
+/// `before_unchecked` callbacks. It is destroyed on unmount.
 #[component]
-#[allow(unused_braces)]
-pub fn Checkbox<D, El>(
-    checkbox_wrapper: Box<dyn Fn() -> HtmlElement<El>>,
+pub fn Checkbox<D>(
+    /// Additional classes of the checkbox, eg. `fitted`.
+    #[prop(optional, into)]
+    class: Classes,
+    /// The appearance of the checkbox.
+    #[prop(optional)]
+    variant: CheckboxVariant,
+    /// Text shown next to the checkbox.
+    #[prop(optional, into)]
+    label: Option<String>,
+    /// Disables the checkbox.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+    /// Initializes the `fomantic-ui` checkbox module.
+    #[prop(optional)]
+    init: bool,
+    /// Called before the checkbox is checked, returning `false` cancels it.
+    /// Requires `init`.
+    #[prop(optional, into)]
+    before_checked: Option<Callback<(), bool>>,
+    /// Called before the checkbox is unchecked, returning `false` cancels
+    /// it. Requires `init`.
+    #[prop(optional, into)]
+    before_unchecked: Option<Callback<(), bool>>,
     data: RwSignal<D>,
 ) -> impl IntoView
 where
-    D: Selectable + 'static,
-    El: ElementDescriptor + 'static,
+    D: Selectable + Send + Sync + 'static,
 {
-    // this might be optimized at a later stage,
-    // `on_change` triggers `is_checked` but it is not necessary
-    // but if this is untracked, it is not triggered from the outside anymore
-    let is_checked = move || data.with(|d| d.is_selected());
+    // computed once per change, groups iterate over all of their items
+    let state = Memo::new(move |_| data.with(|d| d.selection_state()));
+    let is_checked = move || state.get() == SelectionState::All;
+    let is_indeterminate = move || state.get() == SelectionState::Some;
 
     let on_change = move |e: web_sys::Event| {
         data.update(|d| {
@@ -30,17 +126,233 @@ where
         });
     };
 
-    let input_view = view! {
-        <input
-            prop:checked=is_checked
-            type="checkbox"
-            on:change=on_change
-            />
+    let class = Classes::from("ui")
+        .with(class)
+        .with(variant)
+        .with("checkbox")
+        .to_string();
+    let id = format!(
+        "fomantic-checkbox-{}",
+        CHECKBOX_COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+
+    let ref_checkbox = NodeRef::<html::Div>::new();
+    let initialized = RwSignal::new(false);
+    init_module(ref_checkbox, move |checkbox| {
+        if !init {
+            return None;
+        }
+        let config = JsCheckboxConfig::new();
+        // the handlers must outlive the module, they are dropped with the
+        // guard
+        let callbacks = CallbackRegistry::new();
+        if let Some(before_checked) = before_checked {
+            config.set_before_checked(
+                &callbacks.set("beforeChecked", move |_| {
+                    before_checked.run(()).into()
+                }),
+            );
+        }
+        if let Some(before_unchecked) = before_unchecked {
+            config.set_before_unchecked(
+                &callbacks.set("beforeUnchecked", move |_| {
+                    before_unchecked.run(()).into()
+                }),
+            );
+        }
+        let checkbox = JQuery::from_element(checkbox);
+        checkbox.init("checkbox", &config);
+        initialized.set(true);
+        Some(InstanceGuard::new(checkbox, "checkbox").owning(callbacks))
+    });
+
+    // reflects changes of the data in the module, without callbacks
+    Effect::new(move |_| {
+        let behavior = if is_indeterminate() {
+            "set indeterminate"
+        } else if is_checked() {
+            "set checked"
+        } else {
+            "set unchecked"
+        };
+        let enabled = if disabled.get() {
+            "set disabled"
+        } else {
+            "set enabled"
+        };
+        if !initialized.get() {
+            return;
+        }
+        if let Some(checkbox) = ref_checkbox.get_untracked() {
+            let checkbox = JQuery::from_element(&checkbox);
+            checkbox.behavior("checkbox", behavior);
+            checkbox.behavior("checkbox", enabled);
+        }
+    });
+
+    // the label is required by fomantic-ui to draw the box, even if empty
+    view! {
+        <div
+            node_ref=ref_checkbox
+            class=class
+            class:indeterminate=is_indeterminate
+            class:disabled=disabled>
+            <input
+                id=id.clone()
+                prop:checked=is_checked
+                prop:indeterminate=is_indeterminate
+                prop:disabled=disabled
+                type="checkbox"
+                on:change=on_change
+                />
+            <label for=id>{ label }</label>
+        </div>
+    }
+}
+
+/// An option of a [RadioGroup].
+#[derive(Clone, PartialEq, Debug)]
+pub struct RadioOption<T> {
+    /// The value that is stored when the option is selected.
+    pub value: T,
+    /// The text shown next to the radio button.
+    pub label: String,
+}
+
+impl<T, L: ToString> From<(T, L)> for RadioOption<T> {
+    fn from((value, label): (T, L)) -> Self {
+        Self {
+            value,
+            label: label.to_string(),
+        }
+    }
+}
+
+/// A group of radio checkboxes bound to a single value, only one of the
+/// options can be selected at a time.
+#[component]
+pub fn RadioGroup<T>(
+    /// The options of the group.
+    #[prop(into)]
+    options: Vec<RadioOption<T>>,
+    /// The selected value.
+    value: RwSignal<T>,
+    /// Name of the radio inputs, eg. for form submission. A unique name is
+    /// generated if not set.
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// Shows the options next to each other instead of below each other.
+    #[prop(optional)]
+    inline: bool,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let name = name.unwrap_or_else(|| {
+        format!(
+            "fomantic-radio-group-{}",
+            CHECKBOX_COUNTER.fetch_add(1, Ordering::Relaxed)
+        )
+    });
+    let class = if inline {
+        "inline fields"
+    } else {
+        "grouped fields"
+    };
+
+    let items = options
+        .into_iter()
+        .enumerate()
+        .map(|(idx, option)| {
+            let id = format!("{name}-{idx}");
+            let option_value = option.value.clone();
+            let is_checked = move || value.with(|v| *v == option_value);
+            let on_change = move |_| value.set(option.value.clone());
+            view! {
+                <div class="field">
+                    <div class="ui radio checkbox">
+                        <input
+                            id=id.clone()
+                            type="radio"
+                            name=name.clone()
+                            prop:checked=is_checked
+                            on:change=on_change
+                            />
+                        <label for=id>{ option.label }</label>
+                    </div>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class=class>
+            { items }
+        </div>
+    }
+}
+
+/// A checkbox for every item of a signal, bound to the set of selected keys.
+#[component]
+pub fn CheckboxGroup<T, K>(
+    /// The items of the group.
+    #[prop(into)]
+    items: Signal<Vec<T>>,
+    /// Returns the key of an item, which is stored when it is selected.
+    #[prop(into)]
+    key: Callback<T, K>,
+    /// Returns the text shown next to the checkbox of an item.
+    #[prop(into)]
+    label: Callback<T, String>,
+    /// The keys of the selected items.
+    selected: RwSignal<SelectionSet<K>>,
+    /// Shows a checkbox in front of the items that selects all of them, with
+    /// the given label.
+    #[prop(optional, into)]
+    select_all: Option<String>,
+    /// Shows the checkboxes next to each other instead of below each other.
+    #[prop(optional)]
+    inline: bool,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    let class = if inline {
+        "inline fields"
+    } else {
+        "grouped fields"
+    };
+
+    let select_all = select_all.map(|select_all| {
+        let all_keys = RwSignal::new(AllKeysSelection::new(
+            Signal::derive(move || {
+                items.get().into_iter().map(|item| key.run(item)).collect()
+            }),
+            selected,
+        ));
+        view! {
+            <div class="field">
+                <Checkbox label=select_all data=all_keys/>
+            </div>
+        }
+    });
+
+    let item_key = move |item: &T| key.run(item.clone());
+    let item = move |item: T| {
+        let data =
+            RwSignal::new(KeySelection::new(key.run(item.clone()), selected));
+        view! {
+            <div class="field">
+                <Checkbox label=label.run(item) data=data/>
+            </div>
+        }
     };
-    let checkbox_wrapper =
-        checkbox_wrapper().child(input_view).classes("ui checkbox");
 
     view! {
-        { checkbox_wrapper }
+        <div class=class>
+            { select_all }
+            <For each=move || items.get() key=item_key children=item/>
+        </div>
     }
 }
@@ -0,0 +1,118 @@
+use leptos::prelude::*;
+
+/// A group of [Comment]s.
+#[component]
+pub fn CommentGroup(
+    /// Shows the replies of a comment connected by a line.
+    #[prop(optional)]
+    threaded: bool,
+    /// Hides the actions of the comments until they are hovered.
+    #[prop(optional)]
+    minimal: bool,
+    /// A form to add a comment, shown below the comments.
+    #[prop(optional, into)]
+    reply_form: Option<ViewFn>,
+    /// The comments of the group.
+    children: Children,
+) -> impl IntoView {
+    let mut class = "ui".to_string();
+    if threaded {
+        class.push_str(" threaded");
+    }
+    if minimal {
+        class.push_str(" minimal");
+    }
+    class.push_str(" comments");
+
+    let reply_form = reply_form.map(|reply_form| {
+        view! {
+            <div class="ui reply form">
+                { reply_form.run() }
+            </div>
+        }
+    });
+
+    view! {
+        <div class=class>
+            { children() }
+            { reply_form }
+        </div>
+    }
+}
+
+/// A comment of a [CommentGroup].
+#[component]
+pub fn Comment(
+    /// Name of the author.
+    #[prop(into)]
+    author: String,
+    /// Source of the avatar image of the author.
+    #[prop(optional, into)]
+    avatar: Option<String>,
+    /// When the comment was written, eg. `Today at 5:42PM`.
+    #[prop(optional, into)]
+    date: Option<String>,
+    /// The actions of the comment, eg. a reply link.
+    #[prop(optional, into)]
+    actions: Option<ViewFn>,
+    /// A form to reply to the comment, shown below the actions.
+    #[prop(optional, into)]
+    reply_form: Option<ViewFn>,
+    /// The replies to the comment, a list of [Comment]s.
+    #[prop(optional, into)]
+    replies: Option<ViewFn>,
+    /// The text of the comment.
+    children: Children,
+) -> impl IntoView {
+    let avatar = avatar.map(|src| {
+        view! {
+            <div class="avatar">
+                <img src=src/>
+            </div>
+        }
+    });
+    let date = date.map(|date| {
+        view! {
+            <div class="metadata">
+                <span class="date">{ date }</span>
+            </div>
+        }
+    });
+    let actions = actions.map(|actions| {
+        view! {
+            <div class="actions">
+                { actions.run() }
+            </div>
+        }
+    });
+    let reply_form = reply_form.map(|reply_form| {
+        view! {
+            <div class="ui reply form">
+                { reply_form.run() }
+            </div>
+        }
+    });
+    let replies = replies.map(|replies| {
+        view! {
+            <div class="comments">
+                { replies.run() }
+            </div>
+        }
+    });
+
+    view! {
+        <div class="comment">
+            { avatar }
+            <div class="content">
+                <span class="author">{ author }</span>
+                { date }
+                <div class="text">
+                    { children() }
+                </div>
+                { actions }
+                { reply_form }
+            </div>
+            { replies }
+        </div>
+    }
+}
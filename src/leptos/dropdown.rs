@@ -0,0 +1,441 @@
+use super::{
+    lazy::init_module,
+    use_fomantic_config,
+};
+use crate::{
+    a11y::{
+        self,
+        Aria,
+    },
+    callbacks::CallbackRegistry,
+    events::EventSender,
+    jq::JQuery,
+    macros::module_config,
+    models::{
+        Filterable,
+        Identifiable,
+    },
+    modules::InstanceGuard,
+    style::TransitionName,
+};
+use leptos::{
+    html,
+    prelude::*,
+};
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+use std::fmt::Display;
+use wasm_bindgen::prelude::*;
+
+module_config! {
+    /// Settings of the dropdown module.
+    struct DropdownConfig(JsDropdownConfig) {
+        /// Sets wether the selection can be cleared.
+        clearable: bool = "clearable",
+        /// Sets wether the user can add values that are not part of the
+        /// options.
+        allow_additions: bool = "allowAdditions",
+        /// Sets the duration of the transition in milliseconds.
+        duration: u32 = "duration",
+        /// Sets the animation of the menu.
+        transition: &str = "transition",
+        /// Sets the handler that is called when the menu starts to show.
+        on_show: &js_sys::Function = "onShow",
+        /// Sets the handler that is called when the menu starts to hide.
+        on_hide: &js_sys::Function = "onHide",
+        /// Sets the handler that is called when the selection changes.
+        on_change: &js_sys::Function = "onChange",
+    }
+}
+
+/// Separates the values of a multiple selection [Dropdown].
+const VALUE_DELIMITER: &str = ",";
+
+/// An option of a [Dropdown].
+#[derive(Clone, PartialEq, Debug)]
+pub struct DropdownOption {
+    /// The value that is stored when the option is selected.
+    pub value: String,
+    /// The text shown for the option.
+    pub label: String,
+}
+
+impl DropdownOption {
+    /// Creates options from `(value, label)` pairs.
+    pub fn pairs<V, L>(
+        options: impl IntoIterator<Item = (V, L)>,
+    ) -> Vec<DropdownOption>
+    where
+        V: ToString,
+        L: ToString,
+    {
+        options.into_iter().map(Self::from).collect()
+    }
+
+    /// Creates options that use their [Identifiable::id] as value and their
+    /// [Display] output as label.
+    pub fn identified<T>(
+        options: impl IntoIterator<Item = T>,
+    ) -> Vec<DropdownOption>
+    where
+        T: Identifiable + Display,
+        T::Id: ToString,
+    {
+        options
+            .into_iter()
+            .map(|option| Self {
+                value: option.id().to_string(),
+                label: option.to_string(),
+            })
+            .collect()
+    }
+
+    /// Creates options that use their [Display] output as value and label.
+    pub fn displayed<T: Display>(
+        options: impl IntoIterator<Item = T>,
+    ) -> Vec<DropdownOption> {
+        options
+            .into_iter()
+            .map(|option| {
+                let value = option.to_string();
+                Self {
+                    label: value.clone(),
+                    value,
+                }
+            })
+            .collect()
+    }
+}
+
+impl<V: ToString, L: ToString> From<(V, L)> for DropdownOption {
+    fn from((value, label): (V, L)) -> Self {
+        Self {
+            value: value.to_string(),
+            label: label.to_string(),
+        }
+    }
+}
+
+impl Filterable for DropdownOption {
+    fn matches(&self, query: &str) -> bool {
+        self.label.matches(query) || self.value.matches(query)
+    }
+
+    /// Matches the `value` or `label` field, or both for other names.
+    fn matches_field(&self, field: &str, query: &str) -> bool {
+        match field {
+            "value" => self.value.matches(query),
+            "label" => self.label.matches(query),
+            _ => self.matches(query),
+        }
+    }
+}
+
+/// An event of a [Dropdown], emitted into its `events`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DropdownEvent {
+    /// The user changed the selection to the given values.
+    Change(Vec<String>),
+    /// The menu starts to show.
+    Show,
+    /// The menu starts to hide.
+    Hide,
+}
+
+/// A `fomantic-ui` selection dropdown, bound to a signal.
+///
+/// The dropdown module is initialized on mount and destroyed on unmount.
+#[component]
+pub fn Dropdown(
+    /// The options that can be selected.
+    #[prop(into)]
+    options: Signal<Vec<DropdownOption>>,
+    /// The selected values. Holds at most one value unless `multiple` is set.
+    value: RwSignal<Vec<String>>,
+    /// Allows selecting multiple options.
+    #[prop(optional)]
+    multiple: bool,
+    /// Allows searching the options by typing.
+    #[prop(optional)]
+    search: bool,
+    /// Allows clearing the selection.
+    #[prop(optional)]
+    clearable: bool,
+    /// Allows the user to add values that are not part of the options.
+    /// Requires `search`.
+    #[prop(optional)]
+    allow_additions: bool,
+    /// Takes the full width of its container.
+    #[prop(optional)]
+    fluid: bool,
+    /// Disables the dropdown.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+    /// Text shown while nothing is selected.
+    #[prop(optional, into)]
+    placeholder: MaybeProp<String>,
+    /// Name of the hidden input holding the value, eg. for form submission.
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+    /// The animation of the menu, chosen by its position if not set.
+    #[prop(optional)]
+    transition: Option<TransitionName>,
+    /// ARIA attributes labelling the dropdown.
+    #[prop(optional, into)]
+    aria: Aria,
+    /// Receives the events of the dropdown, see
+    /// [EventStream::channel](crate::events::EventStream::channel).
+    #[prop(optional)]
+    events: Option<EventSender<DropdownEvent>>,
+) -> impl IntoView {
+    let mut class = "ui".to_string();
+    if search {
+        class.push_str(" search");
+    }
+    if multiple {
+        class.push_str(" multiple");
+    }
+    class.push_str(" selection dropdown");
+
+    let duration =
+        a11y::transition_duration(use_fomantic_config().transition_duration);
+    let ref_dropdown = NodeRef::<html::Div>::new();
+    let initialized = RwSignal::new(false);
+    init_module(ref_dropdown, move |dropdown| {
+        let config = DropdownConfig::new();
+        config.clearable(clearable);
+        config.allow_additions(allow_additions);
+        if let Some(duration) = duration {
+            config.duration(duration);
+        }
+        if let Some(transition) = transition {
+            config.transition(&transition.to_string());
+        }
+        let change_events = events.clone();
+        // the handlers must outlive the module, they are dropped with the
+        // guard
+        let callbacks = CallbackRegistry::new();
+        config.on_change(&callbacks.set(
+            "onChange",
+            move |selected: JsValue| {
+                let selected = selected
+                    .as_string()
+                    .unwrap_or_default()
+                    .split(VALUE_DELIMITER)
+                    .filter(|v| !v.is_empty())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>();
+                // prevents a loop with the effect below
+                if value.with_untracked(|v| *v != selected) {
+                    if let Some(ref events) = change_events {
+                        events.send(DropdownEvent::Change(selected.clone()));
+                    }
+                    value.set(selected);
+                }
+                JsValue::UNDEFINED
+            },
+        ));
+        if let Some(ref events) = events {
+            let emit = |event: DropdownEvent| {
+                let events = events.clone();
+                move |_| {
+                    events.send(event.clone());
+                    JsValue::TRUE
+                }
+            };
+            config.on_show(&callbacks.set("onShow", emit(DropdownEvent::Show)));
+            config.on_hide(&callbacks.set("onHide", emit(DropdownEvent::Hide)));
+        }
+        let dropdown = JQuery::from_element(dropdown);
+        dropdown.init("dropdown", config.as_ref());
+        initialized.set(true);
+        Some(InstanceGuard::new(dropdown, "dropdown").owning(callbacks))
+    });
+
+    // reflects changes of the options and the value in the module
+    Effect::new(move |_| {
+        options.track();
+        let selected = value.get();
+        if !initialized.get() {
+            return;
+        }
+        let Some(dropdown) = ref_dropdown.get_untracked() else {
+            return;
+        };
+        let dropdown = JQuery::from_element(&dropdown);
+        dropdown.behavior("dropdown", "refresh");
+        if selected.is_empty() {
+            dropdown.behavior("dropdown", "clear");
+        } else if multiple {
+            let selected = selected
+                .iter()
+                .map(|v| JsValue::from_str(v))
+                .collect::<js_sys::Array>();
+            dropdown.behavior_with("dropdown", "set exactly", &selected);
+        } else {
+            dropdown.behavior_with(
+                "dropdown",
+                "set selected",
+                &JsValue::from_str(&selected[0]),
+            );
+        }
+    });
+
+    let items = move || {
+        options
+            .get()
+            .into_iter()
+            .map(|option| {
+                view! {
+                    <div class="item" data-value=option.value>
+                        { option.label }
+                    </div>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div
+            node_ref=ref_dropdown
+            class=class
+            class:fluid=fluid
+            class:disabled=disabled
+            aria-label=aria.label
+            aria-labelledby=aria.labelled_by
+            aria-describedby=aria.described_by>
+            <input
+                type="hidden"
+                name=move || name.get()
+                prop:value=move || value.with(|v| v.join(VALUE_DELIMITER))/>
+            <i class="dropdown icon"></i>
+            <div class="default text">{ move || placeholder.get() }</div>
+            <div class="menu">
+                { items }
+            </div>
+        </div>
+    }
+}
+
+/// Encodes a value of a [MultiSelect] as option value. The delimiter is
+/// escaped, as it might be part of the serialized value.
+fn encode_value<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value)
+        .unwrap_or_default()
+        .replace('%', "%25")
+        .replace(VALUE_DELIMITER, "%2C")
+}
+
+/// Decodes an option value of a [MultiSelect]. Values added by the user are
+/// not encoded, they are parsed as JSON or used as string.
+fn decode_value<T: DeserializeOwned>(value: &str) -> Option<T> {
+    let decoded = value.replace("%2C", VALUE_DELIMITER).replace("%25", "%");
+    serde_json::from_str(&decoded)
+        .or_else(|_| serde_json::from_str(value))
+        .or_else(|_| {
+            serde_json::from_value(serde_json::Value::String(value.into()))
+        })
+        .ok()
+}
+
+/// A multiple selection [Dropdown] bound to a list of typed values.
+///
+/// The values are serialized to option values, the [Display] output is used
+/// as label. With `allow_additions`, values entered by the user are
+/// deserialized from their text, those that do not fit `T` are dropped.
+#[component]
+pub fn MultiSelect<T>(
+    /// The values that can be selected.
+    #[prop(into)]
+    options: Signal<Vec<T>>,
+    /// The selected values.
+    value: RwSignal<Vec<T>>,
+    /// Allows searching the options by typing.
+    #[prop(optional)]
+    search: bool,
+    /// Allows the user to add values that are not part of the options.
+    #[prop(optional)]
+    allow_additions: bool,
+    /// Allows clearing the selection.
+    #[prop(optional)]
+    clearable: bool,
+    /// Takes the full width of its container.
+    #[prop(optional)]
+    fluid: bool,
+    /// Disables the dropdown.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+    /// Text shown while nothing is selected.
+    #[prop(optional, into)]
+    placeholder: MaybeProp<String>,
+    /// Name of the hidden input holding the value, eg. for form submission.
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+) -> impl IntoView
+where
+    T: Serialize
+        + DeserializeOwned
+        + Display
+        + Clone
+        + PartialEq
+        + Send
+        + Sync
+        + 'static,
+{
+    let encoded = RwSignal::new(
+        value.with_untracked(|v| v.iter().map(encode_value).collect()),
+    );
+    // reflects changes in both directions, the checks prevent a loop
+    Effect::new(move |_| {
+        let selected =
+            value.with(|v| v.iter().map(encode_value).collect::<Vec<String>>());
+        if encoded.with_untracked(|e| *e != selected) {
+            encoded.set(selected);
+        }
+    });
+    Effect::new(move |_| {
+        let selected = encoded.with(|e| {
+            e.iter().filter_map(|e| decode_value(e)).collect::<Vec<T>>()
+        });
+        if value.with_untracked(|v| *v != selected) {
+            value.set(selected);
+        }
+    });
+
+    // selected values that are not part of the options, eg. additions, are
+    // shown as well
+    let options = Signal::derive(move || {
+        let mut options = options.get();
+        value.with(|v| {
+            for selected in v {
+                if !options.contains(selected) {
+                    options.push(selected.clone());
+                }
+            }
+        });
+        options
+            .iter()
+            .map(|option| DropdownOption {
+                value: encode_value(option),
+                label: option.to_string(),
+            })
+            .collect::<Vec<_>>()
+    });
+
+    view! {
+        <Dropdown
+            options=options
+            value=encoded
+            multiple=true
+            search=search || allow_additions
+            clearable=clearable
+            allow_additions=allow_additions
+            fluid=fluid
+            disabled=disabled
+            placeholder=placeholder
+            name=name
+        />
+    }
+}
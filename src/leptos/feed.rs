@@ -0,0 +1,97 @@
+use crate::style::{
+    IconName,
+    Size,
+};
+use leptos::prelude::*;
+
+/// A `fomantic-ui` feed, showing [FeedEvent]s in chronological order.
+#[component]
+pub fn Feed(
+    /// Size of the feed.
+    #[prop(optional)]
+    size: Option<Size>,
+    /// The events of the feed.
+    children: Children,
+) -> impl IntoView {
+    let class = match size {
+        Some(size) => format!("ui {size} feed"),
+        None => "ui feed".to_string(),
+    };
+
+    view! {
+        <div class=class>
+            { children() }
+        </div>
+    }
+}
+
+/// An event of a [Feed].
+#[component]
+pub fn FeedEvent(
+    /// Summary of the event, eg. who did what.
+    #[prop(into)]
+    summary: String,
+    /// When the event happened, eg. `3 days ago`.
+    #[prop(optional, into)]
+    date: Option<String>,
+    /// Source of the image shown next to the event, eg. an avatar.
+    #[prop(optional, into)]
+    image: Option<String>,
+    /// The icon shown next to the event if there is no image.
+    #[prop(optional, into)]
+    icon: Option<IconName>,
+    /// Content shown below the text, eg. a like button.
+    #[prop(optional, into)]
+    meta: Option<ViewFn>,
+    /// Additional text of the event.
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let label = match (image, icon) {
+        (Some(src), _) => Some(view! { <img src=src/> }.into_any()),
+        (None, Some(icon)) => {
+            Some(view! { <i class=icon.to_string()></i> }.into_any())
+        }
+        (None, None) => None,
+    };
+    let label = label.map(|label| {
+        view! {
+            <div class="label">
+                { label }
+            </div>
+        }
+    });
+    let date = date.map(|date| {
+        view! {
+            <div class="date">{ date }</div>
+        }
+    });
+    let text = children.map(|children| {
+        view! {
+            <div class="extra text">
+                { children() }
+            </div>
+        }
+    });
+    let meta = meta.map(|meta| {
+        view! {
+            <div class="meta">
+                { meta.run() }
+            </div>
+        }
+    });
+
+    view! {
+        <div class="event">
+            { label }
+            <div class="content">
+                <div class="summary">
+                    { summary }
+                    { date }
+                </div>
+                { text }
+                { meta }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,64 @@
+use crate::models::ValidationErrors;
+use leptos::prelude::*;
+
+/// A field of a `fomantic-ui` form, wrapping any input with a label and an
+/// error message.
+///
+/// While `error` holds a message, the field is marked as erroneous and the
+/// message is shown in a label pointing to the input. Alternatively, the
+/// first error of the field `name` in `errors` is shown, eg. the result of
+/// [Validatable::validate](crate::models::Validatable::validate).
+#[component]
+pub fn FormField(
+    /// Text of the label above the input.
+    #[prop(optional, into)]
+    label: Option<String>,
+    /// Id of the input the label belongs to.
+    #[prop(optional, into)]
+    label_for: Option<String>,
+    /// Marks the field as required.
+    #[prop(optional)]
+    required: bool,
+    /// The error message of the field.
+    #[prop(optional, into)]
+    error: Signal<Option<String>>,
+    /// Name of the field in `errors`.
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// The errors of the model the field belongs to. Ignored while `error`
+    /// holds a message.
+    #[prop(optional, into)]
+    errors: Option<Signal<ValidationErrors>>,
+    /// The input of the field.
+    children: Children,
+) -> impl IntoView {
+    let error = Signal::derive(move || {
+        error.get().or_else(|| {
+            let (name, errors) = (name.as_deref()?, errors?);
+            errors.with(|e| e.first(name).map(str::to_string))
+        })
+    });
+    let has_error = move || error.with(Option::is_some);
+    let label = label.map(|text| {
+        view! {
+            <label for=label_for>{ text }</label>
+        }
+    });
+    let message = move || {
+        error.get().map(|message| {
+            view! {
+                <div class="ui basic red pointing prompt label" role="alert">
+                    { message }
+                </div>
+            }
+        })
+    };
+
+    view! {
+        <div class="field" class:required=required class:error=has_error>
+            { label }
+            { children() }
+            { message }
+        </div>
+    }
+}
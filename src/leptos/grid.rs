@@ -0,0 +1,215 @@
+use crate::style::{
+    Color,
+    ColumnWidth,
+    Device,
+    TextAlign,
+    VerticalAlign,
+};
+use leptos::prelude::*;
+
+/// Appends the alignment classes shared by grids, rows and columns.
+fn push_alignment(
+    class: &mut String,
+    text_align: Option<TextAlign>,
+    vertical_align: Option<VerticalAlign>,
+) {
+    if let Some(text_align) = text_align {
+        class.push_str(&format!(" {text_align}"));
+    }
+    if let Some(vertical_align) = vertical_align {
+        class.push_str(&format!(" {vertical_align}"));
+    }
+}
+
+/// Appends the class that restricts an element to the given devices.
+fn push_only(class: &mut String, only: &[Device]) {
+    if only.is_empty() {
+        return;
+    }
+    for device in only {
+        class.push_str(&format!(" {device}"));
+    }
+    class.push_str(" only");
+}
+
+/// A `fomantic-ui` grid.
+#[component]
+pub fn Grid(
+    /// Amount of columns per row.
+    #[prop(optional)]
+    columns: Option<ColumnWidth>,
+    /// Shows the columns below each other on mobile.
+    #[prop(optional)]
+    stackable: bool,
+    /// Doubles the column width on smaller screens.
+    #[prop(optional)]
+    doubling: bool,
+    /// Centers the columns.
+    #[prop(optional)]
+    centered: bool,
+    /// Shows dividers between the columns.
+    #[prop(optional)]
+    divided: bool,
+    /// Shows dividers between all cells.
+    #[prop(optional)]
+    celled: bool,
+    /// Adds padding around the grid.
+    #[prop(optional)]
+    padded: bool,
+    /// Increases the gutters between the columns.
+    #[prop(optional)]
+    relaxed: bool,
+    /// Horizontal alignment of the content.
+    #[prop(optional)]
+    text_align: Option<TextAlign>,
+    /// Vertical alignment of the columns.
+    #[prop(optional)]
+    vertical_align: Option<VerticalAlign>,
+    /// The rows or columns of the grid.
+    children: Children,
+) -> impl IntoView {
+    let mut class = "ui".to_string();
+    if let Some(columns) = columns {
+        class.push_str(&format!(" {} column", columns.as_word()));
+    }
+    for (enabled, name) in [
+        (stackable, "stackable"),
+        (doubling, "doubling"),
+        (centered, "centered"),
+        (divided, "divided"),
+        (celled, "celled"),
+        (padded, "padded"),
+        (relaxed, "relaxed"),
+    ] {
+        if enabled {
+            class.push(' ');
+            class.push_str(name);
+        }
+    }
+    push_alignment(&mut class, text_align, vertical_align);
+    class.push_str(" grid");
+
+    view! {
+        <div class=class>
+            { children() }
+        </div>
+    }
+}
+
+/// A row of a [Grid].
+#[component]
+pub fn GridRow(
+    /// Amount of columns in this row.
+    #[prop(optional)]
+    columns: Option<ColumnWidth>,
+    /// Shows the row only on the given devices.
+    #[prop(optional)]
+    only: Vec<Device>,
+    /// Centers the columns of the row.
+    #[prop(optional)]
+    centered: bool,
+    /// Stretches the columns to the height of the row.
+    #[prop(optional)]
+    stretched: bool,
+    /// Color of the row.
+    #[prop(optional)]
+    color: Option<Color>,
+    /// Horizontal alignment of the content.
+    #[prop(optional)]
+    text_align: Option<TextAlign>,
+    /// Vertical alignment of the columns.
+    #[prop(optional)]
+    vertical_align: Option<VerticalAlign>,
+    /// The columns of the row.
+    children: Children,
+) -> impl IntoView {
+    let mut class = String::new();
+    if let Some(columns) = columns {
+        class.push_str(&format!(" {} column", columns.as_word()));
+    }
+    push_only(&mut class, &only);
+    if centered {
+        class.push_str(" centered");
+    }
+    if stretched {
+        class.push_str(" stretched");
+    }
+    if let Some(color) = color {
+        class.push_str(&format!(" {color}"));
+    }
+    push_alignment(&mut class, text_align, vertical_align);
+    class.push_str(" row");
+
+    view! {
+        <div class=class.trim_start().to_string()>
+            { children() }
+        </div>
+    }
+}
+
+/// A column of a [Grid].
+///
+/// The device specific widths override `width` on the respective devices.
+#[component]
+pub fn GridColumn(
+    /// Width of the column.
+    #[prop(optional)]
+    width: Option<ColumnWidth>,
+    /// Width of the column on mobile.
+    #[prop(optional)]
+    mobile: Option<ColumnWidth>,
+    /// Width of the column on tablets.
+    #[prop(optional)]
+    tablet: Option<ColumnWidth>,
+    /// Width of the column on computers.
+    #[prop(optional)]
+    computer: Option<ColumnWidth>,
+    /// Width of the column on large screens.
+    #[prop(optional)]
+    large_screen: Option<ColumnWidth>,
+    /// Width of the column on widescreens.
+    #[prop(optional)]
+    widescreen: Option<ColumnWidth>,
+    /// Shows the column only on the given devices.
+    #[prop(optional)]
+    only: Vec<Device>,
+    /// Color of the column.
+    #[prop(optional)]
+    color: Option<Color>,
+    /// Horizontal alignment of the content.
+    #[prop(optional)]
+    text_align: Option<TextAlign>,
+    /// Vertical alignment of the column.
+    #[prop(optional)]
+    vertical_align: Option<VerticalAlign>,
+    /// The contents of the column.
+    children: Children,
+) -> impl IntoView {
+    let mut class = String::new();
+    if let Some(width) = width {
+        class.push_str(&format!(" {width}"));
+    }
+    for (device, width) in [
+        (Device::Mobile, mobile),
+        (Device::Tablet, tablet),
+        (Device::Computer, computer),
+        (Device::LargeScreen, large_screen),
+        (Device::Widescreen, widescreen),
+    ] {
+        if let Some(width) = width {
+            class.push_str(&format!(" {width} {device}"));
+        }
+    }
+    push_only(&mut class, &only);
+    if let Some(color) = color {
+        class.push_str(&format!(" {color}"));
+    }
+    push_alignment(&mut class, text_align, vertical_align);
+    class.push_str(" column");
+
+    view! {
+        <div class=class.trim_start().to_string()>
+            { children() }
+        </div>
+    }
+}
@@ -0,0 +1,48 @@
+use crate::style::{
+    Color,
+    IconName,
+    Size,
+};
+use leptos::prelude::*;
+
+/// A `fomantic-ui` icon.
+#[component]
+pub fn Icon(
+    /// The icon to show.
+    #[prop(into)]
+    name: IconName,
+    /// Color of the icon.
+    #[prop(optional)]
+    color: Option<Color>,
+    /// Size of the icon.
+    #[prop(optional)]
+    size: Option<Size>,
+    /// Shows the disabled state.
+    #[prop(optional)]
+    disabled: bool,
+    /// Spins the icon, eg. to show a loading state.
+    #[prop(optional)]
+    loading: bool,
+    /// Accessible label of the icon. Icons without label are hidden from
+    /// screen readers.
+    #[prop(optional, into)]
+    label: Option<String>,
+) -> impl IntoView {
+    let mut class = String::new();
+    if let Some(color) = color {
+        class.push_str(&format!("{color} "));
+    }
+    if let Some(size) = size {
+        class.push_str(&format!("{size} "));
+    }
+    if disabled {
+        class.push_str("disabled ");
+    }
+    if loading {
+        class.push_str("loading ");
+    }
+    class.push_str(&name.to_string());
+    let hidden = label.is_none().then_some("true");
+
+    view! { <i class=class aria-label=label aria-hidden=hidden></i> }
+}
@@ -0,0 +1,134 @@
+use crate::style::{
+    IconName,
+    Size,
+};
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// A `fomantic-ui` input, bound to a signal.
+///
+/// The `icon`, `label` and `action` props enable the corresponding
+/// variations. While `loading` is set, the icon is replaced by a loader.
+#[component]
+pub fn Input(
+    /// The value of the input, updated on every keystroke.
+    value: RwSignal<String>,
+    /// The `type` attribute of the input element.
+    #[prop(default = "text".into(), into)]
+    input_type: String,
+    /// Placeholder text shown while the input is empty.
+    #[prop(optional, into)]
+    placeholder: Option<String>,
+    /// Icon of the input, eg. `search`.
+    #[prop(optional, into)]
+    icon: Option<IconName>,
+    /// Places the icon on the left side.
+    #[prop(optional)]
+    left_icon: bool,
+    /// Text of a label attached to the input, eg. `https://`.
+    #[prop(optional, into)]
+    label: Option<String>,
+    /// Attaches the label to the right side.
+    #[prop(optional)]
+    right_label: bool,
+    /// An action attached to the right side, eg. a button.
+    #[prop(optional, into)]
+    action: Option<ViewFn>,
+    /// Takes the full width of its container.
+    #[prop(optional)]
+    fluid: bool,
+    /// Shows the input in an error state.
+    #[prop(optional, into)]
+    error: Signal<bool>,
+    /// Shows a loader in place of the icon.
+    #[prop(optional, into)]
+    loading: Signal<bool>,
+    /// Disables the input.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+    /// Size of the input.
+    #[prop(optional)]
+    size: Option<Size>,
+    /// Called with the new value when the user changes the input.
+    #[prop(optional, into)]
+    on_input: Option<Callback<String>>,
+    /// Delays `on_input` until the user stopped typing for the given amount
+    /// of milliseconds.
+    #[prop(optional)]
+    debounce: Option<u64>,
+) -> impl IntoView {
+    let mut classes = vec!["ui".to_string()];
+    if let Some(size) = size {
+        classes.push(size.to_string());
+    }
+    if fluid {
+        classes.push("fluid".into());
+    }
+    if icon.is_some() {
+        classes.push(if left_icon { "left icon" } else { "icon" }.into());
+    }
+    if label.is_some() {
+        classes.push(
+            if right_label {
+                "right labeled"
+            } else {
+                "labeled"
+            }
+            .into(),
+        );
+    }
+    if action.is_some() {
+        classes.push("action".into());
+    }
+    classes.push("input".into());
+    let class = classes.join(" ");
+
+    let mut notify: Box<dyn FnMut(String)> = match (on_input, debounce) {
+        (Some(on_input), Some(delay)) => {
+            Box::new(leptos::leptos_dom::helpers::debounce(
+                Duration::from_millis(delay),
+                move |value| on_input.run(value),
+            ))
+        }
+        (Some(on_input), None) => Box::new(move |value| on_input.run(value)),
+        (None, _) => Box::new(|_| {}),
+    };
+    let on_input = move |e: web_sys::Event| {
+        let new_value = event_target_value(&e);
+        value.set(new_value.clone());
+        notify(new_value);
+    };
+
+    let label = label.map(|text| {
+        view! {
+            <div class="ui label">
+                { text }
+            </div>
+        }
+    });
+    let (left_label, right_label) = if right_label {
+        (None, label)
+    } else {
+        (label, None)
+    };
+
+    view! {
+        <div
+            class=class
+            class:error=error
+            class:loading=loading
+            class:disabled=disabled>
+            { left_label }
+            <input
+                type=input_type
+                placeholder=placeholder
+                prop:value=value
+                prop:disabled=disabled
+                on:input=on_input
+                />
+            { icon.map(|icon| view! { <i class=icon.to_string()></i> }) }
+            { right_label }
+            { action.map(|action| action.run()) }
+        </div>
+    }
+}
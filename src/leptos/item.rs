@@ -0,0 +1,114 @@
+use leptos::prelude::*;
+
+/// A group of [Item]s.
+#[component]
+pub fn ItemGroup(
+    /// Shows dividers between the items.
+    #[prop(optional)]
+    divided: bool,
+    /// Increases the padding between the items.
+    #[prop(optional)]
+    relaxed: bool,
+    /// Highlights the items on hover, eg. if they are links.
+    #[prop(optional)]
+    link: bool,
+    /// Keeps the image next to the content on mobile.
+    #[prop(optional)]
+    unstackable: bool,
+    /// The items of the group.
+    children: Children,
+) -> impl IntoView {
+    let mut class = "ui".to_string();
+    for (enabled, name) in [
+        (divided, "divided"),
+        (relaxed, "relaxed"),
+        (link, "link"),
+        (unstackable, "unstackable"),
+    ] {
+        if enabled {
+            class.push(' ');
+            class.push_str(name);
+        }
+    }
+    class.push_str(" items");
+
+    view! {
+        <div class=class>
+            { children() }
+        </div>
+    }
+}
+
+/// An item of an [ItemGroup], eg. a product in a list.
+#[component]
+pub fn Item(
+    /// Source of the image shown next to the content.
+    #[prop(optional, into)]
+    image: Option<String>,
+    /// The header of the item.
+    #[prop(optional, into)]
+    header: Option<String>,
+    /// Makes the header a link to the given address.
+    #[prop(optional, into)]
+    href: Option<String>,
+    /// Text shown below the header, eg. a category.
+    #[prop(optional, into)]
+    meta: Option<String>,
+    /// Content shown below the description, eg. labels or buttons.
+    #[prop(optional, into)]
+    extra: Option<ViewFn>,
+    /// The description of the item.
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let image = image.map(|src| {
+        view! {
+            <div class="image">
+                <img src=src/>
+            </div>
+        }
+    });
+    let header = header.map(|header| match href {
+        Some(href) => view! {
+            <a class="header" href=href>{ header }</a>
+        }
+        .into_any(),
+        None => view! {
+            <div class="header">{ header }</div>
+        }
+        .into_any(),
+    });
+    let meta = meta.map(|meta| {
+        view! {
+            <div class="meta">
+                <span>{ meta }</span>
+            </div>
+        }
+    });
+    let description = children.map(|children| {
+        view! {
+            <div class="description">
+                { children() }
+            </div>
+        }
+    });
+    let extra = extra.map(|extra| {
+        view! {
+            <div class="extra">
+                { extra.run() }
+            </div>
+        }
+    });
+
+    view! {
+        <div class="item">
+            { image }
+            <div class="content">
+                { header }
+                { meta }
+                { description }
+                { extra }
+            </div>
+        </div>
+    }
+}
@@ -1,11 +1,109 @@
-use leptos::*;
+use crate::style::{
+    Color,
+    IconName,
+    Size,
+};
+use leptos::prelude::*;
 
-/// A simple label.
+/// The shape of a [Label].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LabelVariant {
+    /// Shaped like a price tag.
+    Tag,
+    /// Attached to the left edge of its container.
+    Ribbon,
+    /// Attached to the right edge of its container.
+    RightRibbon,
+    /// Points to the content above.
+    PointingAbove,
+    /// Points to the content below.
+    PointingBelow,
+    /// Points to the content on the left.
+    PointingLeft,
+    /// Points to the content on the right.
+    PointingRight,
+}
+
+impl std::fmt::Display for LabelVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Tag => "tag",
+            Self::Ribbon => "ribbon",
+            Self::RightRibbon => "right ribbon",
+            Self::PointingAbove => "pointing",
+            Self::PointingBelow => "pointing below",
+            Self::PointingLeft => "left pointing",
+            Self::PointingRight => "right pointing",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A `fomantic-ui` label.
+///
+/// Setting `on_remove` shows a delete icon that calls it when clicked.
 #[component]
-pub fn Label(text: MaybeSignal<String>) -> impl IntoView {
+pub fn Label(
+    /// The text of the label.
+    #[prop(into)]
+    text: Signal<String>,
+    /// Color of the label.
+    #[prop(optional)]
+    color: Option<Color>,
+    /// Size of the label.
+    #[prop(optional)]
+    size: Option<Size>,
+    /// Shape of the label.
+    #[prop(optional)]
+    variant: Option<LabelVariant>,
+    /// Shows only the border of the label.
+    #[prop(optional)]
+    basic: bool,
+    /// The icon shown in front of the text, eg. `mail`.
+    #[prop(optional, into)]
+    icon: Option<IconName>,
+    /// Additional content shown after the text, eg. a count.
+    #[prop(optional, into)]
+    detail: Option<ViewFn>,
+    /// Called when the delete icon is clicked.
+    #[prop(optional, into)]
+    on_remove: Option<Callback<()>>,
+) -> impl IntoView {
+    let mut class = "ui".to_string();
+    if let Some(color) = color {
+        class.push_str(&format!(" {color}"));
+    }
+    if let Some(size) = size {
+        class.push_str(&format!(" {size}"));
+    }
+    if let Some(variant) = variant {
+        class.push_str(&format!(" {variant}"));
+    }
+    if basic {
+        class.push_str(" basic");
+    }
+    class.push_str(" label");
+
+    let icon = icon.map(|icon| view! { <i class=icon.to_string()></i> });
+    let detail = detail.map(|detail| {
+        view! {
+            <div class="detail">
+                { detail.run() }
+            </div>
+        }
+    });
+    let remove = on_remove.map(|on_remove| {
+        view! {
+            <i class="delete icon" on:click=move |_| on_remove.run(())></i>
+        }
+    });
+
     view! {
-        <label>
+        <div class=class>
+            { icon }
             { text }
-        </label>
+            { detail }
+            { remove }
+        </div>
     }
 }
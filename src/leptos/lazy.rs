@@ -0,0 +1,82 @@
+use super::use_fomantic_config;
+use crate::modules::InstanceGuard;
+use leptos::{
+    prelude::*,
+    tachys::html::element::ElementType,
+};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use web_sys::{
+    IntersectionObserver,
+    IntersectionObserverEntry,
+};
+
+/// Calls `init` with the element of `node_ref` to initialize its
+/// `fomantic-ui` module.
+///
+/// The element is initialized as soon as it is mounted or hydrated. With
+/// [FomanticConfig::lazy_init](super::FomanticConfig::lazy_init) set, the
+/// initialization is deferred until the element enters the viewport.
+///
+/// The guard returned by `init` is dropped when the component is unmounted,
+/// which destroys the module before its handlers are released.
+pub(crate) fn init_module<E>(
+    node_ref: NodeRef<E>,
+    init: impl Fn(&web_sys::Element) -> Option<InstanceGuard> + 'static,
+) where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
+    let lazy = use_fomantic_config().lazy_init;
+    // the observer and its callback live until the component is unmounted
+    let observer = StoredValue::new_local(None);
+    let guard = StoredValue::new_local(None);
+    let init = Rc::new(move |element: &web_sys::Element| {
+        guard.set_value(init(element));
+    });
+    Effect::new(move |_| {
+        let Some(element) = node_ref.get() else {
+            return;
+        };
+        let element = element.unchecked_into::<web_sys::Element>();
+        if !lazy {
+            init(&element);
+            return;
+        }
+        let callback =
+            Closure::<dyn Fn(js_sys::Array, IntersectionObserver)>::new({
+                let init = Rc::clone(&init);
+                let element = element.clone();
+                move |entries: js_sys::Array, observer: IntersectionObserver| {
+                    let visible = entries.iter().any(|entry| {
+                        entry
+                            .unchecked_into::<IntersectionObserverEntry>()
+                            .is_intersecting()
+                    });
+                    if visible {
+                        observer.disconnect();
+                        init(&element);
+                    }
+                }
+            });
+        match IntersectionObserver::new(callback.as_ref().unchecked_ref()) {
+            Ok(intersection) => {
+                intersection.observe(&element);
+                observer.set_value(Some((intersection, callback)));
+            }
+            // browsers without support initialize right away
+            Err(_) => init(&element),
+        }
+    });
+
+    on_cleanup(move || {
+        observer.with_value(|observer| {
+            if let Some((observer, _)) = observer {
+                observer.disconnect();
+            }
+        });
+        // explicitly, as the order the values of the component are dropped
+        // in is unspecified
+        guard.set_value(None);
+    });
+}
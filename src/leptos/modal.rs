@@ -0,0 +1,200 @@
+use super::{
+    use_fomantic_config,
+    FomanticConfig,
+};
+use crate::{
+    a11y,
+    models::Validatable,
+    modules::modal::{
+        Modal,
+        ModalConfig,
+    },
+};
+use leptos::prelude::*;
+use std::{
+    cell::Cell,
+    fmt::Display,
+    rc::Rc,
+    str::FromStr,
+};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+/// A modal opened by a [ModalHandle].
+struct OpenModal {
+    modal: Modal,
+    /// Set by the handler of a template modal once it has been answered,
+    /// `None` for custom modals.
+    answered: Option<Rc<Cell<bool>>>,
+}
+
+impl OpenModal {
+    /// Whether the modal is done and can be removed.
+    fn is_closed(&self) -> bool {
+        match &self.answered {
+            Some(answered) => answered.get(),
+            None => !self.modal.is_active(),
+        }
+    }
+}
+
+/// Handle to open modals from a component, obtained by [use_modal].
+///
+/// The modals are owned by the handle. Closed modals are removed from the
+/// page when the next one is opened, all remaining ones when the component
+/// is unmounted.
+#[derive(Clone, Copy)]
+pub struct ModalHandle {
+    modals: StoredValue<Vec<OpenModal>, LocalStorage>,
+    defaults: StoredValue<FomanticConfig>,
+}
+
+impl ModalHandle {
+    /// Takes ownership of the given modal.
+    fn open(&self, modal: Modal, answered: Option<Rc<Cell<bool>>>) {
+        let modal = match self.defaults.with_value(|d| d.modal_focus_trap) {
+            true => modal.with_focus_trap(),
+            false => modal,
+        };
+        self.modals.update_value(|modals| {
+            modals.retain(|open| {
+                let closed = open.is_closed();
+                if closed {
+                    open.modal.remove();
+                }
+                !closed
+            });
+            modals.push(OpenModal { modal, answered });
+        });
+    }
+
+    /// Shows an alert with the given title and content.
+    pub fn alert(&self, title: &str, content: &str) {
+        let answered = Rc::new(Cell::new(false));
+        let modal = Modal::new_alert(title, content, {
+            let answered = Rc::clone(&answered);
+            move || answered.set(true)
+        });
+        self.open(modal, Some(answered));
+    }
+
+    /// Asks the user to confirm, resolves to `true` if approved.
+    ///
+    /// Never resolves if the component is unmounted before the user answers.
+    pub async fn confirm(&self, title: &str, content: &str) -> bool {
+        let (promise, resolve) = new_promise();
+        let answered = Rc::new(Cell::new(false));
+        let modal = Modal::new_confirm(title, content, {
+            let answered = Rc::clone(&answered);
+            move |approved| {
+                answered.set(true);
+                let _ = resolve.call1(&JsValue::NULL, &approved.into());
+            }
+        });
+        self.open(modal, Some(answered));
+        JsFuture::from(promise)
+            .await
+            .ok()
+            .and_then(|approved| approved.as_bool())
+            .unwrap_or_default()
+    }
+
+    /// Asks the user for a text, resolves to `None` if cancelled.
+    ///
+    /// Never resolves if the component is unmounted before the user answers.
+    pub async fn prompt(&self, title: &str, content: &str) -> Option<String> {
+        let (promise, resolve) = new_promise();
+        let answered = Rc::new(Cell::new(false));
+        let modal = Modal::new_prompt(title, content, {
+            let answered = Rc::clone(&answered);
+            move |text| {
+                answered.set(true);
+                let _ = resolve.call1(&JsValue::NULL, &text.into());
+            }
+        });
+        self.open(modal, Some(answered));
+        JsFuture::from(promise).await.ok()?.as_string()
+    }
+
+    /// Asks the user for a value, resolves to `None` if cancelled.
+    ///
+    /// The text is parsed and validated, the user is asked again with the
+    /// error messages appended to the content until the value is valid.
+    pub async fn prompt_validated<T>(
+        &self,
+        title: &str,
+        content: &str,
+    ) -> Option<T>
+    where
+        T: FromStr + Validatable,
+        T::Err: Display,
+    {
+        let mut message = content.to_string();
+        loop {
+            let text = self.prompt(title, &message).await?;
+            let errors = match text.parse::<T>() {
+                Ok(value) => {
+                    let errors = value.validate();
+                    if errors.is_empty() {
+                        return Some(value);
+                    }
+                    errors.messages().collect::<Vec<_>>().join(" ")
+                }
+                Err(e) => e.to_string(),
+            };
+            message = format!("{content} {errors}");
+        }
+    }
+
+    /// Returns a configuration with the modal defaults of the
+    /// [FomanticConfig] applied, to be passed to [custom](Self::custom).
+    pub fn config(&self) -> ModalConfig {
+        let config = ModalConfig::default();
+        self.defaults.with_value(|defaults| {
+            config.set_blurring(defaults.modal_blurring);
+            config.set_closeable(defaults.modal_closable);
+            config.set_inverted(defaults.inverted);
+            if let Some(duration) =
+                a11y::transition_duration(defaults.transition_duration)
+            {
+                config.set_duration(duration);
+            }
+        });
+        config
+    }
+
+    /// Shows a modal with the given configuration.
+    pub fn custom(&self, config: ModalConfig) {
+        let modal = Modal::new(config);
+        modal.show();
+        self.open(modal, None);
+    }
+}
+
+/// Creates a promise together with the function that resolves it.
+fn new_promise() -> (js_sys::Promise, js_sys::Function) {
+    let mut resolve = None;
+    let promise = js_sys::Promise::new(&mut |res, _| resolve = Some(res));
+    // the executor is called synchronously by the constructor
+    (promise, resolve.unwrap_throw())
+}
+
+/// Returns a [ModalHandle] to open alert, confirm, prompt and custom modals
+/// imperatively, eg. from an event handler.
+///
+/// The modals are removed from the page when the calling component is
+/// unmounted.
+pub fn use_modal() -> ModalHandle {
+    let handle = ModalHandle {
+        modals: StoredValue::new_local(vec![]),
+        defaults: StoredValue::new(use_fomantic_config()),
+    };
+    on_cleanup(move || {
+        handle.modals.update_value(|modals| {
+            for open in modals.drain(..) {
+                open.modal.remove();
+            }
+        });
+    });
+    handle
+}
@@ -0,0 +1,83 @@
+use leptos::prelude::*;
+
+/// Amount of pages that are shown next to the current page.
+const PAGE_WINDOW: usize = 2;
+
+/// A `fomantic-ui` pagination menu.
+///
+/// Long page ranges are shortened, only the first, last and the pages
+/// surrounding the current one are shown.
+#[component]
+pub fn Pagination(
+    /// The total amount of pages.
+    #[prop(into)]
+    page_count: Signal<usize>,
+    /// The current page, starting at zero.
+    page: RwSignal<usize>,
+) -> impl IntoView {
+    let items = move || {
+        visible_pages(page.get(), page_count.get())
+            .into_iter()
+            .map(|p| match p {
+                Some(p) => view! {
+                    <a
+                        class="item"
+                        class:active=move || page.get() == p
+                        on:click=move |_| page.set(p)>
+                        { p + 1 }
+                    </a>
+                }
+                .into_any(),
+                None => view! {
+                    <div class="disabled item">"..."</div>
+                }
+                .into_any(),
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class="ui right floated pagination menu">
+            <a
+                class="icon item"
+                class:disabled=move || page.get() == 0
+                on:click=move |_| page.update(|p| *p = p.saturating_sub(1))>
+                <i class="left chevron icon"></i>
+            </a>
+            { items }
+            <a
+                class="icon item"
+                class:disabled=move || { page.get() + 1 >= page_count.get() }
+                on:click=move |_| {
+                    let count = page_count.get_untracked();
+                    page.update(|p| {
+                        if *p + 1 < count {
+                            *p += 1;
+                        }
+                    })
+                }>
+                <i class="right chevron icon"></i>
+            </a>
+        </div>
+    }
+}
+
+/// Returns the pages that are shown in the menu, `None` marks a gap.
+fn visible_pages(current: usize, count: usize) -> Vec<Option<usize>> {
+    if count <= 2 * PAGE_WINDOW + 3 {
+        return (0..count).map(Some).collect();
+    }
+    let last = count - 1;
+    let start = current.saturating_sub(PAGE_WINDOW).max(1);
+    let end = (current + PAGE_WINDOW).min(last - 1);
+    let mut pages = vec![Some(0)];
+    if start > 1 {
+        pages.push(None);
+    }
+    pages.extend((start..=end).map(Some));
+    if end < last - 1 {
+        pages.push(None);
+    }
+    pages.push(Some(last));
+    pages
+}
@@ -0,0 +1,137 @@
+use super::use_fomantic_config;
+#[cfg(feature = "table")]
+use super::TableColumn;
+use leptos::prelude::*;
+
+/// Length of a [PlaceholderLine].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PlaceholderLength {
+    /// A very short line.
+    VeryShort,
+    /// A short line.
+    Short,
+    /// A medium line.
+    Medium,
+    /// A long line.
+    Long,
+    /// A very long line.
+    VeryLong,
+    /// The full width, the default.
+    #[default]
+    Full,
+}
+
+impl std::fmt::Display for PlaceholderLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::VeryShort => "very short",
+            Self::Short => "short",
+            Self::Medium => "medium",
+            Self::Long => "long",
+            Self::VeryLong => "very long",
+            Self::Full => "full",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A `fomantic-ui` placeholder, used as skeleton while content is loading.
+#[component]
+pub fn Placeholder(
+    /// Takes the full width of its container.
+    #[prop(optional)]
+    fluid: bool,
+    /// Inverts the colors for dark backgrounds.
+    #[prop(optional)]
+    inverted: bool,
+    /// The lines, headers and images of the placeholder.
+    children: Children,
+) -> impl IntoView {
+    let inverted = inverted || use_fomantic_config().inverted;
+    view! {
+        <div class="ui placeholder" class:fluid=fluid class:inverted=inverted>
+            { children() }
+        </div>
+    }
+}
+
+/// A line of text in a [Placeholder].
+#[component]
+pub fn PlaceholderLine(
+    /// Length of the line.
+    #[prop(optional)]
+    length: PlaceholderLength,
+) -> impl IntoView {
+    let class = match length {
+        PlaceholderLength::Full => "line".to_string(),
+        length => format!("{length} line"),
+    };
+
+    view! {
+        <div class=class></div>
+    }
+}
+
+/// A header in a [Placeholder], made of [PlaceholderLine]s.
+#[component]
+pub fn PlaceholderHeader(
+    /// Shows an image next to the header.
+    #[prop(optional)]
+    image: bool,
+    /// The lines of the header.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class="header" class:image=image>
+            { children() }
+        </div>
+    }
+}
+
+/// An image in a [Placeholder].
+#[component]
+pub fn PlaceholderImage(
+    /// Shows a square image.
+    #[prop(optional)]
+    square: bool,
+    /// Shows a rectangular image.
+    #[prop(optional)]
+    rectangular: bool,
+) -> impl IntoView {
+    view! {
+        <div class="image" class:square=square class:rectangular=rectangular>
+        </div>
+    }
+}
+
+/// Renders the given amount of placeholder rows matching the layout of the
+/// given [Table](super::Table) columns.
+#[cfg(feature = "table")]
+pub fn placeholder_rows<R>(
+    columns: &[TableColumn<R>],
+    rows: usize,
+) -> impl IntoView {
+    (0..rows)
+        .map(|_| {
+            let cells = columns
+                .iter()
+                .map(|column| {
+                    view! {
+                        <td
+                            class=column.cell_class()
+                            colspan=column.colspan_attribute()>
+                            <div class="ui fluid placeholder">
+                                <div class="line"></div>
+                            </div>
+                        </td>
+                    }
+                })
+                .collect_view();
+            view! {
+                <tr>
+                    { cells }
+                </tr>
+            }
+        })
+        .collect_view()
+}
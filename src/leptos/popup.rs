@@ -0,0 +1,271 @@
+use super::{
+    lazy::init_module,
+    use_fomantic_config,
+};
+use crate::{
+    a11y,
+    jq::JQuery,
+    macros::module_config,
+    modules::InstanceGuard,
+    style::{
+        Direction,
+        Mirror,
+    },
+};
+use leptos::{
+    html,
+    prelude::*,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+module_config! {
+    /// Settings of the popup module.
+    struct PopupConfig(JsPopupConfig) {
+        /// Sets the text content of the popup.
+        content: &str = "content",
+        /// Sets an existing element that is used as popup.
+        popup: &web_sys::Element = "popup",
+        /// Sets the position of the popup.
+        position: &str = "position",
+        /// Sets the event that shows the popup.
+        on: &str = "on",
+        /// Sets wether the popup stays open while hovering it.
+        hoverable: bool = "hoverable",
+        /// Sets the duration of the transition in milliseconds.
+        duration: u32 = "duration",
+        /// Sets the variation of the popup, eg. `inverted`.
+        variation: &str = "variation",
+    }
+}
+
+/// Position of a [Popup] relative to its target.
+#[derive(
+    Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize,
+)]
+pub enum PopupPosition {
+    /// Above, aligned to the left edge.
+    TopLeft,
+    /// Above and centered, the default.
+    #[default]
+    TopCenter,
+    /// Above, aligned to the right edge.
+    TopRight,
+    /// Below, aligned to the left edge.
+    BottomLeft,
+    /// Below and centered.
+    BottomCenter,
+    /// Below, aligned to the right edge.
+    BottomRight,
+    /// Left of the target.
+    LeftCenter,
+    /// Right of the target.
+    RightCenter,
+}
+
+impl std::fmt::Display for PopupPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::TopLeft => "top left",
+            Self::TopCenter => "top center",
+            Self::TopRight => "top right",
+            Self::BottomLeft => "bottom left",
+            Self::BottomCenter => "bottom center",
+            Self::BottomRight => "bottom right",
+            Self::LeftCenter => "left center",
+            Self::RightCenter => "right center",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl Mirror for PopupPosition {
+    fn mirrored(self) -> Self {
+        match self {
+            Self::TopLeft => Self::TopRight,
+            Self::TopRight => Self::TopLeft,
+            Self::BottomLeft => Self::BottomRight,
+            Self::BottomRight => Self::BottomLeft,
+            Self::LeftCenter => Self::RightCenter,
+            Self::RightCenter => Self::LeftCenter,
+            other => other,
+        }
+    }
+}
+
+/// The event that shows a [Popup].
+#[derive(
+    Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize,
+)]
+pub enum PopupTrigger {
+    /// Hovering the target, the default.
+    #[default]
+    Hover,
+    /// Clicking the target.
+    Click,
+    /// Focusing the target.
+    Focus,
+}
+
+impl std::fmt::Display for PopupTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hover => write!(f, "hover"),
+            Self::Click => write!(f, "click"),
+            Self::Focus => write!(f, "focus"),
+        }
+    }
+}
+
+/// The content of a [Popup].
+#[derive(Clone)]
+pub enum PopupContent {
+    /// Plain text.
+    Text(String),
+    /// A leptos view that stays reactive while the popup is shown.
+    View(ViewFn),
+}
+
+impl From<&str> for PopupContent {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for PopupContent {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<ViewFn> for PopupContent {
+    fn from(view: ViewFn) -> Self {
+        Self::View(view)
+    }
+}
+
+/// Attaches a `fomantic-ui` popup to its children.
+///
+/// The popup module is initialized on mount and destroyed on unmount.
+#[component]
+pub fn Popup(
+    /// The content of the popup.
+    #[prop(into)]
+    content: PopupContent,
+    /// Position of the popup relative to the children.
+    #[prop(optional)]
+    position: PopupPosition,
+    /// The event that shows the popup.
+    #[prop(optional)]
+    trigger: PopupTrigger,
+    /// Keeps the popup open while it is hovered.
+    #[prop(optional)]
+    hoverable: bool,
+    /// Inverts the colors of the popup.
+    #[prop(optional)]
+    inverted: bool,
+    /// Writing direction of the popup, mirroring its position for right to
+    /// left. Defaults to the direction of the
+    /// [FomanticConfig](super::FomanticConfig).
+    #[prop(optional)]
+    direction: Option<Direction>,
+    /// The target of the popup.
+    children: Children,
+) -> impl IntoView {
+    let config = use_fomantic_config();
+    let inverted = inverted || config.inverted;
+    let duration = a11y::transition_duration(config.transition_duration);
+    let direction = direction.unwrap_or(config.direction);
+    let position = position.for_direction(direction);
+    let ref_target = NodeRef::<html::Span>::new();
+    let ref_popup = NodeRef::<html::Div>::new();
+    let (text, popup_view) = match content {
+        PopupContent::Text(text) => (Some(text), None),
+        PopupContent::View(view) => (None, Some(view)),
+    };
+
+    init_module(ref_target, move |target| {
+        let config = PopupConfig::new();
+        config
+            .position(&position.to_string())
+            .on(&trigger.to_string())
+            .hoverable(hoverable);
+        if let Some(duration) = duration {
+            config.duration(duration);
+        }
+        if inverted {
+            config.variation("inverted");
+        }
+        if let Some(text) = &text {
+            config.content(text);
+        }
+        if let Some(popup) = ref_popup.get_untracked() {
+            config.popup(&popup);
+        }
+        let target = JQuery::from_element(target);
+        target.init("popup", config.as_ref());
+        Some(InstanceGuard::new(target, "popup"))
+    });
+
+    let popup = popup_view.map(|view| {
+        view! {
+            <div
+                node_ref=ref_popup
+                class="ui popup"
+                class:inverted=inverted
+                dir=direction.to_string()>
+                { view.run() }
+            </div>
+        }
+    });
+
+    view! {
+        <span node_ref=ref_target style="display: inline-block">
+            { children() }
+        </span>
+        { popup }
+    }
+}
+
+/// A [Popup] with a text content as island, for apps built with
+/// `leptos-islands`.
+///
+/// Only the islands are hydrated, so a [Popup] used directly in a server
+/// rendered page is never initialized. The island ships the popup to the
+/// client, its props are serialized into the page. Context is not shared
+/// with the island, so the default
+/// [FomanticConfig](super::FomanticConfig) is used, which defers the
+/// initialization until the popup enters the viewport.
+#[cfg(feature = "leptos-islands")]
+#[island]
+pub fn PopupIsland(
+    /// The text content of the popup.
+    text: String,
+    /// Position of the popup relative to the children.
+    #[prop(optional)]
+    position: Option<PopupPosition>,
+    /// The event that shows the popup.
+    #[prop(optional)]
+    trigger: Option<PopupTrigger>,
+    /// Keeps the popup open while it is hovered.
+    #[prop(optional)]
+    hoverable: Option<bool>,
+    /// Inverts the colors of the popup.
+    #[prop(optional)]
+    inverted: Option<bool>,
+    /// The target of the popup.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <Popup
+            content=text
+            position=position.unwrap_or_default()
+            trigger=trigger.unwrap_or_default()
+            hoverable=hoverable.unwrap_or_default()
+            inverted=inverted.unwrap_or_default()>
+            { children() }
+        </Popup>
+    }
+}
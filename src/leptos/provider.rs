@@ -0,0 +1,109 @@
+#[cfg(feature = "toast")]
+use crate::modules::toast::ToastPosition;
+use crate::style::Direction;
+use leptos::prelude::*;
+
+/// Texts shown by the components, eg. to translate them.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FomanticTexts {
+    /// Placeholder of the search input of a [Table](super::Table).
+    pub search_placeholder: String,
+    /// Shown by a [Table](super::Table) without rows.
+    pub no_data: String,
+    /// Label of the column chooser of a [Table](super::Table).
+    pub columns: String,
+    /// Label of the first section of a [Breadcrumb](super::Breadcrumb) that
+    /// is derived from the route.
+    pub home: String,
+}
+
+impl Default for FomanticTexts {
+    fn default() -> Self {
+        Self {
+            search_placeholder: "Search...".to_string(),
+            no_data: "No data available.".to_string(),
+            columns: "Columns".to_string(),
+            home: "Home".to_string(),
+        }
+    }
+}
+
+/// Global configuration of the components, supplied by a
+/// [FomanticProvider].
+///
+/// Props that are set on a component take precedence.
+#[derive(Clone)]
+pub struct FomanticConfig {
+    /// Shows the components in inverted colors, for dark pages.
+    pub inverted: bool,
+    /// Writing direction of the components. Positions of popups and toasts
+    /// are mirrored for right to left.
+    pub direction: Direction,
+    /// Duration of the transitions of the modules in milliseconds, the
+    /// defaults of `fomantic-ui` are used if not set.
+    pub transition_duration: Option<u32>,
+    /// Texts shown by the components.
+    pub texts: FomanticTexts,
+    /// Where a [ToastContainer](super::ToastContainer) shows its toasts.
+    #[cfg(feature = "toast")]
+    pub toast_position: ToastPosition,
+    /// How long toasts are visible in milliseconds. `None` keeps them until
+    /// they are closed.
+    #[cfg(feature = "toast")]
+    pub toast_display_time: Option<u32>,
+    /// Blurs the page behind modals.
+    #[cfg(feature = "modal")]
+    pub modal_blurring: bool,
+    /// Closes modals when the dimmer is clicked.
+    #[cfg(feature = "modal")]
+    pub modal_closable: bool,
+    /// Keeps the keyboard focus within the modals opened by a
+    /// [ModalHandle](super::ModalHandle).
+    #[cfg(feature = "modal")]
+    pub modal_focus_trap: bool,
+    /// Defers the initialization of the modules of dropdowns, popups,
+    /// checkboxes and sortable tables until they enter the viewport. Enabled
+    /// by default with the `leptos-islands` feature.
+    pub lazy_init: bool,
+}
+
+impl Default for FomanticConfig {
+    fn default() -> Self {
+        Self {
+            inverted: false,
+            direction: Direction::Ltr,
+            transition_duration: None,
+            texts: FomanticTexts::default(),
+            #[cfg(feature = "toast")]
+            toast_position: ToastPosition::default(),
+            #[cfg(feature = "toast")]
+            toast_display_time: Some(3000),
+            #[cfg(feature = "modal")]
+            modal_blurring: false,
+            #[cfg(feature = "modal")]
+            modal_closable: true,
+            #[cfg(feature = "modal")]
+            modal_focus_trap: true,
+            lazy_init: cfg!(feature = "leptos-islands"),
+        }
+    }
+}
+
+/// Returns the configuration of the surrounding [FomanticProvider], or the
+/// default configuration if there is none.
+pub fn use_fomantic_config() -> FomanticConfig {
+    use_context::<FomanticConfig>().unwrap_or_default()
+}
+
+/// Supplies the given configuration to all components below it.
+#[component]
+pub fn FomanticProvider(
+    /// The configuration of the components.
+    #[prop(optional)]
+    config: FomanticConfig,
+    /// The components that use the configuration.
+    children: Children,
+) -> impl IntoView {
+    provide_context(config);
+    children()
+}
@@ -0,0 +1,173 @@
+use super::{
+    Pagination,
+    SortState,
+    TableColumn,
+    TableKey,
+    TableRow,
+};
+use crate::models::{
+    DataProvider,
+    PageRequest,
+};
+use leptos::prelude::*;
+
+/// A `fomantic-ui` table that fetches its rows from a [DataProvider].
+///
+/// The rows are fetched again whenever the page, sort order or filter
+/// changes. Sorting and filtering is left to the provider.
+#[component]
+pub fn RemoteTable<P, R>(
+    /// The source of the rows.
+    provider: P,
+    /// The columns of the table. Comparators are ignored, sorting is left to
+    /// the provider.
+    columns: Vec<TableColumn<R>>,
+    /// Identifies the rows, eg. `|row: &Row| row.id`.
+    #[prop(into)]
+    key: TableKey<R>,
+    /// Amount of rows requested per page.
+    page_size: usize,
+    /// The current page, starting at zero.
+    #[prop(optional)]
+    page: Option<RwSignal<usize>>,
+    /// The current sort order, ordered by priority. Updated when a heading
+    /// is clicked, shift-click sorts by multiple columns.
+    #[prop(optional)]
+    sort: Option<RwSignal<Vec<SortState>>>,
+    /// Filter text that is passed to the provider.
+    #[prop(optional, into)]
+    filter: Signal<String>,
+) -> impl IntoView
+where
+    P: DataProvider<R> + Clone + 'static,
+    R: Clone + Send + Sync + 'static,
+{
+    let column_count = columns.iter().map(|c| c.colspan).sum::<usize>();
+    let page = page.unwrap_or_else(|| RwSignal::new(0));
+    let sort = sort.unwrap_or_else(|| RwSignal::new(vec![]));
+    let filter = Signal::derive(move || filter.get());
+
+    // start over on the first page when the sort order or filter changes
+    Effect::new(move |prev: Option<()>| {
+        sort.track();
+        filter.track();
+        if prev.is_some() {
+            page.set(0);
+        }
+    });
+
+    let loading = RwSignal::new(false);
+    let resource = LocalResource::new(move || {
+        let request = PageRequest {
+            page: page.get(),
+            size: page_size,
+            sort: sort.get(),
+            filter: filter.get(),
+        };
+        let provider = provider.clone();
+        loading.set(true);
+        async move {
+            let page = provider.fetch(request).await;
+            loading.set(false);
+            page
+        }
+    });
+
+    let page_count = Signal::derive(move || {
+        resource
+            .with(|p| p.as_ref().map(|p| p.total))
+            .unwrap_or(0)
+            .div_ceil(page_size.max(1))
+            .max(1)
+    });
+    let rows = move || {
+        resource
+            .with(|p| p.as_ref().map(|p| p.items.clone()))
+            .unwrap_or_default()
+    };
+
+    let heading_items = columns
+        .iter()
+        .enumerate()
+        .map(|(idx, column)| {
+            let heading_class = column.layout_class();
+            let sortable = column.sortable;
+            view! {
+                <th
+                    colspan=column.colspan_attribute()
+                    class=move || {
+                        if sortable {
+                            format!(
+                                "{heading_class} {}",
+                                sort.with(|s| SortState::heading_class(s, idx))
+                            )
+                        } else {
+                            format!("{heading_class} disabled")
+                        }
+                    }
+                    on:click=move |e: web_sys::MouseEvent| {
+                        if sortable {
+                            let additive = e.shift_key();
+                            sort.update(|s| {
+                                SortState::toggle_multi(s, idx, additive)
+                            });
+                        }
+                    }>
+                    { (column.header)() }
+                </th>
+            }
+        })
+        .collect_view();
+
+    let columns = StoredValue::new(columns);
+    let row_key = move |item: &R| key.key(item);
+    let row = move |item: R| {
+        let td_list = columns.with_value(|columns| {
+            columns
+                .iter()
+                .map(|column| {
+                    view! {
+                        <td
+                            class=column.cell_class()
+                            colspan=column.colspan_attribute()>
+                        { (column.cell)(&item) }
+                        </td>
+                    }
+                })
+                .collect_view()
+        });
+        view! {
+            <TableRow>
+                { td_list }
+            </TableRow>
+        }
+    };
+
+    view! {
+        <div
+            class="ui basic fitted segment"
+            class:loading=loading>
+            <table class="ui sortable basic table">
+                <thead>
+                    <tr>
+                        { heading_items }
+                    </tr>
+                </thead>
+                <tbody>
+                <For
+                    each=rows
+                    key=row_key
+                    children=row
+                />
+                </tbody>
+                <tfoot>
+                    <tr>
+                        <th colspan=column_count.to_string()>
+                            <Pagination page_count=page_count page=page/>
+                        </th>
+                    </tr>
+                </tfoot>
+            </table>
+        </div>
+    }
+}
@@ -0,0 +1,119 @@
+use crate::models::{
+    Selectable,
+    SelectionSet,
+    SelectionState,
+};
+use leptos::prelude::*;
+use std::hash::Hash;
+
+/// Connects a single item to a signal of selected keys, eg. to bind a
+/// [Checkbox](super::Checkbox) to it.
+pub struct KeySelection<K: Eq + Hash + Send + Sync + 'static> {
+    key: K,
+    selected: RwSignal<SelectionSet<K>>,
+}
+
+impl<K: Eq + Hash + Send + Sync> KeySelection<K> {
+    /// Connects the item with the given key to the selected keys.
+    pub fn new(key: K, selected: RwSignal<SelectionSet<K>>) -> Self {
+        Self { key, selected }
+    }
+}
+
+impl<K: Eq + Hash + Clone + Send + Sync> Selectable for KeySelection<K> {
+    fn select(&mut self) {
+        let key = self.key.clone();
+        self.selected.update(|s| {
+            s.select(key);
+        });
+    }
+
+    fn deselect(&mut self) {
+        self.selected.update(|s| {
+            s.deselect(&self.key);
+        });
+    }
+
+    fn toggle(&mut self) {
+        let key = self.key.clone();
+        self.selected.update(|s| {
+            s.toggle(key);
+        });
+    }
+
+    fn is_selected(&self) -> bool {
+        self.selected.with(|s| s.is_selected(&self.key))
+    }
+}
+
+/// Connects a group of items to a signal of selected keys, eg. to bind a
+/// select-all [Checkbox](super::Checkbox) to it.
+///
+/// The group is indeterminate while only some of its items are selected.
+pub struct AllKeysSelection<K: Eq + Hash + Send + Sync + 'static> {
+    keys: Signal<Vec<K>>,
+    selected: RwSignal<SelectionSet<K>>,
+}
+
+impl<K: Eq + Hash + Send + Sync> AllKeysSelection<K> {
+    /// Connects the items with the given keys to the selected keys.
+    pub fn new(
+        keys: Signal<Vec<K>>,
+        selected: RwSignal<SelectionSet<K>>,
+    ) -> Self {
+        Self { keys, selected }
+    }
+}
+
+impl<K: Eq + Hash + Clone + Send + Sync> Selectable for AllKeysSelection<K> {
+    fn select(&mut self) {
+        let keys = self.keys.get_untracked();
+        self.selected.update(|s| s.select_all(keys));
+    }
+
+    fn deselect(&mut self) {
+        // keys that are not part of the group stay selected
+        self.keys.with_untracked(|keys| {
+            self.selected.update(|s| s.deselect_all(keys))
+        });
+    }
+
+    fn toggle(&mut self) {
+        if self.is_selected() {
+            self.deselect();
+        } else {
+            self.select();
+        }
+    }
+
+    fn is_selected(&self) -> bool {
+        self.selection_state() == SelectionState::All
+    }
+
+    fn is_indeterminate(&self) -> bool {
+        self.selection_state() == SelectionState::Some
+    }
+
+    fn selection_state(&self) -> SelectionState {
+        self.selected.with(|s| self.keys.with(|k| s.state_of(k)))
+    }
+}
+
+/// Shares the selection state with other components through the signal.
+impl Selectable for RwSignal<bool> {
+    fn select(&mut self) {
+        self.set(true);
+    }
+
+    fn deselect(&mut self) {
+        self.set(false);
+    }
+
+    fn toggle(&mut self) {
+        self.update(|selected| *selected = !*selected);
+    }
+
+    fn is_selected(&self) -> bool {
+        self.get()
+    }
+}
@@ -0,0 +1,149 @@
+use super::lazy::init_module;
+use crate::{
+    callbacks::CallbackRegistry,
+    jq::JQuery,
+    models::{
+        RangeModel,
+        RangeValue,
+    },
+    modules::InstanceGuard,
+    style::Classes,
+};
+use leptos::{
+    html,
+    prelude::*,
+};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = Object)]
+    type JsSliderConfig;
+    /// Configuration constructor.
+    #[wasm_bindgen(constructor, js_class = Object)]
+    fn new() -> JsSliderConfig;
+    /// Sets the lowest value of the slider.
+    #[wasm_bindgen(method, setter, js_name = "min")]
+    fn set_min(this: &JsSliderConfig, min: f64);
+    /// Sets the highest value of the slider.
+    #[wasm_bindgen(method, setter, js_name = "max")]
+    fn set_max(this: &JsSliderConfig, max: f64);
+    /// Sets the distance between the values, `0` allows any value.
+    #[wasm_bindgen(method, setter, js_name = "step")]
+    fn set_step(this: &JsSliderConfig, step: f64);
+    /// Sets the initial value of the first thumb.
+    #[wasm_bindgen(method, setter, js_name = "start")]
+    fn set_start(this: &JsSliderConfig, start: f64);
+    /// Sets the initial value of the second thumb of a range.
+    #[wasm_bindgen(method, setter, js_name = "end")]
+    fn set_end(this: &JsSliderConfig, end: f64);
+    /// Sets the handler that is called once a thumb has been moved.
+    #[wasm_bindgen(method, setter, js_name = "onChange")]
+    fn set_on_change(this: &JsSliderConfig, handler: &js_sys::Function);
+}
+
+/// Returns the value of the given thumb, `first` or `second`.
+fn thumb_value(slider: &JQuery, thumb: &str) -> Option<f64> {
+    slider
+        .call("slider", &[&"get thumbValue".into(), &thumb.into()])
+        .as_f64()
+}
+
+/// A slider bound to a [RangeModel].
+///
+/// The `fomantic-ui` slider module is initialized on mount with the bounds
+/// and step of the model, later changes of them are not reflected. A model
+/// with a `low`/`high` pair is shown as range with two thumbs. The selected
+/// values are clamped and snapped by the model, see
+/// [RangeModel::set_pair].
+#[component]
+pub fn Slider<T>(
+    /// Additional classes of the slider, eg. `labeled`.
+    #[prop(optional, into)]
+    class: Classes,
+    /// Disables the slider, the module ignores input while it has the
+    /// `disabled` class.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+    /// The range and the selected values.
+    value: RwSignal<RangeModel<T>>,
+) -> impl IntoView
+where
+    T: RangeValue + Send + Sync + 'static,
+{
+    let is_pair = value.with_untracked(RangeModel::is_pair);
+    let class = Classes::from("ui")
+        .with(class)
+        .with_if(is_pair, "range")
+        .with("slider")
+        .to_string();
+
+    let ref_slider = NodeRef::<html::Div>::new();
+    let initialized = RwSignal::new(false);
+    init_module(ref_slider, move |slider| {
+        let model = value.get_untracked();
+        let config = JsSliderConfig::new();
+        config.set_min(model.min().to_f64());
+        config.set_max(model.max().to_f64());
+        config.set_step(model.step().map_or(0.0, RangeValue::to_f64));
+        config.set_start(model.low().to_f64());
+        if is_pair {
+            config.set_end(model.high().to_f64());
+        }
+        let slider = JQuery::from_element(slider);
+        // the handler must outlive the module, it is dropped with the guard
+        let callbacks = CallbackRegistry::new();
+        config.set_on_change(&callbacks.set("onChange", {
+            let slider = slider.clone();
+            move |_| {
+                let (Some(first), second) = (
+                    thumb_value(&slider, "first"),
+                    thumb_value(&slider, "second"),
+                ) else {
+                    return JsValue::UNDEFINED;
+                };
+                let mut model = value.get_untracked();
+                let first = T::from_f64(first);
+                model.set_pair(first, second.map_or(first, T::from_f64));
+                if value.with_untracked(|current| *current != model) {
+                    value.set(model);
+                }
+                JsValue::UNDEFINED
+            }
+        }));
+        slider.init("slider", &config);
+        initialized.set(true);
+        Some(InstanceGuard::new(slider, "slider").owning(callbacks))
+    });
+
+    // reflects changes of the model in the module, without callbacks
+    Effect::new(move |_| {
+        let (low, high) = value.with(|v| (v.low().to_f64(), v.high().to_f64()));
+        if !initialized.get() {
+            return;
+        }
+        if let Some(slider) = ref_slider.get_untracked() {
+            let slider = JQuery::from_element(&slider);
+            if is_pair {
+                slider.call(
+                    "slider",
+                    &[
+                        &"set rangeValue".into(),
+                        &low.into(),
+                        &high.into(),
+                        &JsValue::FALSE,
+                    ],
+                );
+            } else {
+                slider.call(
+                    "slider",
+                    &[&"set value".into(), &low.into(), &JsValue::FALSE],
+                );
+            }
+        }
+    });
+
+    view! {
+        <div node_ref=ref_slider class=class class:disabled=disabled></div>
+    }
+}
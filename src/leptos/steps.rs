@@ -0,0 +1,197 @@
+use crate::style::IconName;
+use leptos::prelude::*;
+
+/// Drives the [Step]s of a wizard and shows the matching [StepPane].
+///
+/// Steps before the furthest reached one are completed and can be revisited
+/// by clicking them.
+#[derive(Clone, Copy)]
+pub struct StepController {
+    count: usize,
+    current: RwSignal<usize>,
+    reached: RwSignal<usize>,
+}
+
+impl StepController {
+    /// Creates a controller for the given amount of steps, starting at the
+    /// first one.
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            current: RwSignal::new(0),
+            reached: RwSignal::new(0),
+        }
+    }
+
+    /// The index of the current step.
+    pub fn current(&self) -> usize {
+        self.current.get()
+    }
+
+    /// Whether the current step is the first one.
+    pub fn is_first(&self) -> bool {
+        self.current() == 0
+    }
+
+    /// Whether the current step is the last one.
+    pub fn is_last(&self) -> bool {
+        self.current() + 1 >= self.count
+    }
+
+    /// Whether the given step is the current one.
+    pub fn is_active(&self, step: usize) -> bool {
+        self.current() == step
+    }
+
+    /// Whether the given step has been completed.
+    pub fn is_completed(&self, step: usize) -> bool {
+        step < self.reached.get() && !self.is_active(step)
+    }
+
+    /// Completes the current step and advances to the next one.
+    pub fn next(&self) {
+        self.go_to(self.current.get_untracked() + 1);
+    }
+
+    /// Goes back to the previous step.
+    pub fn previous(&self) {
+        self.go_to(self.current.get_untracked().saturating_sub(1));
+    }
+
+    /// Goes to the given step. Steps after the furthest reached one can only
+    /// be entered one at a time.
+    pub fn go_to(&self, step: usize) {
+        let reached = self.reached.get_untracked();
+        if step >= self.count || step > reached + 1 {
+            return;
+        }
+        self.current.set(step);
+        if step > reached {
+            self.reached.set(step);
+        }
+    }
+}
+
+/// A group of `fomantic-ui` steps.
+#[component]
+pub fn Steps(
+    /// Numbers the steps.
+    #[prop(optional)]
+    ordered: bool,
+    /// Shows the steps below each other.
+    #[prop(optional)]
+    vertical: bool,
+    /// Takes the full width of its container.
+    #[prop(optional)]
+    fluid: bool,
+    /// Drives the steps that have an `index`.
+    #[prop(optional)]
+    controller: Option<StepController>,
+    /// The steps.
+    children: Children,
+) -> impl IntoView {
+    if let Some(controller) = controller {
+        provide_context(controller);
+    }
+
+    view! {
+        <div
+            class="ui steps"
+            class:ordered=ordered
+            class:vertical=vertical
+            class:fluid=fluid>
+            { children() }
+        </div>
+    }
+}
+
+/// A single step of [Steps].
+///
+/// If an `index` is given, the state of the step is driven by the
+/// [StepController] of the surrounding [Steps] in addition to the signals.
+#[component]
+pub fn Step(
+    /// The title of the step.
+    #[prop(into)]
+    title: String,
+    /// Further description of the step.
+    #[prop(optional, into)]
+    description: Option<String>,
+    /// Icon of the step, eg. `truck`.
+    #[prop(optional, into)]
+    icon: Option<IconName>,
+    /// Position of the step in the [StepController].
+    #[prop(optional)]
+    index: Option<usize>,
+    /// Marks the step as active.
+    #[prop(optional, into)]
+    active: Signal<bool>,
+    /// Marks the step as completed.
+    #[prop(optional, into)]
+    completed: Signal<bool>,
+    /// Disables the step.
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+) -> impl IntoView {
+    let controlled = index.zip(use_context::<StepController>());
+    let is_active = move || {
+        active.get() || controlled.is_some_and(|(idx, c)| c.is_active(idx))
+    };
+    let is_completed = move || {
+        completed.get()
+            || controlled.is_some_and(|(idx, c)| c.is_completed(idx))
+    };
+    let on_click = move |_| {
+        if disabled.get_untracked() {
+            return;
+        }
+        if let Some((idx, controller)) = controlled {
+            controller.go_to(idx);
+        }
+    };
+
+    view! {
+        <div
+            class="step"
+            class:link=controlled.is_some()
+            class:active=is_active
+            class:completed=is_completed
+            class:disabled=disabled
+            on:click=on_click>
+            { icon.map(|icon| view! { <i class=icon.to_string()></i> }) }
+            <div class="content">
+                <div class="title">{ title }</div>
+                { description.map(|description| view! {
+                    <div class="description">{ description }</div>
+                }) }
+            </div>
+        </div>
+    }
+}
+
+/// Content that is only shown while the given step is the current one.
+///
+/// The content stays mounted, so inputs keep their state when navigating
+/// between the steps.
+#[component]
+pub fn StepPane(
+    /// The controller of the steps.
+    controller: StepController,
+    /// Position of the step the content belongs to.
+    index: usize,
+    /// The content of the step.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div
+            style:display=move || {
+                if controller.is_active(index) {
+                    ""
+                } else {
+                    "none"
+                }
+            }>
+            { children() }
+        </div>
+    }
+}
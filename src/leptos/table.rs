@@ -1,30 +1,160 @@
-use super::TableRow;
-use leptos::*;
-use leptos_meta::{
-    provide_meta_context,
-    Script,
+use super::{
+    lazy::init_module,
+    table_column_chooser::TableColumnChooser,
+    table_group::{
+        group_rows,
+        TableGroupBy,
+        TableLine,
+    },
+    table_resize::ResizeHandle,
+    table_selection::{
+        AllRowsSelection,
+        RowSelection,
+    },
+    table_sort::{
+        apply_tablesort,
+        column_ranks,
+        sort_ranks,
+        sort_rows,
+    },
+    table_sort_function::{
+        apply_sort_functions,
+        FLOAT_SORT_FUNCTION,
+    },
+    use_fomantic_config,
+    Checkbox,
+    Pagination,
+    RowKey,
+    SortDirection,
+    SortState,
+    TableColumn,
+    TableExport,
+    TableKey,
+    TableSortMode,
+};
+use crate::{
+    a11y::Aria,
+    jq::JQuery,
+    models::SelectionSet,
+    style::{
+        Color,
+        ColumnWidth,
+        Size,
+    },
+    Key,
+};
+use leptos::{
+    html,
+    prelude::*,
 };
 use std::{
-    hash::{
-        DefaultHasher,
-        Hash,
-        Hasher,
+    cmp::Ordering,
+    collections::{
+        HashMap,
+        HashSet,
     },
     iter::Iterator,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering as AtomicOrdering,
+    },
 };
 use tracing::debug;
-use wasm_bindgen::prelude::wasm_bindgen;
-
-#[wasm_bindgen]
-extern "C" {
-    /// Intermediary type to grab the result from jquery.
-    type Table;
-    /// Queries the table with the given id from the DOM.
-    #[wasm_bindgen(js_name = "$")]
-    fn new_table(id: &str) -> Table;
-    /// Enables sorting for the table with the given id.
-    #[wasm_bindgen(method)]
-    fn tablesort(this: &Table);
+use wasm_bindgen::JsCast;
+
+/// Counter used to generate unique ids for tables without an id.
+static TABLE_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether the row matches the filter text of the table.
+///
+/// Columns without a filter predicate are ignored, a table without any
+/// predicate shows all rows.
+fn matches_filter<R>(columns: &[TableColumn<R>], row: &R, query: &str) -> bool {
+    let mut predicates = columns
+        .iter()
+        .filter_map(|c| c.filter.as_deref())
+        .peekable();
+    query.is_empty()
+        || predicates.peek().is_none()
+        || predicates.any(|predicate| predicate(row, query))
+}
+
+/// Whether the row is selected by the range filters of all columns that
+/// have one.
+fn matches_ranges<R>(columns: &[TableColumn<R>], row: &R) -> bool {
+    columns
+        .iter()
+        .filter_map(|c| c.range_filter.as_deref())
+        .all(|selected| selected(row))
+}
+
+/// The comparators of the sorted columns with their direction. Columns
+/// without comparator are skipped.
+fn sort_criteria<'a, R>(
+    columns: &'a [TableColumn<R>],
+    states: &[SortState],
+) -> Vec<(SortDirection, &'a dyn Fn(&R, &R) -> Ordering)> {
+    states
+        .iter()
+        .filter_map(|state| {
+            let comparator =
+                columns.get(state.column)?.comparator.as_deref()?;
+            Some((state.direction, comparator as &dyn Fn(&R, &R) -> Ordering))
+        })
+        .collect()
+}
+
+/// Narrowest viewport width that is not considered a mobile device by
+/// `fomantic-ui`.
+const MOBILE_BREAKPOINT: &str = "(max-width: 767px)";
+
+/// Whether the viewport matches the given media query, updated when the
+/// viewport changes.
+///
+/// Always `false` while rendering on the server and until the component is
+/// mounted, so hydration sees the same markup.
+fn use_media_query(query: &'static str) -> Signal<bool> {
+    let matches = RwSignal::new(false);
+    // removes the listener when the owner is disposed
+    let listener = StoredValue::new_local(None);
+    Effect::new(move |_| {
+        let Ok(Some(media)) = window().match_media(query) else {
+            return;
+        };
+        matches.set(media.matches());
+        listener.set_value(Some(gloo::events::EventListener::new(
+            &media,
+            "change",
+            {
+                let media = media.clone();
+                move |_| matches.set(media.matches())
+            },
+        )));
+    });
+    matches.into()
+}
+
+/// Viewport sizes below which the columns of a table are stacked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TableStacking {
+    /// Stacks the columns on mobile devices.
+    #[default]
+    Mobile,
+    /// Stacks the columns on tablets and mobile devices.
+    Tablet,
+    /// Never stacks the columns.
+    Unstackable,
+}
+
+impl std::fmt::Display for TableStacking {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Mobile => "",
+            Self::Tablet => "tablet stackable",
+            Self::Unstackable => "unstackable",
+        };
+        write!(f, "{s}")
+    }
 }
 
 /// Algorithms for sorting a table column.
@@ -33,8 +163,22 @@ extern "C" {
 pub enum TableSortingAlgorithm {
     /// The default, builtin sorting.
     Default,
-    /// A custom float sorting algorithm.
+    /// Sorts by the float value of the cells.
     Float,
+    /// Sorts by a function registered with
+    /// [register_sort_function](super::register_sort_function).
+    Custom(&'static str),
+}
+
+impl TableSortingAlgorithm {
+    /// The name of the registered sort function, if any.
+    pub(crate) fn function_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Default => None,
+            Self::Float => Some(FLOAT_SORT_FUNCTION),
+            Self::Custom(name) => Some(name),
+        }
+    }
 }
 
 impl std::fmt::Display for TableSortingAlgorithm {
@@ -43,7 +187,7 @@ impl std::fmt::Display for TableSortingAlgorithm {
         f: &mut std::fmt::Formatter<'_>,
     ) -> Result<(), std::fmt::Error> {
         let s = match self {
-            Self::Default => "",
+            Self::Default | Self::Custom(_) => "",
             Self::Float => "float",
         };
         write!(f, "{s}")
@@ -58,98 +202,1002 @@ impl std::fmt::Display for TableSortingAlgorithm {
 pub fn Table<D, R>(
     /// The table data.
     #[prop(into)]
-    data: MaybeSignal<D>,
-    /// A list of closures defining the column heading.
-    column_heading: Vec<Box<dyn Fn(NodeRef<html::Th>) -> Fragment>>,
-    /// A list of closures that return the contents of each column.
-    columns: Vec<Box<dyn Fn(&R) -> Fragment>>,
-    /// Determines the sorting algorithm of the column.
+    data: Signal<D>,
+    /// The id of the table element. A unique id is generated if not set.
+    #[prop(optional, into)]
+    id: Option<String>,
+    /// The columns of the table.
+    columns: Vec<TableColumn<R>>,
+    /// Identifies the rows, eg. `|row: &Row| row.id`.
+    #[prop(into)]
+    key: TableKey<R>,
+    /// Determines whether the table is sorted by `tablesort` or in Rust.
+    #[prop(optional)]
+    sort_mode: TableSortMode,
+    /// The current sort order, ordered by priority. Updated when a heading
+    /// is clicked, shift-click sorts by multiple columns. Sorts the rows when
+    /// set from the outside, eg. to restore a saved order. `tablesort` can
+    /// not restore the unsorted order, so clearing the order only affects
+    /// [TableSortMode::Rust].
+    ///
+    /// Sorting by multiple columns using `tablesort` requires comparators for
+    /// the sorted columns.
+    #[prop(optional)]
+    sort: Option<RwSignal<Vec<SortState>>>,
+    /// Amount of rows shown per page. Enables pagination when set.
+    #[prop(optional)]
+    page_size: Option<usize>,
+    /// The current page, starting at zero. Only used when `page_size` is set.
+    #[prop(optional)]
+    page: Option<RwSignal<usize>>,
+    /// The selected rows. Adds a checkbox column, including a select-all
+    /// checkbox in the header, when set.
+    #[prop(optional)]
+    selection: Option<RwSignal<Vec<R>>>,
+    /// The filter text that is matched against the column filter predicates.
+    #[prop(optional)]
+    filter: Option<RwSignal<String>>,
+    /// Renders a search input above the table that updates `filter`.
+    #[prop(optional)]
+    searchable: bool,
+    /// Keeps the header visible while the body scrolls.
+    #[prop(optional)]
+    sticky_header: bool,
+    /// Maximum height of the scrolling container when `sticky_header` is set.
+    /// Defaults to `30em`.
+    #[prop(optional, into)]
+    max_height: Option<String>,
+    /// Keeps the leading column visible while the table scrolls
+    /// horizontally.
+    #[prop(optional)]
+    sticky_first_column: bool,
+    /// Content of a footer row spanning all columns.
+    #[prop(optional, into)]
+    footer: Option<ViewFn>,
+    /// The ids of the hidden columns. Only hideable columns are affected.
+    #[prop(optional)]
+    hidden_columns: Option<RwSignal<HashSet<String>>>,
+    /// Renders a dropdown above the table for showing and hiding the
+    /// hideable columns.
+    #[prop(optional)]
+    column_chooser: bool,
+    /// Handle for exporting the filtered rows, sorted when using
+    /// [TableSortMode::Rust].
+    #[prop(optional)]
+    export: Option<TableExport>,
+    /// Reduces the styling of the table. Defaults to `true`.
+    #[prop(default = true)]
+    basic: bool,
+    /// Divides the table into cells.
+    #[prop(optional)]
+    celled: bool,
+    /// Alternates the background of the rows.
+    #[prop(optional)]
+    striped: bool,
+    /// Reduces the padding of the cells.
+    #[prop(optional)]
+    compact: bool,
+    /// Highlights the row below the cursor.
+    #[prop(optional)]
+    selectable: bool,
+    /// Inverts the colors of the table.
+    #[prop(optional)]
+    inverted: bool,
+    /// The color of the table.
+    #[prop(optional)]
+    color: Option<Color>,
+    /// The size of the table.
+    #[prop(optional)]
+    size: Option<Size>,
+    /// Divides the table into the given amount of equal width columns.
+    #[prop(optional)]
+    column_count: Option<ColumnWidth>,
+    /// Shown instead of the rows when there is no data, or no row matches
+    /// the filter. Defaults to a placeholder message.
+    #[prop(optional, into)]
+    empty: Option<ViewFn>,
+    /// Whether the data is being loaded. Shows a loader above the rows, or
+    /// placeholder rows while there is no data yet.
+    #[prop(optional, into)]
+    loading: Option<Signal<bool>>,
+    /// Called with the row and the event when a row is clicked.
+    #[prop(optional, into)]
+    on_row_click: Option<Callback<(R, web_sys::MouseEvent)>>,
+    /// Called with the row and the event when a row is double clicked.
+    #[prop(optional, into)]
+    on_row_dblclick: Option<Callback<(R, web_sys::MouseEvent)>>,
+    /// The highlighted row. Set to the clicked row, eg. for showing its
+    /// details next to the table.
+    #[prop(optional)]
+    active_row: Option<RwSignal<Option<R>>>,
+    /// Called with the focused row when `Enter` is pressed. `Space` toggles
+    /// the selection of the focused row, the arrow keys move the focus.
+    #[prop(optional, into)]
+    on_row_activate: Option<Callback<R>>,
+    /// The widths of the resized columns in pixels, by column index.
+    #[prop(optional)]
+    column_widths: Option<RwSignal<HashMap<usize, f64>>>,
+    /// Determines on which devices the columns are stacked.
+    #[prop(optional)]
+    stacking: TableStacking,
+    /// Renders the rows as cards instead of a table on mobile devices.
+    #[prop(optional)]
+    mobile_cards: bool,
+    /// Groups the rows on the current page below collapsible headers.
+    #[prop(optional, into)]
+    group_by: Option<TableGroupBy<R>>,
+    /// The labels of the collapsed groups.
+    #[prop(optional)]
+    collapsed_groups: Option<RwSignal<HashSet<String>>>,
+    /// ARIA attributes labelling the table.
     #[prop(optional, into)]
-    column_sorting: MaybeSignal<Vec<TableSortingAlgorithm>>,
+    aria: Aria,
 ) -> impl IntoView
 where
-    D: IntoIterator<Item = R> + Clone + 'static,
-    R: Hash + 'static,
+    D: IntoIterator<Item = R> + Clone + Send + Sync + 'static,
+    R: Clone + Send + Sync + 'static,
 {
-    // Used for inserting custom sort algorithms via leptos-meta
-    provide_meta_context();
-
-    let heading_items = column_heading
-        .into_iter()
-        .enumerate()
-        .map(|(idx, head)| {
-            let sorting = column_sorting.clone();
-            move || {
-                let ref_th = create_node_ref::<html::Th>();
-                let sorting_class = sorting
-                    .with(|sorting_vec| {
-                        sorting_vec.get(idx).map(|s| s.to_owned())
+    let config = use_fomantic_config();
+    let inverted = inverted || config.inverted;
+    let rust_sorting = sort_mode == TableSortMode::Rust;
+    let id = id.unwrap_or_else(|| {
+        format!(
+            "fomantic-table-{}",
+            TABLE_ID_COUNTER.fetch_add(1, AtomicOrdering::Relaxed)
+        )
+    });
+    let sort = sort.unwrap_or_else(|| RwSignal::new(vec![]));
+    let table_selector = StoredValue::new(format!("#{id}"));
+    let columns = StoredValue::new(columns);
+    let hidden_columns =
+        hidden_columns.unwrap_or_else(|| RwSignal::new(HashSet::new()));
+    let column_visible = move |idx: usize| {
+        hidden_columns
+            .with(|hidden| columns.with_value(|c| c[idx].is_visible(hidden)))
+    };
+    let colspan = move || {
+        let visible = (0..columns.with_value(Vec::len))
+            .filter(|idx| column_visible(*idx))
+            .map(|idx| columns.with_value(|c| c[idx].colspan))
+            .sum::<usize>();
+        (visible + usize::from(selection.is_some())).to_string()
+    };
+    let data = Signal::derive(move || data.get());
+    let filter = filter.unwrap_or_else(|| RwSignal::new(String::new()));
+    let filtered = Signal::derive(move || {
+        let query = filter.get();
+        let query = query.trim();
+        data.with(|d| {
+            columns.with_value(|c| {
+                d.clone()
+                    .into_iter()
+                    .filter(|row| {
+                        matches_filter(c, row, query) && matches_ranges(c, row)
                     })
-                    .map(|sort| sort.to_string())
-                    .unwrap_or("".to_string());
-                if !sorting_class.is_empty() {
-                    ref_th.on_load(move |th| {
-                        let _ = th.classes(sorting_class);
-                    });
-                }
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+    let row_key = key;
+    let card_key = row_key.clone();
+    let selected_keys = {
+        let row_key = row_key.clone();
+        Memo::new(move |_| {
+            selection
+                .map(|s| {
+                    s.with(|s| {
+                        s.iter()
+                            .map(|r| row_key.key(r))
+                            .collect::<SelectionSet<_>>()
+                    })
+                })
+                .unwrap_or_default()
+        })
+    };
 
-                view! {
-                    <th
-                        node_ref=ref_th>
-                        { head(ref_th) }
-                    </th>
+    // sticky headings need an opaque background and have to stay above the
+    // positioned checkboxes of the body
+    let heading_style = sticky_header.then_some(
+        "position: sticky; top: 0; z-index: 2; background-clip: padding-box;",
+    );
+
+    let select_all_heading = selection.map(|selection| {
+        let all_rows = RwSignal::new(AllRowsSelection {
+            data: filtered,
+            selection,
+            selected_keys,
+            row_key: row_key.clone(),
+        });
+        view! {
+            <th class="collapsing no-sort" style=heading_style>
+                <Checkbox class="fitted" data=all_rows/>
+            </th>
+        }
+    });
+
+    let column_widths =
+        column_widths.unwrap_or_else(|| RwSignal::new(HashMap::new()));
+    let heading_items = (0..columns.with_value(Vec::len))
+        .map(|idx| {
+            let (heading_class, sortable, span, resize, sort_function) =
+                columns.with_value(|c| {
+                    (
+                        c[idx].heading_class(rust_sorting),
+                        c[idx].is_sortable(rust_sorting),
+                        c[idx].colspan_attribute(),
+                        c[idx].resize,
+                        c[idx]
+                            .sorting_algorithm
+                            .function_name()
+                            .filter(|_| !rust_sorting),
+                    )
+                });
+            let style = move || {
+                let width = column_widths.with(|w| w.get(&idx).copied());
+                let style = [
+                    heading_style.map(str::to_string),
+                    // the resize handle is positioned relative to the heading
+                    (resize.is_some() && !sticky_header)
+                        .then(|| "position: relative;".to_string()),
+                    width.map(|w| format!("width: {w}px;")),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+                (!style.is_empty()).then_some(style)
+            };
+            let class = move || {
+                if rust_sorting && sortable {
+                    format!(
+                        "{heading_class} {}",
+                        sort.with(|s| SortState::heading_class(s, idx))
+                    )
+                } else {
+                    heading_class.clone()
+                }
+            };
+            let on_click = move |e: web_sys::MouseEvent| {
+                if !sortable {
+                    return;
                 }
+                let additive = e.shift_key();
+                sort.update(|s| SortState::toggle_multi(s, idx, additive));
+            };
+            // shows the priority when sorted by multiple columns
+            let priority = move || {
+                sort.with(|s| {
+                    s.iter()
+                        .position(|s| s.column == idx)
+                        .filter(|_| s.len() > 1)
+                })
+                .map(|pos| {
+                    view! {
+                        <span class="ui mini circular label">{ pos + 1 }</span>
+                    }
+                })
+            };
+            move || {
+                column_visible(idx).then(|| {
+                    view! {
+                        <th
+                            class=class.clone()
+                            colspan=span.clone()
+                            style=style
+                            data-sort-function=sort_function
+                            data-column=idx
+                            on:click=on_click>
+                            { columns.with_value(|c| (c[idx].header)()) }
+                            { priority }
+                            {
+                                resize.map(|range| view! {
+                                    <ResizeHandle
+                                        column=idx
+                                        range=range
+                                        widths=column_widths/>
+                                })
+                            }
+                        </th>
+                    }
+                })
             }
         })
-        .collect::<Vec<_>>();
-
-    let ref_table = create_node_ref::<leptos::html::Table>();
-    let init_table = move || {
-        if let Some(table) = ref_table.get() {
-            let _ = table.on_mount(|_| {
-                new_table("table.ui.sortable.table").tablesort();
-                debug!("Initializing sortable table finished.");
+        .collect_view();
+
+    let page = page.unwrap_or_else(|| RwSignal::new(0));
+    let page_size = page_size.filter(|size| *size > 0);
+    let page_count = Signal::derive(move || match page_size {
+        Some(size) => filtered.with(Vec::len).div_ceil(size).max(1),
+        None => 1,
+    });
+    // keep the current page in range when the data shrinks
+    Effect::new(move |_| {
+        let count = page_count.get();
+        if page.get_untracked() >= count {
+            page.set(count - 1);
+        }
+    });
+    // start over on the first page when the sort order or filter changes
+    Effect::new(move |prev: Option<()>| {
+        sort.track();
+        filter.track();
+        if prev.is_some() {
+            page.set(0);
+        }
+    });
+    let sorted = move || {
+        let mut rows = filtered.get();
+        if rust_sorting {
+            sort.with(|s| {
+                columns.with_value(|c| {
+                    sort_rows(&mut rows, &sort_criteria(c, s));
+                })
             });
         }
+        rows
+    };
+    if let Some(export) = export {
+        export.attach(move || {
+            let rows = untrack(sorted);
+            columns.with_value(|c| {
+                let exported =
+                    c.iter().filter(|c| c.text.is_some()).collect::<Vec<_>>();
+                let heading = exported
+                    .iter()
+                    .map(|c| c.id.clone().unwrap_or_default())
+                    .collect::<Vec<_>>();
+                std::iter::once(heading)
+                    .chain(rows.iter().map(|row| {
+                        exported
+                            .iter()
+                            .filter_map(|c| c.text.as_ref())
+                            .map(|text| text(row))
+                            .collect()
+                    }))
+                    .collect()
+            })
+        });
+    }
+    let rows = move || {
+        let rows = sorted();
+        match page_size {
+            Some(size) => rows
+                .into_iter()
+                .skip(page.get() * size)
+                .take(size)
+                .collect::<Vec<_>>(),
+            None => rows,
+        }
     };
 
-    view! {
-        // add custom sort algorithms
-        <Script src="/js/tablesort-custom-sort.js" defer="true"></Script>
+    // ranks of the rows, used by tablesort to apply the comparators
+    let ranks_key = row_key.clone();
+    let ranks = Memo::new(move |_| {
+        let has_comparators =
+            columns.with_value(|c| c.iter().any(|c| c.comparator.is_some()));
+        if rust_sorting || !has_comparators {
+            return HashMap::new();
+        }
+        let rows = data.get().into_iter().collect::<Vec<_>>();
+        columns.with_value(|c| {
+            let comparators = c
+                .iter()
+                .map(|c| {
+                    c.comparator
+                        .as_deref()
+                        .map(|c| c as &dyn Fn(&R, &R) -> Ordering)
+                })
+                .collect::<Vec<_>>();
+            column_ranks(&rows, |r| ranks_key.key(r), &comparators)
+        })
+    });
+    // ranks of the rows when sorted by multiple columns using tablesort,
+    // applied to the column that has been sorted last. They are reversed if
+    // the column is sorted in descending order, as tablesort sorts them in
+    // the direction of the column
+    let sort_ranks_key = row_key.clone();
+    let multi_ranks = Memo::new(move |_| {
+        if rust_sorting || sort.with(Vec::len) < 2 {
+            return None;
+        }
+        let rows = data.get().into_iter().collect::<Vec<_>>();
+        let last = sort.with(|s| s.last().copied())?;
+        let mut ranks = sort.with(|s| {
+            columns.with_value(|c| {
+                sort_ranks(
+                    &rows,
+                    |r| sort_ranks_key.key(r),
+                    &sort_criteria(c, s),
+                )
+            })
+        });
+        if last.direction == SortDirection::Descending {
+            let max = ranks.values().copied().max().unwrap_or_default();
+            ranks.values_mut().for_each(|rank| *rank = max - *rank);
+        }
+        Some((last.column, ranks))
+    });
+
+    let column_footer = columns
+        .with_value(|c| {
+            c.iter()
+                .any(|c| c.footer.is_some() || c.aggregation.is_some())
+        })
+        .then(|| {
+            let footer_items = (0..columns.with_value(Vec::len))
+                .map(|idx| {
+                    let (class, span) = columns.with_value(|c| {
+                        (c[idx].cell_class(), c[idx].colspan_attribute())
+                    });
+                    let content = move || {
+                        columns.with_value(|c| {
+                            let column = &c[idx];
+                            match (&column.footer, &column.aggregation) {
+                                (Some(footer), _) => {
+                                    Some(filtered.with(|rows| footer(rows)))
+                                }
+                                (None, Some(aggregation)) => Some(
+                                    aggregation.compute(&rows()).into_any(),
+                                ),
+                                (None, None) => None,
+                            }
+                        })
+                    };
+                    move || {
+                        column_visible(idx).then(|| {
+                            view! {
+                                <th class=class.clone() colspan=span.clone()>
+                                    { content }
+                                </th>
+                            }
+                        })
+                    }
+                })
+                .collect_view();
+            view! {
+                <tr>
+                    { selection.is_some().then(|| view! { <th></th> }) }
+                    { footer_items }
+                </tr>
+            }
+        });
+    let footer_row = footer.map(|footer| {
+        view! {
+            <tr>
+                <th colspan=colspan>{ footer.run() }</th>
+            </tr>
+        }
+    });
+    let pagination_row = page_size.map(|_| {
+        view! {
+            <tr>
+                <th colspan=colspan>
+                    <Pagination page_count=page_count page=page/>
+                </th>
+            </tr>
+        }
+    });
+    let footer = (column_footer.is_some()
+        || footer_row.is_some()
+        || pagination_row.is_some())
+    .then(|| {
+        view! {
+            <tfoot>
+                { column_footer }
+                { footer_row }
+                { pagination_row }
+            </tfoot>
+        }
+    });
+
+    let ref_table = NodeRef::<html::Table>::new();
+    let selector = table_selector.get_value();
+    // tablesort is no fomantic-ui module and has nothing to destroy
+    init_module(ref_table, move |_| {
+        if !rust_sorting {
+            apply_sort_functions(&selector);
+            JQuery::select(&selector).call("tablesort", &[]);
+            sort.with_untracked(|s| apply_tablesort(&selector, s));
+            debug!("Initializing sortable table {selector} finished.");
+        }
+        None
+    });
+    // tablesort keeps its own order and only sorts by a single column, so
+    // the sort order is applied whenever it changes, by the ranks of all
+    // sorted columns once they have been rendered
+    Effect::new(move |_| {
+        sort.track();
+        if rust_sorting {
+            return;
+        }
+        request_animation_frame(move || {
+            table_selector.with_value(|table| {
+                sort.with_untracked(|s| apply_tablesort(table, s))
+            })
+        });
+    });
 
+    let search = searchable.then(|| {
+        view! {
+            <div class="ui icon input">
+                <input
+                    type="text"
+                    placeholder=config.texts.search_placeholder
+                    prop:value=filter
+                    on:input=move |ev| filter.set(event_target_value(&ev))/>
+                <i class="search icon"></i>
+            </div>
+        }
+    });
+
+    let table_class = [
+        Some("ui sortable".to_string()),
+        color.map(|c| c.to_string()),
+        size.map(|s| s.to_string()),
+        column_count.map(|c| format!("{} column", c.as_word())),
+        basic.then(|| "basic".to_string()),
+        celled.then(|| "celled".to_string()),
+        striped.then(|| "striped".to_string()),
+        compact.then(|| "compact".to_string()),
+        selectable.then(|| "selectable".to_string()),
+        inverted.then(|| "inverted".to_string()),
+        Some(stacking.to_string()),
+        Some("table".to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|c| !c.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let no_data = config.texts.no_data;
+    let empty = empty.unwrap_or_else(|| {
+        ViewFn::from(move || {
+            view! {
+                <div class="ui placeholder segment">
+                    <div class="ui icon header">
+                        <i class="search icon"></i>
+                        { no_data.clone() }
+                    </div>
+                </div>
+            }
+        })
+    });
+    let is_loading = move || loading.map(|l| l.get()).unwrap_or_default();
+    let empty_row = move || {
+        if !filtered.with(Vec::is_empty) {
+            return None;
+        }
+        let rows = if is_loading() {
+            (0..page_size.unwrap_or(3))
+                .map(|_| {
+                    view! {
+                        <tr>
+                            <td colspan=colspan>
+                                <div class="ui fluid placeholder">
+                                    <div class="line"></div>
+                                </div>
+                            </td>
+                        </tr>
+                    }
+                })
+                .collect_view()
+                .into_any()
+        } else {
+            view! {
+                <tr>
+                    <td colspan=colspan>{ empty.run() }</td>
+                </tr>
+            }
+            .into_any()
+        };
+        Some(rows)
+    };
+
+    let chooser = column_chooser.then(|| {
+        view! {
+            <TableColumnChooser columns=columns hidden_columns=hidden_columns/>
+        }
+    });
+
+    // absolute positions of the shown rows, used for keyboard navigation
+    let positions_key = row_key.clone();
+    let row_positions = Memo::new(move |_| {
+        let offset = page_size.map_or(0, |size| page.get() * size);
+        rows()
+            .iter()
+            .enumerate()
+            .map(|(pos, r)| (positions_key.key(r), offset + pos))
+            .collect::<HashMap<_, _>>()
+    });
+    let focused_row = RwSignal::new(None::<RowKey>);
+    let navigation_key = row_key.clone();
+    let navigation_id = id.clone();
+    let on_keydown = move |e: web_sys::KeyboardEvent| {
+        // only rows are navigated, not the inputs inside of them
+        let Some(row) = e
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+            .filter(|el| el.tag_name() == "TR")
+        else {
+            return;
+        };
+        // the heading row has index 1
+        let Some(position) = row
+            .get_attribute("aria-rowindex")
+            .and_then(|i| i.parse::<usize>().ok())
+            .and_then(|i| i.checked_sub(2))
+        else {
+            return;
+        };
+        let key = e.key();
+        if Key::ArrowDown.matches(&key) || Key::ArrowUp.matches(&key) {
+            e.prevent_default();
+            let target = if Key::ArrowDown.matches(&key) {
+                position + 1
+            } else if let Some(target) = position.checked_sub(1) {
+                target
+            } else {
+                return;
+            };
+            let next = document()
+                .query_selector(&format!(
+                    "#{navigation_id} tbody tr[aria-rowindex=\"{}\"]",
+                    target + 2
+                ))
+                .ok()
+                .flatten()
+                .and_then(|next| next.dyn_into::<web_sys::HtmlElement>().ok());
+            if let Some(next) = next {
+                let _ = next.focus();
+            }
+            return;
+        }
+        let offset = page_size.map_or(0, |size| page.get_untracked() * size);
+        let Some(item) = position
+            .checked_sub(offset)
+            .and_then(|pos| untrack(rows).into_iter().nth(pos))
+        else {
+            return;
+        };
+        if Key::Enter.matches(&key) {
+            e.prevent_default();
+            if let Some(active) = active_row {
+                active.set(Some(item.clone()));
+            }
+            if let Some(on_row_activate) = on_row_activate {
+                on_row_activate.run(item);
+            }
+        } else if Key::Space.matches(&key) {
+            let Some(selection) = selection else {
+                return;
+            };
+            e.prevent_default();
+            let item_key = navigation_key.key(&item);
+            selection.update(|s| {
+                match s.iter().position(|r| navigation_key.key(r) == item_key) {
+                    Some(idx) => {
+                        s.remove(idx);
+                    }
+                    None => s.push(item),
+                }
+            });
+        }
+    };
+
+    let line_key = row_key.clone();
+    let line_key = move |line: &TableLine<R>| line.key(&line_key);
+    let render_row = move |item: R| {
+        let key = row_key.key(&item);
+        let td_list = (0..columns.with_value(Vec::len))
+            .map(|idx| {
+                let item = item.clone();
+                let key = key.clone();
+                let sort_value = move || {
+                    let multi_rank = multi_ranks.with(|m| {
+                        m.as_ref()
+                            .filter(|(column, _)| *column == idx)
+                            .and_then(|(_, ranks)| ranks.get(&key).copied())
+                    });
+                    multi_rank
+                        .or_else(|| {
+                            ranks.with(|r| {
+                                r.get(&key).and_then(|ranks| {
+                                    ranks.get(idx).copied().flatten()
+                                })
+                            })
+                        })
+                        .map(|rank| rank.to_string())
+                };
+                move || {
+                    column_visible(idx).then(|| {
+                        let editing = RwSignal::new(false);
+                        let editor =
+                            columns.with_value(|c| c[idx].editor.clone());
+                        let editable =
+                            editor.as_ref().is_some_and(|e| !e.is_inline());
+                        let item = item.clone();
+                        let content = move || match &editor {
+                            Some(editor)
+                                if editor.is_inline() || editing.get() =>
+                            {
+                                editor.render(item.clone(), editing)
+                            }
+                            _ => columns.with_value(|c| (c[idx].cell)(&item)),
+                        };
+                        columns.with_value(|c| {
+                            view! {
+                                <td
+                                    class=c[idx].cell_class()
+                                    colspan=c[idx].colspan_attribute()
+                                    data-sort-value=sort_value.clone()
+                                    on:dblclick=move |_| {
+                                        if editable {
+                                            editing.set(true);
+                                        }
+                                    }>
+                                { content }
+                                </td>
+                            }
+                        })
+                    })
+                }
+            })
+            .collect_view();
+        let select_cell = selection.map(|selection| {
+            let row = RwSignal::new(RowSelection {
+                row: item.clone(),
+                key: key.clone(),
+                selection,
+                selected_keys,
+                row_key: row_key.clone(),
+            });
+            view! {
+                <td class="collapsing">
+                    <Checkbox class="fitted" data=row/>
+                </td>
+            }
+        });
+        let active_key = row_key.clone();
+        let active = {
+            let key = key.clone();
+            move || {
+                active_row.is_some_and(|active| {
+                    active.with(|a| {
+                        a.as_ref().is_some_and(|a| active_key.key(a) == key)
+                    })
+                })
+            }
+        };
+        let click_item = item.clone();
+        let on_click = move |e| {
+            if let Some(active) = active_row {
+                active.set(Some(click_item.clone()));
+            }
+            if let Some(on_row_click) = on_row_click {
+                on_row_click.run((click_item.clone(), e));
+            }
+        };
+        let on_dblclick = move |e| {
+            if let Some(on_row_dblclick) = on_row_dblclick {
+                on_row_dblclick.run((item.clone(), e));
+            }
+        };
+        let row_index = {
+            let key = key.clone();
+            move || {
+                row_positions
+                    .with(|p| p.get(&key).map(|pos| (pos + 2).to_string()))
+            }
+        };
+        let aria_selected = {
+            let key = key.clone();
+            move || {
+                selection.map(|_| {
+                    selected_keys.with(|k| k.is_selected(&key)).to_string()
+                })
+            }
+        };
+        // only a single row is reachable with tab, the focused or the first one
+        let tab_index = {
+            let key = key.clone();
+            move || {
+                let reachable = row_positions.with(|p| {
+                    match focused_row.get().filter(|f| p.contains_key(f)) {
+                        Some(focused) => focused == key,
+                        None => p.values().min() == p.get(&key),
+                    }
+                });
+                if reachable {
+                    "0"
+                } else {
+                    "-1"
+                }
+            }
+        };
+        view! {
+            <tr
+                class:active=active
+                aria-rowindex=row_index
+                aria-selected=aria_selected
+                tabindex=tab_index
+                on:focus=move |_| focused_row.set(Some(key.clone()))
+                on:click=on_click
+                on:dblclick=on_dblclick>
+                { select_cell }
+                { td_list }
+            </tr>
+        }
+        .into_any()
+    };
+    let collapsed_groups =
+        collapsed_groups.unwrap_or_else(|| RwSignal::new(HashSet::new()));
+    let render_group = move |label: String, count: usize| {
+        let is_collapsed = {
+            let label = label.clone();
+            move || collapsed_groups.with(|c| c.contains(&label))
+        };
+        let icon = move || {
+            if is_collapsed() {
+                "caret right icon"
+            } else {
+                "caret down icon"
+            }
+        };
+        let toggle_label = label.clone();
+        let on_click = move |_| {
+            collapsed_groups.update(|c| {
+                if !c.remove(&toggle_label) {
+                    c.insert(toggle_label.clone());
+                }
+            })
+        };
+        view! {
+            <tr class="group" style="cursor: pointer;" on:click=on_click>
+                <td colspan=colspan>
+                    <i class=icon></i>
+                    <strong>{ label }</strong>
+                    " (" { count } ")"
+                </td>
+            </tr>
+        }
+        .into_any()
+    };
+    let lines = move || {
+        let rows = rows();
+        match &group_by {
+            Some(group_by) => collapsed_groups
+                .with(|collapsed| group_rows(rows, group_by, collapsed)),
+            None => rows.into_iter().map(TableLine::Row).collect::<Vec<_>>(),
+        }
+    };
+
+    let table = view! {
         <table
             node_ref=ref_table
-            class="ui sortable basic table">
+            id=id.clone()
+            class=table_class
+            role="grid"
+            aria-label=aria.label
+            aria-labelledby=aria.labelled_by
+            aria-describedby=aria.described_by
+            aria-rowcount=move || (filtered.with(Vec::len) + 1).to_string()>
             <thead>
+                { select_all_heading }
                 { heading_items }
             </thead>
-            <tbody>
+            <tbody on:keydown=on_keydown>
             <For
-                each=move || data.get()
-                key=move |item: &R| {
-                    let mut hasher = DefaultHasher::new();
-                    item.hash(&mut hasher);
-                    hasher.finish()
-                }
-                children=move |item: R| {
-                    let td_list = columns
-                        .iter()
-                        .map(|c| view! {
-                            <td>
-                            { c(&item) }
-                            </td>
-                        })
-                        .collect::<Vec<_>>();
-                    view! {
-                        <TableRow>
-                            { td_list }
-                        </TableRow>
+                each=lines
+                key=line_key
+                children=move |line| match line {
+                    TableLine::Group { label, count } => {
+                        render_group(label, count)
                     }
+                    TableLine::Row(item) => render_row(item),
                 }
             />
+            { empty_row }
             </tbody>
+            { footer }
         </table>
+    };
+    let table = if sticky_header || sticky_first_column {
+        let mut style = String::new();
+        if sticky_header {
+            style.push_str(&format!(
+                "max-height: {}; overflow-y: auto;",
+                max_height.unwrap_or_else(|| "30em".to_string())
+            ));
+        }
+        if sticky_first_column {
+            style.push_str(" overflow-x: auto;");
+        }
+        // the leading cells need an opaque background, the headings have one
+        // already and have to stay above the cells when both are sticky
+        let first_column_style = sticky_first_column.then(|| {
+            format!(
+                "#{id} tbody, #{id} tfoot, #{id} tr {{ background-color: \
+                 inherit; }} #{id} td:first-child {{ position: sticky; left: \
+                 0; z-index: 1; background-color: inherit; }} #{id} \
+                 th:first-child {{ position: sticky; left: 0; z-index: 3; }}"
+            )
+        });
+        view! {
+            <div style=style.trim().to_string()>
+                { first_column_style.map(|css| view! { <style>{ css }</style> }) }
+                { table }
+            </div>
+        }
+        .into_any()
+    } else {
+        table.into_any()
+    };
+    let table = if loading.is_some() {
+        view! {
+            <div class="ui basic fitted segment" class:loading=is_loading>
+                { table }
+            </div>
+        }
+        .into_any()
+    } else {
+        table.into_any()
+    };
+
+    let (table, cards) = if mobile_cards {
+        let mobile = use_media_query(MOBILE_BREAKPOINT);
+        let card_key = move |item: &R| card_key.key(item);
+        let cards = view! {
+            <div
+                class="ui one cards"
+                style:display=move || if mobile.get() { "" } else { "none" }>
+                <For
+                    each=rows
+                    key=card_key
+                    children=move |item: R| {
+                        let fields = (0..columns.with_value(Vec::len))
+                            .map(|idx| {
+                                let item = item.clone();
+                                move || {
+                                    column_visible(idx).then(|| {
+                                        columns.with_value(|c| view! {
+                                            <div class="item">
+                                                <div class="header">
+                                                    { (c[idx].header)() }
+                                                </div>
+                                                { (c[idx].cell)(&item) }
+                                            </div>
+                                        })
+                                    })
+                                }
+                            })
+                            .collect_view();
+                        view! {
+                            <div class="card">
+                                <div class="content">
+                                    <div class="ui list">{ fields }</div>
+                                </div>
+                            </div>
+                        }
+                    }
+                />
+            </div>
+        };
+        let table = view! {
+            <div style:display=move || if mobile.get() { "none" } else { "" }>
+                { table }
+            </div>
+        };
+        (table.into_any(), Some(cards))
+    } else {
+        (table, None)
+    };
+
+    view! {
+        { search }
+        { chooser }
 
-        { init_table }
+        { table }
+        { cards }
     }
 }
@@ -0,0 +1,58 @@
+/// A summary of a column, computed over the rows shown on the current page
+/// and rendered in the footer of the [Table](super::Table).
+pub struct Aggregation<R>(AggregationKind<R>);
+
+enum AggregationKind<R> {
+    Sum(Box<dyn Fn(&R) -> f64 + Send + Sync>),
+    Avg(Box<dyn Fn(&R) -> f64 + Send + Sync>),
+    Count,
+    Custom(Box<dyn Fn(&[R]) -> String + Send + Sync>),
+}
+
+impl<R> Aggregation<R> {
+    /// The sum of the given values of the rows.
+    pub fn sum<F>(value: F) -> Self
+    where
+        F: Fn(&R) -> f64 + Send + Sync + 'static,
+    {
+        Self(AggregationKind::Sum(Box::new(value)))
+    }
+
+    /// The average of the given values of the rows.
+    pub fn avg<F>(value: F) -> Self
+    where
+        F: Fn(&R) -> f64 + Send + Sync + 'static,
+    {
+        Self(AggregationKind::Avg(Box::new(value)))
+    }
+
+    /// The amount of rows.
+    pub fn count() -> Self {
+        Self(AggregationKind::Count)
+    }
+
+    /// A custom summary of the rows.
+    pub fn custom<F>(summary: F) -> Self
+    where
+        F: Fn(&[R]) -> String + Send + Sync + 'static,
+    {
+        Self(AggregationKind::Custom(Box::new(summary)))
+    }
+
+    /// Computes the summary of the given rows.
+    pub(crate) fn compute(&self, rows: &[R]) -> String {
+        match &self.0 {
+            AggregationKind::Sum(value) => {
+                rows.iter().map(value).sum::<f64>().to_string()
+            }
+            // the average of no rows is undefined
+            AggregationKind::Avg(_) if rows.is_empty() => String::new(),
+            AggregationKind::Avg(value) => {
+                (rows.iter().map(value).sum::<f64>() / rows.len() as f64)
+                    .to_string()
+            }
+            AggregationKind::Count => rows.len().to_string(),
+            AggregationKind::Custom(summary) => summary(rows),
+        }
+    }
+}
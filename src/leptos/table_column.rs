@@ -0,0 +1,274 @@
+use super::{
+    table_resize::ResizeRange,
+    Aggregation,
+    CellEditor,
+    TableSortingAlgorithm,
+};
+use crate::{
+    models::{
+        Filterable,
+        RangeModel,
+        RangeValue,
+    },
+    style::{
+        ColumnWidth,
+        TextAlign,
+    },
+};
+use leptos::prelude::*;
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+};
+
+/// Definition of a column of a [Table](super::Table).
+///
+/// `R` defines the row item type.
+pub struct TableColumn<R> {
+    pub(crate) id: Option<String>,
+    pub(crate) hideable: bool,
+    pub(crate) header: Box<dyn Fn() -> AnyView + Send + Sync>,
+    pub(crate) cell: Box<dyn Fn(&R) -> AnyView + Send + Sync>,
+    pub(crate) footer: Option<Box<dyn Fn(&[R]) -> AnyView + Send + Sync>>,
+    pub(crate) aggregation: Option<Aggregation<R>>,
+    pub(crate) sortable: bool,
+    pub(crate) comparator:
+        Option<Box<dyn Fn(&R, &R) -> Ordering + Send + Sync>>,
+    pub(crate) filter: Option<Box<dyn Fn(&R, &str) -> bool + Send + Sync>>,
+    pub(crate) range_filter: Option<Box<dyn Fn(&R) -> bool + Send + Sync>>,
+    pub(crate) text: Option<Box<dyn Fn(&R) -> String + Send + Sync>>,
+    pub(crate) editor: Option<CellEditor<R>>,
+    pub(crate) sorting_algorithm: TableSortingAlgorithm,
+    pub(crate) width: Option<ColumnWidth>,
+    pub(crate) align: Option<TextAlign>,
+    pub(crate) colspan: usize,
+    pub(crate) resize: Option<ResizeRange>,
+}
+
+impl<R> TableColumn<R> {
+    /// Creates a new column with the given heading and cell renderer.
+    pub fn new<H, HV, C, CV>(header: H, cell: C) -> Self
+    where
+        H: Fn() -> HV + Send + Sync + 'static,
+        HV: IntoView + 'static,
+        C: Fn(&R) -> CV + Send + Sync + 'static,
+        CV: IntoView + 'static,
+    {
+        Self {
+            id: None,
+            hideable: false,
+            header: Box::new(move || header().into_any()),
+            cell: Box::new(move |row: &R| cell(row).into_any()),
+            footer: None,
+            aggregation: None,
+            sortable: true,
+            comparator: None,
+            filter: None,
+            range_filter: None,
+            text: None,
+            editor: None,
+            sorting_algorithm: TableSortingAlgorithm::Default,
+            width: None,
+            align: None,
+            colspan: 1,
+            resize: None,
+        }
+    }
+
+    /// Sets the id of the column, used to refer to it when hiding it.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Whether the column can be hidden. Requires an [id](Self::id).
+    /// Defaults to `false`.
+    pub fn hideable(mut self, hideable: bool) -> Self {
+        self.hideable = hideable;
+        self
+    }
+
+    /// Sets the footer cell renderer, eg. for totals.
+    ///
+    /// The renderer receives all rows matching the current filter.
+    pub fn footer<F, FV>(mut self, footer: F) -> Self
+    where
+        F: Fn(&[R]) -> FV + Send + Sync + 'static,
+        FV: IntoView + 'static,
+    {
+        self.footer = Some(Box::new(move |rows: &[R]| footer(rows).into_any()));
+        self
+    }
+
+    /// Shows the given summary of the rows on the current page in the footer.
+    /// Ignored if a [footer](Self::footer) renderer is set.
+    pub fn aggregate(mut self, aggregation: Aggregation<R>) -> Self {
+        self.aggregation = Some(aggregation);
+        self
+    }
+
+    /// Whether the column can be sorted by clicking its heading. Defaults to `true`.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets the comparator used to sort the column.
+    pub fn comparator<F>(mut self, comparator: F) -> Self
+    where
+        F: Fn(&R, &R) -> Ordering + Send + Sync + 'static,
+    {
+        self.comparator = Some(Box::new(comparator));
+        self
+    }
+
+    /// Sets the predicate deciding whether a row matches the filter text.
+    ///
+    /// A row is shown when any column with a predicate matches.
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&R, &str) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Box::new(predicate));
+        self
+    }
+
+    /// Matches the rows against the filter text using their [Filterable]
+    /// implementation. Uses [Filterable::matches_field] with the
+    /// [id](Self::id) of the column if it has been set before.
+    pub fn filterable(self) -> Self
+    where
+        R: Filterable,
+    {
+        match self.id.clone() {
+            Some(id) => {
+                self.filter(move |row: &R, query| row.matches_field(&id, query))
+            }
+            None => self.filter(|row: &R, query| row.matches(query)),
+        }
+    }
+
+    /// Only shows the rows whose value is selected in the given range, eg.
+    /// bound to a [Slider](super::Slider), see [RangeModel::contains].
+    ///
+    /// Applies in addition to the filter text.
+    pub fn range_filter<T, F>(
+        mut self,
+        value: F,
+        range: impl Into<Signal<RangeModel<T>>>,
+    ) -> Self
+    where
+        T: RangeValue + Send + Sync + 'static,
+        F: Fn(&R) -> T + Send + Sync + 'static,
+    {
+        let range = range.into();
+        self.range_filter = Some(Box::new(move |row: &R| {
+            range.with(|range| range.contains(value(row)))
+        }));
+        self
+    }
+
+    /// Sets the extractor for the plain text of a cell, used when exporting
+    /// the table. The [id](Self::id) is used as heading of the exported column.
+    pub fn text<F>(mut self, text: F) -> Self
+    where
+        F: Fn(&R) -> String + Send + Sync + 'static,
+    {
+        self.text = Some(Box::new(text));
+        self
+    }
+
+    /// Makes the cells of the column editable with the given editor.
+    pub fn editor(mut self, editor: CellEditor<R>) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    /// Sets the `tablesort` algorithm used to sort the column.
+    pub fn sorting_algorithm(
+        mut self,
+        algorithm: TableSortingAlgorithm,
+    ) -> Self {
+        self.sorting_algorithm = algorithm;
+        self
+    }
+
+    /// Sets the width of the column.
+    pub fn width(mut self, width: ColumnWidth) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the alignment of the column contents.
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Sets the amount of table columns the heading, cells and footer of the
+    /// column span. Defaults to `1`.
+    pub fn colspan(mut self, colspan: usize) -> Self {
+        self.colspan = colspan.max(1);
+        self
+    }
+
+    /// Allows resizing the column by dragging the right border of its
+    /// heading, within the given range of pixels.
+    pub fn resizable(mut self, min_width: f64, max_width: Option<f64>) -> Self {
+        self.resize = Some(ResizeRange {
+            min: min_width,
+            max: max_width,
+        });
+        self
+    }
+
+    /// The `colspan` attribute of the cells, `None` if spanning a single
+    /// column.
+    pub(crate) fn colspan_attribute(&self) -> Option<String> {
+        (self.colspan > 1).then(|| self.colspan.to_string())
+    }
+
+    /// Whether the column is shown, given the ids of the hidden columns.
+    pub(crate) fn is_visible(&self, hidden: &HashSet<String>) -> bool {
+        !self.hideable || self.id.as_ref().is_none_or(|id| !hidden.contains(id))
+    }
+
+    /// Whether the column can be sorted in the given mode.
+    pub(crate) fn is_sortable(&self, rust_sorting: bool) -> bool {
+        self.sortable && (!rust_sorting || self.comparator.is_some())
+    }
+
+    /// The class names of the heading cell.
+    pub(crate) fn heading_class(&self, rust_sorting: bool) -> String {
+        let mut classes = vec![];
+        if !self.is_sortable(rust_sorting) {
+            classes.push(if rust_sorting {
+                "disabled".to_string()
+            } else {
+                "no-sort".to_string()
+            });
+        } else if !rust_sorting {
+            classes.push(self.sorting_algorithm.to_string());
+        }
+        classes.push(self.layout_class());
+        classes.retain(|c| !c.is_empty());
+        classes.join(" ")
+    }
+
+    /// The class names defining width and alignment of the column.
+    pub(crate) fn layout_class(&self) -> String {
+        [
+            self.width.map(|w| w.to_string()),
+            self.align.map(|a| a.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// The class names of the body cells.
+    pub(crate) fn cell_class(&self) -> String {
+        self.align.map(|a| a.to_string()).unwrap_or_default()
+    }
+}
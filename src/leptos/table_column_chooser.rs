@@ -0,0 +1,87 @@
+use super::{
+    lazy::init_module,
+    use_fomantic_config,
+    TableColumn,
+};
+use crate::{
+    jq::JQuery,
+    modules::InstanceGuard,
+};
+use leptos::{
+    html,
+    prelude::*,
+};
+use std::collections::HashSet;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = Object)]
+    type JsColumnChooserConfig;
+    /// Configuration constructor.
+    #[wasm_bindgen(constructor, js_class = Object)]
+    fn new() -> JsColumnChooserConfig;
+    /// Sets the action that is performed when an item is clicked.
+    #[wasm_bindgen(method, setter, js_name = "action")]
+    fn set_action(this: &JsColumnChooserConfig, action: &str);
+}
+
+/// A dropdown for showing and hiding the hideable columns of a
+/// [Table](super::Table).
+#[component]
+pub(crate) fn TableColumnChooser<R: 'static>(
+    columns: StoredValue<Vec<TableColumn<R>>>,
+    hidden_columns: RwSignal<HashSet<String>>,
+) -> impl IntoView {
+    let items = columns.with_value(|c| {
+        c.iter()
+            .filter(|column| column.hideable)
+            .filter_map(|column| {
+                let id = column.id.clone()?;
+                let toggle_id = id.clone();
+                let on_click = move |_| {
+                    hidden_columns.update(|hidden| {
+                        if !hidden.remove(&toggle_id) {
+                            hidden.insert(toggle_id.clone());
+                        }
+                    })
+                };
+                let icon = move || {
+                    if hidden_columns.with(|hidden| hidden.contains(&id)) {
+                        "square outline icon"
+                    } else {
+                        "check square outline icon"
+                    }
+                };
+                Some(view! {
+                    <div class="item" on:click=on_click>
+                        <i class=icon></i>
+                        { (column.header)() }
+                    </div>
+                })
+            })
+            .collect_view()
+    });
+
+    let ref_dropdown = NodeRef::<html::Div>::new();
+    init_module(ref_dropdown, |dropdown| {
+        let config = JsColumnChooserConfig::new();
+        // keep the menu open while toggling multiple columns
+        config.set_action("nothing");
+        let dropdown = JQuery::from_element(dropdown);
+        dropdown.init("dropdown", &config);
+        Some(InstanceGuard::new(dropdown, "dropdown"))
+    });
+
+    view! {
+        <div
+            node_ref=ref_dropdown
+            class="ui floating labeled icon dropdown button">
+            <i class="columns icon"></i>
+            <span class="text">{ use_fomantic_config().texts.columns }</span>
+            <div class="menu">
+                { items }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    models::Editable,
+    Key,
+};
+use leptos::{
+    html,
+    prelude::*,
+};
+use std::sync::Arc;
+use tracing::warn;
+use wasm_bindgen::JsCast;
+
+/// The input used to edit the cells of a [TableColumn](super::TableColumn).
+///
+/// Text and select editors are opened by double clicking a cell. `Enter`
+/// commits the new value, `Escape` or leaving the editor discards it.
+/// Checkbox editors are always shown and commit on every change.
+pub struct CellEditor<R>(EditorKind<R>);
+
+enum EditorKind<R> {
+    Text {
+        value: Arc<dyn Fn(&R) -> String + Send + Sync>,
+        commit: Arc<dyn Fn(&R, String) + Send + Sync>,
+    },
+    Select {
+        options: Arc<Vec<(String, String)>>,
+        value: Arc<dyn Fn(&R) -> String + Send + Sync>,
+        commit: Arc<dyn Fn(&R, String) + Send + Sync>,
+    },
+    Checkbox {
+        value: Arc<dyn Fn(&R) -> bool + Send + Sync>,
+        commit: Arc<dyn Fn(&R, bool) + Send + Sync>,
+    },
+}
+
+impl<R> Clone for CellEditor<R> {
+    fn clone(&self) -> Self {
+        Self(match &self.0 {
+            EditorKind::Text { value, commit } => EditorKind::Text {
+                value: Arc::clone(value),
+                commit: Arc::clone(commit),
+            },
+            EditorKind::Select {
+                options,
+                value,
+                commit,
+            } => EditorKind::Select {
+                options: Arc::clone(options),
+                value: Arc::clone(value),
+                commit: Arc::clone(commit),
+            },
+            EditorKind::Checkbox { value, commit } => EditorKind::Checkbox {
+                value: Arc::clone(value),
+                commit: Arc::clone(commit),
+            },
+        })
+    }
+}
+
+impl<R: Clone + Send + Sync + 'static> CellEditor<R> {
+    /// A text input, initialized with `value` of the row.
+    pub fn text<V, C>(value: V, commit: C) -> Self
+    where
+        V: Fn(&R) -> String + Send + Sync + 'static,
+        C: Fn(&R, String) + Send + Sync + 'static,
+    {
+        Self(EditorKind::Text {
+            value: Arc::new(value),
+            commit: Arc::new(commit),
+        })
+    }
+
+    /// A select input with the given `(value, label)` options, initialized
+    /// with `value` of the row. Commits as soon as an option is chosen.
+    pub fn select<V, C>(
+        options: Vec<(String, String)>,
+        value: V,
+        commit: C,
+    ) -> Self
+    where
+        V: Fn(&R) -> String + Send + Sync + 'static,
+        C: Fn(&R, String) + Send + Sync + 'static,
+    {
+        Self(EditorKind::Select {
+            options: Arc::new(options),
+            value: Arc::new(value),
+            commit: Arc::new(commit),
+        })
+    }
+
+    /// A checkbox, checked if `value` of the row is `true`.
+    pub fn checkbox<V, C>(value: V, commit: C) -> Self
+    where
+        V: Fn(&R) -> bool + Send + Sync + 'static,
+        C: Fn(&R, bool) + Send + Sync + 'static,
+    {
+        Self(EditorKind::Checkbox {
+            value: Arc::new(value),
+            commit: Arc::new(commit),
+        })
+    }
+
+    /// A text input editing the given field of the row through its
+    /// [Editable] implementation.
+    ///
+    /// `update` is called with the edited copy of the row. Edits of fields
+    /// that are not editable, or with invalid values, are discarded.
+    pub fn field<U>(field: &'static str, update: U) -> Self
+    where
+        R: Editable,
+        U: Fn(R) + Send + Sync + 'static,
+    {
+        Self::text(
+            move |row: &R| row.field_value(field).unwrap_or_default(),
+            move |row: &R, value| {
+                if !row.is_editable(field) {
+                    return;
+                }
+                let mut row = row.clone();
+                match row.apply_edit(field, &value) {
+                    Ok(()) => update(row),
+                    Err(e) => warn!("Discarding edit of field {field}: {e}"),
+                }
+            },
+        )
+    }
+
+    /// Whether the editor is shown instead of the cell content at all times.
+    pub(crate) fn is_inline(&self) -> bool {
+        matches!(self.0, EditorKind::Checkbox { .. })
+    }
+
+    /// Renders the editor for the given row. `editing` is reset when the
+    /// editor is closed.
+    pub(crate) fn render(&self, row: R, editing: RwSignal<bool>) -> AnyView {
+        let on_keydown = move |e: web_sys::KeyboardEvent| {
+            if Key::Escape.matches(&e.key()) {
+                editing.set(false);
+            }
+        };
+        let on_blur = move |_| editing.set(false);
+
+        match &self.0 {
+            EditorKind::Text { value, commit } => {
+                let commit = Arc::clone(commit);
+                let initial = value(&row);
+                let ref_input = NodeRef::<html::Input>::new();
+                focus_on_mount(ref_input);
+                view! {
+                    <div class="ui fluid input">
+                        <input
+                            node_ref=ref_input
+                            type="text"
+                            value=initial
+                            on:blur=on_blur
+                            on:keydown=move |e| {
+                                if Key::Enter.matches(&e.key()) {
+                                    commit(&row, event_target_value(&e));
+                                    editing.set(false);
+                                } else {
+                                    on_keydown(e);
+                                }
+                            }/>
+                    </div>
+                }
+                .into_any()
+            }
+            EditorKind::Select {
+                options,
+                value,
+                commit,
+            } => {
+                let commit = Arc::clone(commit);
+                let initial = value(&row);
+                let options = options
+                    .iter()
+                    .map(|(value, label)| {
+                        view! {
+                            <option
+                                value=value.clone()
+                                selected=*value == initial>
+                                { label.clone() }
+                            </option>
+                        }
+                    })
+                    .collect_view();
+                let ref_select = NodeRef::<html::Select>::new();
+                focus_on_mount(ref_select);
+                view! {
+                    <select
+                        node_ref=ref_select
+                        class="ui fluid dropdown"
+                        on:blur=on_blur
+                        on:keydown=on_keydown
+                        on:change=move |e| {
+                            commit(&row, event_target_value(&e));
+                            editing.set(false);
+                        }>
+                        { options }
+                    </select>
+                }
+                .into_any()
+            }
+            EditorKind::Checkbox { value, commit } => {
+                let commit = Arc::clone(commit);
+                let checked = value(&row);
+                view! {
+                    <div class="ui fitted checkbox">
+                        <input
+                            type="checkbox"
+                            prop:checked=checked
+                            on:change=move |e| {
+                                commit(&row, event_target_checked(&e))
+                            }/>
+                        <label></label>
+                    </div>
+                }
+                .into_any()
+            }
+        }
+    }
+}
+
+/// Focuses the given element as soon as it is mounted.
+fn focus_on_mount<E>(node_ref: NodeRef<E>)
+where
+    E: html::ElementType,
+    E::Output: JsCast + Clone + AsRef<web_sys::HtmlElement> + 'static,
+{
+    Effect::new(move |_| {
+        if let Some(element) = node_ref.get() {
+            let _ = AsRef::<web_sys::HtmlElement>::as_ref(&element).focus();
+        }
+    });
+}
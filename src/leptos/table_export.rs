@@ -0,0 +1,186 @@
+use leptos::prelude::*;
+use wasm_bindgen::{
+    JsCast,
+    JsValue,
+};
+
+/// Text formats a [Table](super::Table) can be exported to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ExportFormat {
+    /// Comma separated values.
+    #[default]
+    Csv,
+    /// Tab separated values.
+    Tsv,
+}
+
+impl ExportFormat {
+    /// The MIME type of the format.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Tsv => "text/tab-separated-values",
+        }
+    }
+
+    /// Serializes the given records.
+    pub(crate) fn serialize(&self, records: &[Vec<String>]) -> String {
+        let delimiter = match self {
+            Self::Csv => ",",
+            Self::Tsv => "\t",
+        };
+        records
+            .iter()
+            .map(|record| {
+                record
+                    .iter()
+                    .map(|field| self.escape(field))
+                    .collect::<Vec<_>>()
+                    .join(delimiter)
+            })
+            .map(|line| line + "\r\n")
+            .collect()
+    }
+
+    /// Escapes a single field.
+    fn escape(&self, field: &str) -> String {
+        match self {
+            Self::Csv if field.contains([',', '"', '\r', '\n']) => {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            }
+            Self::Csv => field.to_string(),
+            // TSV has no quoting, so delimiters are replaced instead
+            Self::Tsv => field.replace(['\t', '\r', '\n'], " "),
+        }
+    }
+}
+
+/// Handle for exporting the rows of a [Table](super::Table).
+///
+/// Pass it to the `export` prop of the table, afterwards the current rows
+/// can be exported, eg. from a button click handler. Only columns with a
+/// [text](super::TableColumn::text) extractor are exported.
+#[derive(Clone, Copy)]
+pub struct TableExport {
+    records:
+        StoredValue<Option<Box<dyn Fn() -> Vec<Vec<String>> + Send + Sync>>>,
+}
+
+impl TableExport {
+    /// Creates a new handle that is not attached to a table yet.
+    pub fn new() -> Self {
+        Self {
+            records: StoredValue::new(None),
+        }
+    }
+
+    /// Attaches the handle to the records of a table.
+    pub(crate) fn attach<F>(&self, records: F)
+    where
+        F: Fn() -> Vec<Vec<String>> + Send + Sync + 'static,
+    {
+        self.records.set_value(Some(Box::new(records)));
+    }
+
+    /// Serializes the filtered rows of the table, including a heading line.
+    /// Returns `None` if the handle is not attached to a table.
+    pub fn text(&self, format: ExportFormat) -> Option<String> {
+        self.records.with_value(|records| {
+            records.as_ref().map(|records| format.serialize(&records()))
+        })
+    }
+
+    /// Serializes the rows of the table and triggers a browser download
+    /// with the given filename.
+    pub fn download(
+        &self,
+        format: ExportFormat,
+        filename: &str,
+    ) -> anyhow::Result<()> {
+        let Some(text) = self.text(format) else {
+            anyhow::bail!("The export is not attached to a table.");
+        };
+        download(&text, format.mime_type(), filename).map_err(|e| {
+            anyhow::anyhow!("Could not download the table export: {e:?}")
+        })
+    }
+}
+
+impl Default for TableExport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Downloads the given text as file by clicking a temporary link.
+fn download(
+    text: &str,
+    mime_type: &str,
+    filename: &str,
+) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(text));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob =
+        web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let link = document()
+        .create_element("a")?
+        .dyn_into::<web_sys::HtmlElement>()?;
+    link.set_attribute("href", &url)?;
+    link.set_attribute("download", filename)?;
+    link.click();
+
+    web_sys::Url::revoke_object_url(&url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExportFormat;
+
+    fn records() -> Vec<Vec<String>> {
+        [
+            ["Name", "Note"],
+            ["Smith, John", "says \"hi\""],
+            ["Tab\there", "two\r\nlines"],
+            ["plain", ""],
+        ]
+        .iter()
+        .map(|record| record.iter().map(|f| f.to_string()).collect())
+        .collect()
+    }
+
+    #[test]
+    fn quotes_csv_fields_with_special_characters() {
+        assert_eq!(ExportFormat::Csv.escape("plain"), "plain");
+        assert_eq!(ExportFormat::Csv.escape("a,b"), "\"a,b\"");
+        assert_eq!(ExportFormat::Csv.escape("a \"b\""), "\"a \"\"b\"\"\"");
+        assert_eq!(ExportFormat::Csv.escape("a\nb"), "\"a\nb\"");
+        assert_eq!(ExportFormat::Csv.escape("a\rb"), "\"a\rb\"");
+        // tabs need no quoting in csv
+        assert_eq!(ExportFormat::Csv.escape("a\tb"), "a\tb");
+    }
+
+    #[test]
+    fn replaces_tsv_delimiters() {
+        assert_eq!(ExportFormat::Tsv.escape("a\tb"), "a b");
+        assert_eq!(ExportFormat::Tsv.escape("a\r\nb"), "a  b");
+        assert_eq!(ExportFormat::Tsv.escape("a, \"b\""), "a, \"b\"");
+    }
+
+    #[test]
+    fn serializes_records_as_lines() {
+        assert_eq!(
+            ExportFormat::Csv.serialize(&records()),
+            "Name,Note\r\n\"Smith, John\",\"says \
+             \"\"hi\"\"\"\r\nTab\there,\"two\r\nlines\"\r\nplain,\r\n"
+        );
+        assert_eq!(
+            ExportFormat::Tsv.serialize(&records()),
+            "Name\tNote\r\nSmith, John\tsays \"hi\"\r\nTab here\ttwo  \
+             lines\r\nplain\t\r\n"
+        );
+        assert_eq!(ExportFormat::Csv.serialize(&[]), "");
+    }
+}
@@ -0,0 +1,149 @@
+use super::{
+    RowKey,
+    TableKey,
+};
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    sync::Arc,
+};
+
+/// Assigns the rows of a table to groups, eg. by date or category.
+///
+/// Created from any closure `Fn(&R) -> G` where `G` is displayable, the
+/// displayed value is used as label of the group.
+pub struct TableGroupBy<R>(Arc<dyn Fn(&R) -> String + Send + Sync>);
+
+impl<R> TableGroupBy<R> {
+    /// The label of the group the given row belongs to.
+    pub(crate) fn group(&self, row: &R) -> String {
+        (self.0)(row)
+    }
+}
+
+impl<R> Clone for TableGroupBy<R> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<R, G, F> From<F> for TableGroupBy<R>
+where
+    F: Fn(&R) -> G + Send + Sync + 'static,
+    G: Display,
+{
+    fn from(group_by: F) -> Self {
+        Self(Arc::new(move |row: &R| group_by(row).to_string()))
+    }
+}
+
+/// A line in the body of a table.
+pub(crate) enum TableLine<R> {
+    /// The header of a group.
+    Group {
+        /// The label of the group.
+        label: String,
+        /// Amount of rows in the group.
+        count: usize,
+    },
+    /// A row of data.
+    Row(R),
+}
+
+impl<R> TableLine<R> {
+    /// Identifies the line, see [TableKey].
+    pub(crate) fn key(&self, row_key: &TableKey<R>) -> RowKey {
+        match self {
+            Self::Group { label, count } => RowKey::new(GroupKey {
+                label: label.clone(),
+                count: *count,
+            }),
+            Self::Row(row) => row_key.key(row),
+        }
+    }
+}
+
+/// The key of a group header, its own type never equals the key of a row.
+#[derive(PartialEq, Eq, Hash)]
+struct GroupKey {
+    label: String,
+    count: usize,
+}
+
+/// Groups the rows in order of the first row of each group. Rows of collapsed
+/// groups are left out, their headers are kept.
+pub(crate) fn group_rows<R>(
+    rows: Vec<R>,
+    group_by: &TableGroupBy<R>,
+    collapsed: &HashSet<String>,
+) -> Vec<TableLine<R>> {
+    let mut groups: Vec<(String, Vec<R>)> = vec![];
+    for row in rows {
+        let label = group_by.group(&row);
+        match groups.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, rows)) => rows.push(row),
+            None => groups.push((label, vec![row])),
+        }
+    }
+    groups
+        .into_iter()
+        .flat_map(|(label, rows)| {
+            let header = TableLine::Group {
+                count: rows.len(),
+                label: label.clone(),
+            };
+            let rows = if collapsed.contains(&label) {
+                vec![]
+            } else {
+                rows
+            };
+            std::iter::once(header).chain(rows.into_iter().map(TableLine::Row))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        group_rows,
+        TableGroupBy,
+        TableLine,
+    };
+    use std::collections::HashSet;
+
+    /// Describes the lines, `#label count` for group headers.
+    fn describe(lines: &[TableLine<(&str, u32)>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| match line {
+                TableLine::Group { label, count } => {
+                    format!("#{label} {count}")
+                }
+                TableLine::Row((name, _)) => name.to_string(),
+            })
+            .collect()
+    }
+
+    fn rows() -> Vec<(&'static str, u32)> {
+        vec![("a", 2), ("b", 1), ("c", 2), ("d", 3), ("e", 1)]
+    }
+
+    #[test]
+    fn groups_rows_in_order_of_their_first_row() {
+        let group_by = TableGroupBy::from(|row: &(&str, u32)| row.1);
+        let lines = group_rows(rows(), &group_by, &HashSet::new());
+        assert_eq!(
+            describe(&lines),
+            ["#2 2", "a", "c", "#1 2", "b", "e", "#3 1", "d"]
+        );
+        assert!(group_rows(vec![], &group_by, &HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn keeps_the_headers_of_collapsed_groups() {
+        let group_by = TableGroupBy::from(|row: &(&str, u32)| row.1);
+        let collapsed = HashSet::from(["2".to_string(), "3".to_string()]);
+        let lines = group_rows(rows(), &group_by, &collapsed);
+        assert_eq!(describe(&lines), ["#2 2", "#1 2", "b", "e", "#3 1"]);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::models::Identifiable;
+use std::{
+    any::Any,
+    hash::{
+        Hash,
+        Hasher,
+    },
+    sync::Arc,
+};
+
+/// Identifies the rows of a table, eg. by their id.
+///
+/// Rows with the same key are only rendered once, and keep their DOM nodes
+/// when the data changes. Created from any closure `Fn(&R) -> K` where `K`
+/// is comparable and hashable, or with [identifiable](Self::identifiable)
+/// from the [Identifiable] implementation of the rows.
+pub struct TableKey<R>(Arc<dyn Fn(&R) -> RowKey + Send + Sync>);
+
+impl<R> TableKey<R> {
+    /// Identifies the rows by their [Identifiable::id].
+    pub fn identifiable() -> Self
+    where
+        R: Identifiable,
+        R::Id: Eq + Hash + Send + Sync + 'static,
+    {
+        Self::from(|row: &R| row.id())
+    }
+
+    /// Calculates the key of the given row.
+    pub(crate) fn key(&self, row: &R) -> RowKey {
+        (self.0)(row)
+    }
+}
+
+impl<R> Clone for TableKey<R> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<R, K, F> From<F> for TableKey<R>
+where
+    F: Fn(&R) -> K + Send + Sync + 'static,
+    K: Eq + Hash + Send + Sync + 'static,
+{
+    fn from(key: F) -> Self {
+        Self(Arc::new(move |row: &R| RowKey::new(key(row))))
+    }
+}
+
+/// A key of any type, compared by its own [Eq] implementation, so distinct
+/// keys never collide.
+#[derive(Clone)]
+pub(crate) struct RowKey(Arc<dyn KeyValue>);
+
+impl RowKey {
+    /// Wraps the given key.
+    pub(crate) fn new<K>(key: K) -> Self
+    where
+        K: Eq + Hash + Send + Sync + 'static,
+    {
+        Self(Arc::new(key))
+    }
+}
+
+impl PartialEq for RowKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_key(other.0.as_any())
+    }
+}
+
+impl Eq for RowKey {}
+
+impl Hash for RowKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_key(state);
+    }
+}
+
+/// The operations of a [RowKey] on the wrapped key.
+trait KeyValue: Send + Sync {
+    /// Returns the key for downcasting.
+    fn as_any(&self) -> &dyn Any;
+    /// Returns whether the key equals the given one, keys of other types are
+    /// never equal.
+    fn eq_key(&self, other: &dyn Any) -> bool;
+    /// Feeds the key into the given hasher.
+    fn hash_key(&self, state: &mut dyn Hasher);
+}
+
+impl<K> KeyValue for K
+where
+    K: Eq + Hash + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_key(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<K>().is_some_and(|other| self == other)
+    }
+
+    fn hash_key(&self, mut state: &mut dyn Hasher) {
+        self.hash(&mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RowKey,
+        TableKey,
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn compares_the_keys_themselves() {
+        let key = TableKey::from(|row: &(&str, u32)| row.0.to_string());
+        assert!(key.key(&("a", 1)) == key.key(&("a", 2)));
+        assert!(key.key(&("a", 1)) != key.key(&("b", 1)));
+        let keys = ["a", "b", "a", "c"]
+            .iter()
+            .map(|name| key.key(&(name, 0)))
+            .collect::<HashSet<_>>();
+        assert_eq!(keys.len(), 3);
+    }
+
+    #[test]
+    fn never_equals_keys_of_other_types() {
+        assert!(RowKey::new(1_u32) == RowKey::new(1_u32));
+        assert!(RowKey::new(1_u32) != RowKey::new(1_u64));
+        assert!(RowKey::new("1") != RowKey::new("1".to_string()));
+    }
+}
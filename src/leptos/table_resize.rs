@@ -0,0 +1,67 @@
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// The range a column can be resized in, in pixels.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct ResizeRange {
+    pub(crate) min: f64,
+    pub(crate) max: Option<f64>,
+}
+
+impl ResizeRange {
+    /// Limits the given width to the range.
+    fn clamp(&self, width: f64) -> f64 {
+        let width = width.max(self.min);
+        self.max.map_or(width, |max| width.min(max))
+    }
+}
+
+/// A handle on the right border of a heading cell that resizes the column
+/// when dragged.
+#[component]
+pub(crate) fn ResizeHandle(
+    column: usize,
+    range: ResizeRange,
+    widths: RwSignal<HashMap<usize, f64>>,
+) -> impl IntoView {
+    // pointer position and column width when the drag started
+    let drag = StoredValue::new(None::<(f64, f64)>);
+
+    let on_pointerdown = move |e: web_sys::PointerEvent| {
+        let handle = event_target::<web_sys::HtmlElement>(&e);
+        let Some(heading) = handle.parent_element() else {
+            return;
+        };
+        let width = f64::from(heading.client_width());
+        drag.set_value(Some((f64::from(e.client_x()), width)));
+        let _ = handle.set_pointer_capture(e.pointer_id());
+        e.prevent_default();
+    };
+    let on_pointermove = move |e: web_sys::PointerEvent| {
+        if let Some((start_x, start_width)) = drag.get_value() {
+            let width =
+                range.clamp(start_width + f64::from(e.client_x()) - start_x);
+            widths.update(|w| {
+                w.insert(column, width);
+            });
+        }
+    };
+    let on_pointerup = move |e: web_sys::PointerEvent| {
+        drag.set_value(None);
+        let handle = event_target::<web_sys::HtmlElement>(&e);
+        let _ = handle.release_pointer_capture(e.pointer_id());
+    };
+
+    view! {
+        <div
+            class="resize handle"
+            style="position: absolute; top: 0; right: 0; bottom: 0; \
+                   width: 5px; cursor: col-resize; touch-action: none;"
+            on:pointerdown=on_pointerdown
+            on:pointermove=on_pointermove
+            on:pointerup=on_pointerup
+            // do not sort the column when the drag ends
+            on:click=|e| e.stop_propagation()>
+        </div>
+    }
+}
@@ -1,4 +1,4 @@
-use leptos::*;
+use leptos::prelude::*;
 
 /// Defines a row in a `fomantic-ui` table.
 #[component]
@@ -0,0 +1,93 @@
+use super::{
+    RowKey,
+    TableKey,
+};
+use crate::models::{
+    Selectable,
+    SelectionSet,
+    SelectionState,
+};
+use leptos::prelude::*;
+
+/// Connects the checkbox of a single table row to the selected rows.
+pub(crate) struct RowSelection<R: Send + Sync + 'static> {
+    pub(crate) row: R,
+    pub(crate) key: RowKey,
+    pub(crate) selection: RwSignal<Vec<R>>,
+    pub(crate) selected_keys: Memo<SelectionSet<RowKey>>,
+    pub(crate) row_key: TableKey<R>,
+}
+
+impl<R: Clone + Send + Sync> Selectable for RowSelection<R> {
+    fn select(&mut self) {
+        if !self.is_selected() {
+            let row = self.row.clone();
+            self.selection.update(|s| s.push(row));
+        }
+    }
+
+    fn deselect(&mut self) {
+        let (key, row_key) = (&self.key, &self.row_key);
+        self.selection
+            .update(|s| s.retain(|r| row_key.key(r) != *key));
+    }
+
+    fn toggle(&mut self) {
+        if self.is_selected() {
+            self.deselect();
+        } else {
+            self.select();
+        }
+    }
+
+    fn is_selected(&self) -> bool {
+        self.selected_keys.with(|k| k.is_selected(&self.key))
+    }
+}
+
+/// Connects the select-all checkbox in the table header to the selected rows.
+pub(crate) struct AllRowsSelection<R: Send + Sync + 'static> {
+    pub(crate) data: Signal<Vec<R>>,
+    pub(crate) selection: RwSignal<Vec<R>>,
+    pub(crate) selected_keys: Memo<SelectionSet<RowKey>>,
+    pub(crate) row_key: TableKey<R>,
+}
+
+impl<R: Clone + Send + Sync> Selectable for AllRowsSelection<R> {
+    fn select(&mut self) {
+        self.selection.set(self.data.get_untracked());
+    }
+
+    fn deselect(&mut self) {
+        self.selection.set(vec![]);
+    }
+
+    fn toggle(&mut self) {
+        if self.is_selected() {
+            self.deselect();
+        } else {
+            self.select();
+        }
+    }
+
+    fn is_selected(&self) -> bool {
+        self.selection_state() == SelectionState::All
+    }
+
+    fn is_indeterminate(&self) -> bool {
+        self.selection_state() == SelectionState::Some
+    }
+
+    fn selection_state(&self) -> SelectionState {
+        let row_key = &self.row_key;
+        self.selected_keys.with(|keys| {
+            self.data.with(|rows| {
+                let selected = rows
+                    .iter()
+                    .filter(|r| keys.is_selected(&row_key.key(r)))
+                    .count();
+                SelectionState::from_counts(selected, rows.len())
+            })
+        })
+    }
+}
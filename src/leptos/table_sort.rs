@@ -0,0 +1,252 @@
+use crate::{
+    jq::{
+        JQuery,
+        JsJQuery,
+    },
+    models::{
+        SortDirection,
+        SortState,
+    },
+};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    hash::Hash,
+};
+use wasm_bindgen::{
+    prelude::wasm_bindgen,
+    JsCast,
+    JsValue,
+};
+
+#[wasm_bindgen]
+extern "C" {
+    /// A `tablesort` instance.
+    type TableSortInstance;
+    /// Sorts the table by the column of the given heading.
+    #[wasm_bindgen(method)]
+    fn sort(this: &TableSortInstance, heading: &JsJQuery, direction: &str);
+}
+
+/// Determines how a table is sorted when a heading is clicked.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TableSortMode {
+    /// Sorting is done by the jQuery `tablesort` plugin.
+    #[default]
+    TableSort,
+    /// Sorting is done in Rust using the comparators of the columns, no
+    /// additional JavaScript is required.
+    Rust,
+}
+
+impl SortState {
+    /// The class names of a heading sorted by one of the given states.
+    pub(crate) fn heading_class(states: &[Self], column: usize) -> String {
+        match states.iter().find(|s| s.column == column) {
+            Some(s) => format!("sorted {}", s.direction),
+            None => String::new(),
+        }
+    }
+}
+
+/// Compares two rows by the given criteria, ordered by priority.
+fn compare_by<R>(
+    a: &R,
+    b: &R,
+    criteria: &[(SortDirection, &dyn Fn(&R, &R) -> Ordering)],
+) -> Ordering {
+    criteria
+        .iter()
+        .map(|(direction, comparator)| match direction {
+            SortDirection::Ascending => comparator(a, b),
+            SortDirection::Descending => comparator(b, a),
+        })
+        .find(|o| *o != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Sorts the rows by the given criteria, ordered by priority.
+pub(crate) fn sort_rows<R>(
+    rows: &mut [R],
+    criteria: &[(SortDirection, &dyn Fn(&R, &R) -> Ordering)],
+) {
+    rows.sort_by(|a, b| compare_by(a, b, criteria));
+}
+
+/// Ranks the rows by the given criteria, ordered by priority.
+///
+/// Used to sort by multiple columns with `tablesort`, which only supports a
+/// single column. Rows that compare equal share the same rank.
+pub(crate) fn sort_ranks<R, K>(
+    rows: &[R],
+    key: impl Fn(&R) -> K,
+    criteria: &[(SortDirection, &dyn Fn(&R, &R) -> Ordering)],
+) -> HashMap<K, usize>
+where
+    K: Hash + Eq,
+{
+    let mut order = (0..rows.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| compare_by(&rows[*a], &rows[*b], criteria));
+    let mut ranks = HashMap::new();
+    let mut rank = 0;
+    for (pos, idx) in order.iter().enumerate() {
+        if pos > 0
+            && compare_by(&rows[order[pos - 1]], &rows[*idx], criteria)
+                != Ordering::Equal
+        {
+            rank = pos;
+        }
+        ranks.insert(key(&rows[*idx]), rank);
+    }
+    ranks
+}
+
+/// Sorts the table with the given selector by the last of the given states
+/// using `tablesort`.
+///
+/// The column is sorted by the `data-sort-value` of its cells, eg. the
+/// ranks of all sorted columns. `tablesort` reads them through jQuery,
+/// which caches the values of the attributes, so they are refreshed first.
+/// Does nothing if `tablesort` has not been initialized or the heading of
+/// the column is hidden.
+pub(crate) fn apply_tablesort(table_selector: &str, states: &[SortState]) {
+    let Some(state) = states.last() else {
+        return;
+    };
+    let table = JQuery::select(table_selector);
+    let instance = table.data("tablesort");
+    if !instance.is_object() {
+        return;
+    }
+    let heading =
+        table.find(&format!("thead th[data-column=\"{}\"]", state.column));
+    let (Some(element), Some(js_heading)) =
+        (heading.element(0), heading.as_js())
+    else {
+        return;
+    };
+    // tablesort matches the cells by the position of the heading
+    let position =
+        std::iter::successors(Some(element), |e| e.previous_element_sibling())
+            .count();
+    let cells = table.find(&format!("tbody tr > :nth-child({position})"));
+    for idx in 0..cells.length() as i32 {
+        let value = cells
+            .element(idx)
+            .and_then(|cell| cell.get_attribute("data-sort-value"))
+            .and_then(|value| value.parse::<f64>().ok())
+            .map_or(JsValue::NULL, JsValue::from_f64);
+        cells.eq(idx).set_data("sortValue", &value);
+    }
+    let direction = match state.direction {
+        SortDirection::Ascending => "asc",
+        SortDirection::Descending => "desc",
+    };
+    instance
+        .unchecked_into::<TableSortInstance>()
+        .sort(js_heading, direction);
+}
+
+/// Ranks the rows by the comparators of the columns.
+///
+/// The `tablesort` plugin sorts by the `data-sort-value` attribute of a cell
+/// if present, so the rank is used to apply the comparators in JavaScript.
+/// Rows that compare equal share the same rank. The result maps the key of a
+/// row to its rank in every column, `None` for columns without comparator.
+pub(crate) fn column_ranks<R, K>(
+    rows: &[R],
+    key: impl Fn(&R) -> K,
+    comparators: &[Option<&dyn Fn(&R, &R) -> Ordering>],
+) -> HashMap<K, Vec<Option<usize>>>
+where
+    K: Hash + Eq,
+{
+    let mut ranks = rows
+        .iter()
+        .map(|r| (key(r), vec![None; comparators.len()]))
+        .collect::<HashMap<_, _>>();
+    for (column, comparator) in comparators.iter().enumerate() {
+        let Some(comparator) = comparator else {
+            continue;
+        };
+        let mut order = (0..rows.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| comparator(&rows[*a], &rows[*b]));
+        let mut rank = 0;
+        for (pos, idx) in order.iter().enumerate() {
+            if pos > 0
+                && comparator(&rows[order[pos - 1]], &rows[*idx])
+                    != Ordering::Equal
+            {
+                rank = pos;
+            }
+            if let Some(row_ranks) = ranks.get_mut(&key(&rows[*idx])) {
+                row_ranks[column] = Some(rank);
+            }
+        }
+    }
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        column_ranks,
+        sort_ranks,
+    };
+    use crate::models::SortDirection;
+    use std::cmp::Ordering;
+
+    /// Rows of id, name and age.
+    const ROWS: [(u32, &str, u32); 4] =
+        [(1, "Bea", 30), (2, "Al", 25), (3, "Cy", 30), (4, "Al", 40)];
+
+    fn by_name(a: &(u32, &str, u32), b: &(u32, &str, u32)) -> Ordering {
+        a.1.cmp(b.1)
+    }
+
+    fn by_age(a: &(u32, &str, u32), b: &(u32, &str, u32)) -> Ordering {
+        a.2.cmp(&b.2)
+    }
+
+    #[test]
+    fn ranks_rows_by_the_criteria_in_priority() {
+        let ranks = sort_ranks(
+            &ROWS,
+            |row| row.0,
+            &[
+                (SortDirection::Ascending, &by_name),
+                (SortDirection::Descending, &by_age),
+            ],
+        );
+        assert_eq!(ranks[&4], 0);
+        assert_eq!(ranks[&2], 1);
+        assert_eq!(ranks[&1], 2);
+        assert_eq!(ranks[&3], 3);
+    }
+
+    #[test]
+    fn shares_ranks_of_equal_rows() {
+        let ranks = sort_ranks(
+            &ROWS,
+            |row| row.0,
+            &[(SortDirection::Descending, &by_age)],
+        );
+        assert_eq!((ranks[&4], ranks[&1], ranks[&3], ranks[&2]), (0, 1, 1, 3));
+        let ranks = sort_ranks(&ROWS, |row| row.0, &[]);
+        assert!(ranks.values().all(|rank| *rank == 0));
+    }
+
+    #[test]
+    fn ranks_each_column_with_a_comparator() {
+        let ranks = column_ranks(
+            &ROWS,
+            |row| row.0,
+            &[Some(&by_name), None, Some(&by_age)],
+        );
+        assert_eq!(ranks.len(), 4);
+        assert_eq!(ranks[&1], [Some(2), None, Some(1)]);
+        assert_eq!(ranks[&2], [Some(0), None, Some(0)]);
+        assert_eq!(ranks[&3], [Some(3), None, Some(1)]);
+        assert_eq!(ranks[&4], [Some(0), None, Some(3)]);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::jq::JQuery;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+use wasm_bindgen::prelude::*;
+
+/// Name of the builtin function sorting by the float value of a cell.
+pub(crate) const FLOAT_SORT_FUNCTION: &str = "float";
+
+#[wasm_bindgen]
+extern "C" {
+    /// A cell passed to a `tablesort` `sortBy` function.
+    type SortCell;
+    /// The text content of the cell.
+    #[wasm_bindgen(method)]
+    fn text(this: &SortCell) -> String;
+}
+
+/// The `sortBy` function of `tablesort`, called with the heading, the cell
+/// and the `tablesort` instance.
+type SortByClosure = Closure<dyn Fn(JsValue, SortCell) -> JsValue>;
+
+/// Maps the text of a cell to the value it is sorted by.
+type SortValue = Box<dyn Fn(&str) -> JsValue>;
+
+/// A registered sort function.
+///
+/// Tables copy the closure into the data of their headings, so it stays the
+/// same when the function is registered again and dispatches to the current
+/// [SortValue] instead.
+struct SortFunction {
+    sort_value: Rc<RefCell<SortValue>>,
+    closure: SortByClosure,
+}
+
+impl SortFunction {
+    /// Creates the closure dispatching to the given function.
+    fn new<F, V>(sort_value: F) -> Self
+    where
+        F: Fn(&str) -> V + 'static,
+        V: Into<JsValue>,
+    {
+        let sort_value: Rc<RefCell<SortValue>> =
+            Rc::new(RefCell::new(boxed(sort_value)));
+        let closure = Closure::new({
+            let sort_value = Rc::clone(&sort_value);
+            move |_heading: JsValue, cell: SortCell| {
+                (sort_value.borrow())(&cell.text())
+            }
+        });
+        Self {
+            sort_value,
+            closure,
+        }
+    }
+}
+
+/// Boxes the given function, converting its values.
+fn boxed<F, V>(sort_value: F) -> SortValue
+where
+    F: Fn(&str) -> V + 'static,
+    V: Into<JsValue>,
+{
+    Box::new(move |text| sort_value(text).into())
+}
+
+thread_local! {
+    /// The registered sort functions by name.
+    static SORT_FUNCTIONS: RefCell<HashMap<&'static str, SortFunction>> =
+        RefCell::new(HashMap::from([(
+            FLOAT_SORT_FUNCTION,
+            SortFunction::new(|text| {
+                text.trim().parse::<f64>().unwrap_or(f64::MIN)
+            }),
+        )]));
+}
+
+/// Registers a function for sorting [Table](super::Table) columns with
+/// `tablesort`.
+///
+/// The function maps the text of a cell to the value it is sorted by, eg. a
+/// number. Use it with
+/// [TableSortingAlgorithm::Custom](super::TableSortingAlgorithm::Custom) and
+/// the same name. Registering a name again replaces the function, also for
+/// the tables that are shown already.
+pub fn register_sort_function<F, V>(name: &'static str, sort_value: F)
+where
+    F: Fn(&str) -> V + 'static,
+    V: Into<JsValue>,
+{
+    SORT_FUNCTIONS.with(|functions| {
+        let mut functions = functions.borrow_mut();
+        match functions.get(name) {
+            Some(function) => {
+                *function.sort_value.borrow_mut() = boxed(sort_value)
+            }
+            None => {
+                functions.insert(name, SortFunction::new(sort_value));
+            }
+        }
+    });
+}
+
+/// Attaches the registered sort functions to the headings of the table with
+/// the given selector, by their `data-sort-function` attribute.
+pub(crate) fn apply_sort_functions(table_selector: &str) {
+    SORT_FUNCTIONS.with(|functions| {
+        for (name, function) in functions.borrow().iter() {
+            JQuery::select(&format!(
+                "{table_selector} th[data-sort-function=\"{name}\"]"
+            ))
+            .set_data("sortBy", function.closure.as_ref());
+        }
+    });
+}
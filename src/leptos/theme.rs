@@ -0,0 +1,95 @@
+use crate::theme::{
+    self,
+    ThemeMode,
+};
+use leptos::prelude::*;
+use tracing::{
+    error,
+    warn,
+};
+
+/// Handle to read and switch the theme, obtained by [use_theme].
+#[derive(Clone, Copy)]
+pub struct ThemeHandle {
+    mode: RwSignal<ThemeMode>,
+    system_dark: RwSignal<bool>,
+}
+
+impl ThemeHandle {
+    /// The theme chosen by the user.
+    pub fn mode(&self) -> ThemeMode {
+        self.mode.get()
+    }
+
+    /// Switches to the given theme and remembers it.
+    pub fn set_mode(&self, mode: ThemeMode) {
+        if let Err(e) = theme::try_store_theme(mode) {
+            warn!("Could not store the theme: {e}");
+        }
+        self.mode.set(mode);
+    }
+
+    /// Switches between the light and the dark theme.
+    pub fn toggle(&self) {
+        let mode = if self.is_dark() {
+            ThemeMode::Light
+        } else {
+            ThemeMode::Dark
+        };
+        self.set_mode(mode);
+    }
+
+    /// Whether the dark colors are used, following the system for
+    /// [ThemeMode::System].
+    ///
+    /// Always `false` for [ThemeMode::System] while rendering on the server.
+    pub fn is_dark(&self) -> bool {
+        match self.mode.get() {
+            ThemeMode::System => self.system_dark.get(),
+            mode => mode == ThemeMode::Dark,
+        }
+    }
+
+    /// Whether the dark colors are used, as signal, eg. for the `inverted`
+    /// prop of components.
+    pub fn dark(&self) -> Signal<bool> {
+        let handle = *self;
+        Signal::derive(move || handle.is_dark())
+    }
+}
+
+/// Returns the [ThemeHandle] shared by the calling component and its
+/// descendants.
+///
+/// The first call restores the theme remembered in the local storage once
+/// mounted and applies every change of the theme to the page, see
+/// [apply_theme](crate::theme::apply_theme). Call it in the root component,
+/// so all components share the same handle.
+pub fn use_theme() -> ThemeHandle {
+    if let Some(handle) = use_context::<ThemeHandle>() {
+        return handle;
+    }
+    let handle = ThemeHandle {
+        mode: RwSignal::new(ThemeMode::default()),
+        system_dark: RwSignal::new(false),
+    };
+    provide_context(handle);
+    // removes the listener when the owner is disposed
+    let listener = StoredValue::new_local(None);
+    Effect::new(move |_| {
+        if let Some(mode) = theme::stored_theme() {
+            handle.mode.set(mode);
+        }
+        handle.system_dark.set(theme::prefers_dark());
+        listener.set_value(theme::on_system_change(move |dark| {
+            handle.system_dark.set(dark)
+        }));
+    });
+    Effect::new(move |_| {
+        handle.system_dark.track();
+        if let Err(e) = theme::try_apply_theme(handle.mode.get()) {
+            error!("Could not apply the theme: {e}");
+        }
+    });
+    handle
+}
@@ -0,0 +1,203 @@
+use super::use_fomantic_config;
+use crate::{
+    a11y::Aria,
+    modules::toast::{
+        AriaLive,
+        ToastPosition,
+    },
+    style::{
+        Classes,
+        Direction,
+        Mirror,
+    },
+};
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// The content of a toast shown by a [ToastContainer].
+///
+/// Unlike [Toast](crate::modules::toast::Toast), the content is a leptos view
+/// and stays reactive while the toast is visible.
+#[derive(Clone)]
+pub struct ToastContent {
+    title: Option<String>,
+    content: ViewFn,
+    class: Option<String>,
+    /// `None` uses the display time of the
+    /// [FomanticConfig](super::FomanticConfig).
+    display_time: Option<Option<Duration>>,
+}
+
+impl ToastContent {
+    /// Creates a new toast showing the given view.
+    pub fn new(content: impl Into<ViewFn>) -> Self {
+        Self {
+            title: None,
+            content: content.into(),
+            class: None,
+            display_time: None,
+        }
+    }
+
+    /// Sets the title of the toast.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Adds a class to the toast, eg. `success` or `red`.
+    pub fn class(mut self, class: impl Into<Classes>) -> Self {
+        self.class = Some(class.into().to_string());
+        self
+    }
+
+    /// How long the toast is visible in milliseconds. `None` keeps it until
+    /// it is closed. Defaults to the display time of the
+    /// [FomanticConfig](super::FomanticConfig).
+    pub fn display_time(mut self, display_time: Option<u32>) -> Self {
+        self.display_time =
+            Some(display_time.map(|t| Duration::from_millis(t.into())));
+        self
+    }
+}
+
+/// Handle to push toasts into the surrounding [ToastContainer].
+///
+/// Obtained by [use_toast] in any component below the container.
+#[derive(Clone, Copy)]
+pub struct ToastHandle {
+    toasts: RwSignal<Vec<(usize, ToastContent)>>,
+    next_id: StoredValue<usize>,
+    display_time: Option<Duration>,
+}
+
+impl ToastHandle {
+    fn new(display_time: Option<u32>) -> Self {
+        Self {
+            toasts: RwSignal::new(vec![]),
+            next_id: StoredValue::new(0),
+            display_time: display_time.map(|t| Duration::from_millis(t.into())),
+        }
+    }
+
+    /// Shows the given toast. Returns an id that can be used to dismiss it.
+    pub fn show(&self, content: ToastContent) -> usize {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        if let Some(display_time) =
+            content.display_time.unwrap_or(self.display_time)
+        {
+            let toasts = self.toasts;
+            set_timeout(
+                move || {
+                    // the container might be gone already
+                    toasts.try_update(|t| t.retain(|(i, _)| *i != id));
+                },
+                display_time,
+            );
+        }
+        self.toasts.update(|t| t.push((id, content)));
+        id
+    }
+
+    /// Removes the toast with the given id.
+    pub fn dismiss(&self, id: usize) {
+        self.toasts.update(|t| t.retain(|(i, _)| *i != id));
+    }
+
+    /// Removes all toasts.
+    pub fn clear(&self) {
+        self.toasts.update(Vec::clear);
+    }
+}
+
+/// Returns the [ToastHandle] of the surrounding [ToastContainer].
+///
+/// # Panics
+///
+/// If there is no [ToastContainer] above the calling component.
+pub fn use_toast() -> ToastHandle {
+    expect_context::<ToastHandle>()
+}
+
+/// Reserves a region for toasts that are pushed through [use_toast] by any of
+/// its children.
+#[component]
+pub fn ToastContainer(
+    /// Where the toasts are shown, defaults to the position of the
+    /// [FomanticConfig](super::FomanticConfig).
+    #[prop(optional)]
+    position: Option<ToastPosition>,
+    /// Wether the newest toast should be displayed on top.
+    #[prop(optional)]
+    newest_on_top: bool,
+    /// How the toasts are announced by screen readers.
+    #[prop(optional)]
+    aria_live: AriaLive,
+    /// Writing direction of the toasts, mirroring the position for right to
+    /// left. Defaults to the direction of the
+    /// [FomanticConfig](super::FomanticConfig).
+    #[prop(optional)]
+    direction: Option<Direction>,
+    /// ARIA attributes labelling the region of the toasts, eg. `Notifications`.
+    #[prop(optional, into)]
+    aria: Aria,
+    /// The components that are able to show toasts.
+    children: Children,
+) -> impl IntoView {
+    let config = use_fomantic_config();
+    let direction = direction.unwrap_or(config.direction);
+    let position = position
+        .unwrap_or(config.toast_position)
+        .for_direction(direction);
+    let handle = ToastHandle::new(config.toast_display_time);
+    provide_context(handle);
+
+    let toasts = move || {
+        let mut toasts = handle.toasts.get();
+        if newest_on_top {
+            toasts.reverse();
+        }
+        toasts
+    };
+    let render_toast = move |(id, toast): (usize, ToastContent)| {
+        let class = match toast.class {
+            Some(class) => format!("ui {class} toast"),
+            None => "ui toast".to_string(),
+        };
+        view! {
+            <div class="floating toast-box">
+                <div class=class>
+                    <i class="close icon" on:click=move |_| handle.dismiss(id)></i>
+                    <div class="content">
+                        { toast.title.map(|title| view! {
+                            <div class="ui header">{ title }</div>
+                        }) }
+                        <div class="message">
+                            { toast.content.run() }
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    };
+
+    view! {
+        { children() }
+        <div
+            class=format!("ui toast-container {position}")
+            dir=direction.to_string()
+            aria-live=aria_live.to_string()
+            aria-atomic="false"
+            aria-label=aria.label
+            aria-labelledby=aria.labelled_by
+            aria-describedby=aria.described_by
+            role=aria_live.role()>
+            <For
+                each=toasts
+                key=|(id, _)| *id
+                children=render_toast
+            />
+        </div>
+    }
+}
@@ -0,0 +1,153 @@
+use super::use_fomantic_config;
+use crate::{
+    a11y,
+    jq::JQuery,
+    macros::module_config,
+    modules::{
+        FomanticModule,
+        InstanceGuard,
+    },
+    style::TransitionName,
+};
+use leptos::{
+    html,
+    prelude::*,
+};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+module_config! {
+    /// Settings of the transition module.
+    struct TransitionConfig(JsTransitionConfig) {
+        /// Sets the animation, eg. `fade in`.
+        animation: &str = "animation",
+        /// Sets the duration of the animation in milliseconds.
+        duration: u32 = "duration",
+        /// Sets the handler that is called when the animation has finished.
+        on_complete: &Closure<dyn Fn()> = "onComplete",
+    }
+}
+
+/// Shows or hides its children with a `fomantic-ui` transition.
+///
+/// The children are mounted before the show animation starts and removed
+/// after the hide animation has finished. Unlike the leptos component of the
+/// same name, it does not wait for resources.
+#[component]
+pub fn Transition(
+    /// Whether the children are shown.
+    #[prop(into)]
+    when: Signal<bool>,
+    /// The animation used to show and hide the children. Static animations,
+    /// eg. [TransitionName::Shake], are played without hiding the children.
+    #[prop(default = TransitionName::Fade)]
+    animation: TransitionName,
+    /// Duration of the animation in milliseconds, defaults to the duration
+    /// of the [FomanticConfig](super::FomanticConfig).
+    #[prop(optional)]
+    duration: Option<u32>,
+    /// The content that is shown.
+    children: ChildrenFn,
+) -> impl IntoView {
+    let duration = a11y::transition_duration(
+        duration.or(use_fomantic_config().transition_duration),
+    );
+    let mounted = RwSignal::new(when.get_untracked());
+    // set when the children are mounted by a change of `when`, the initial
+    // state is shown without animation
+    let animate_in = StoredValue::new(false);
+    let ref_wrapper = NodeRef::<html::Div>::new();
+    let on_complete = StoredValue::new_local(None::<Rc<Closure<dyn Fn()>>>);
+    // destroys the module of the wrapper before the handler is dropped
+    let guard = StoredValue::new_local(None::<InstanceGuard>);
+
+    let run = move |element: &web_sys::Element, direction: &str| {
+        let config = TransitionConfig::new();
+        if animation.is_static() {
+            config.animation(&animation.to_string());
+        } else {
+            config.animation(&format!("{animation} {direction}"));
+        }
+        if let Some(duration) = duration {
+            config.duration(duration);
+        }
+        let handler = on_complete.get_value();
+        if let Some(handler) = &handler {
+            config.on_complete(handler);
+        }
+        let wrapper = JQuery::from_element(element);
+        wrapper.init("transition", config.as_ref());
+        // the wrapper is created again when the children are mounted again
+        let guarded = guard.with_value(|guard| {
+            guard
+                .as_ref()
+                .and_then(|guard| guard.element().element(0))
+                .is_some_and(|guarded| {
+                    element.is_same_node(Some(guarded.as_ref()))
+                })
+        });
+        if !guarded {
+            let wrapper = InstanceGuard::new(wrapper, "transition");
+            guard.set_value(Some(match handler {
+                Some(handler) => wrapper.owning(handler),
+                None => wrapper,
+            }));
+        }
+    };
+
+    Effect::new(move |previous: Option<bool>| {
+        let shown = when.get();
+        if previous.is_none() || previous == Some(shown) {
+            return shown;
+        }
+        if on_complete.with_value(Option::is_none) {
+            // the hide animation might be interrupted by showing again
+            on_complete.set_value(Some(Rc::new(Closure::<dyn Fn()>::new(
+                move || {
+                    if !when.get_untracked() {
+                        mounted.set(false);
+                    }
+                },
+            ))));
+        }
+        let element = ref_wrapper.get_untracked();
+        match (shown, mounted.get_untracked(), element) {
+            (true, true, Some(element)) => run(&element, "in"),
+            (true, false, _) => {
+                animate_in.set_value(true);
+                mounted.set(true);
+            }
+            (false, true, Some(element)) => run(&element, "out"),
+            (false, _, _) => mounted.set(false),
+            (true, true, None) => {}
+        }
+        shown
+    });
+
+    on_cleanup(move || guard.set_value(None));
+
+    // starts the show animation as soon as the children are mounted
+    Effect::new(move |_| {
+        if let Some(element) = ref_wrapper.get() {
+            if animate_in.get_value() {
+                animate_in.set_value(false);
+                run(&element, "in");
+            }
+        }
+    });
+
+    move || {
+        mounted.get().then(|| {
+            let class = if animate_in.get_value() {
+                "transition hidden"
+            } else {
+                ""
+            };
+            view! {
+                <div node_ref=ref_wrapper class=class>
+                    { children() }
+                </div>
+            }
+        })
+    }
+}
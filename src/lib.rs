@@ -1,11 +1,57 @@
 #![deny(missing_docs)]
 //! Bindings for [fomantic-ui](https://fomantic-ui.com/).
 
+pub mod a11y;
+#[cfg(any(feature = "modal", feature = "toast"))]
 mod action;
+#[cfg(feature = "bundled-assets")]
+pub mod assets;
+pub mod backend;
+pub mod callbacks;
+pub mod dom;
+mod environment;
+mod error;
+pub mod events;
+mod init;
+pub mod jq;
+mod key;
 #[cfg(feature = "leptos")]
 pub mod leptos;
+pub mod locale;
+mod logging;
+mod macros;
+#[cfg(feature = "mock")]
+pub mod mock;
 #[cfg(feature = "models")]
 pub mod models;
 pub mod modules;
+#[cfg(feature = "models")]
+pub mod settings;
+pub mod style;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+pub mod theme;
 
-pub use action::Action;
+#[cfg(any(feature = "modal", feature = "toast"))]
+pub use action::{
+    Action,
+    ActionHandle,
+    ActionItem,
+    ActionKind,
+};
+pub use environment::{
+    ensure_loaded,
+    Environment,
+};
+pub use error::FomanticError;
+pub use init::{
+    init_all,
+    try_init_all,
+};
+pub use key::Key;
+pub use logging::{
+    log_level,
+    set_log_level,
+};
+#[cfg(feature = "models")]
+pub use settings::settings;
@@ -0,0 +1,471 @@
+//! Translations of the texts shown by the modules.
+//!
+//! A [Locale] holds the texts of all modules, eg. the buttons of modals, the
+//! month names of the calendar or the prompts of form validation.
+//! [Locale::apply] writes them to the defaults of the modules, so they are
+//! used by every module initialized afterwards.
+//!
+//! Besides the built-in locales, the texts can be taken from any
+//! translation system, eg. fluent or gettext, by implementing [Translator]:
+//!
+//! ```no_run
+//! use fomantic_ui::locale::Locale;
+//!
+//! fn lookup(key: &str) -> Option<String> {
+//!     // eg. a fluent bundle, with the keys listed at Locale::translate
+//!     (key == "modal.cancel").then(|| "Abbrechen".to_string())
+//! }
+//!
+//! Locale::english().translate(&lookup).apply().unwrap();
+//! ```
+use crate::{
+    environment::extend_defaults,
+    FomanticError,
+};
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+/// Looks up the translation of a text by its key, see [Locale::translate].
+pub trait Translator {
+    /// Returns the translation of the given key, `None` to keep the text.
+    fn translate(&self, key: &str) -> Option<String>;
+}
+
+impl<F> Translator for F
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn translate(&self, key: &str) -> Option<String> {
+        self(key)
+    }
+}
+
+/// Texts of the modal templates, eg. alert and confirm.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct ModalLocale {
+    /// The button confirming the modal.
+    pub ok: String,
+    /// The button dismissing the modal.
+    pub cancel: String,
+    /// Title of the close icon.
+    pub close: String,
+}
+
+/// Texts of the calendar.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarLocale {
+    /// Names of the days, starting with sunday.
+    pub days: [String; 7],
+    /// Abbreviated names of the days, starting with sunday.
+    pub days_short: [String; 7],
+    /// Names of the months, starting with january.
+    pub months: [String; 12],
+    /// Abbreviated names of the months, starting with january.
+    pub months_short: [String; 12],
+    /// The button selecting the current day.
+    pub today: String,
+    /// The button selecting the current time.
+    pub now: String,
+    /// Marks times before noon.
+    pub am: String,
+    /// Marks times after noon.
+    pub pm: String,
+    /// Header of the column of calendar weeks.
+    pub week_no: String,
+}
+
+/// Messages of the dropdown.
+///
+/// `{term}`, `{count}` and `{maxCount}` are replaced by `fomantic-ui`.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DropdownLocale {
+    /// Shown for a value that can be added.
+    pub add_result: String,
+    /// Shown for the amount of selections.
+    pub count: String,
+    /// Shown if no more values can be selected.
+    pub max_selections: String,
+    /// Shown if the search has no results.
+    pub no_results: String,
+    /// Shown if the remote values could not be loaded.
+    pub server_error: String,
+}
+
+/// Prompts of the validation rules of forms.
+///
+/// `{name}` is replaced by the name of the field and `{ruleValue}` by the
+/// value of the rule, eg. the minimum length.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormLocale {
+    /// Rule `empty`.
+    pub empty: String,
+    /// Rule `checked`.
+    pub checked: String,
+    /// Rule `email`.
+    pub email: String,
+    /// Rule `url`.
+    pub url: String,
+    /// Rule `integer`.
+    pub integer: String,
+    /// Rule `decimal`.
+    pub decimal: String,
+    /// Rule `number`.
+    pub number: String,
+    /// Rule `minLength`.
+    pub min_length: String,
+    /// Rule `maxLength`.
+    pub max_length: String,
+    /// Rule `exactLength`.
+    pub exact_length: String,
+    /// Rule `match`.
+    #[serde(rename = "match")]
+    pub matches: String,
+    /// Rule `different`.
+    pub different: String,
+}
+
+/// Labels of the progress bar.
+///
+/// `{percent}`, `{value}` and `{total}` are replaced by `fomantic-ui`.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct ProgressLocale {
+    /// Label showing the percentage.
+    pub percent: String,
+    /// Label showing the value and the total.
+    pub ratio: String,
+}
+
+/// The texts of all modules in one language.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct Locale {
+    /// Texts of the modal templates.
+    pub modal: ModalLocale,
+    /// Texts of the calendar.
+    pub calendar: CalendarLocale,
+    /// Messages of the dropdown.
+    pub dropdown: DropdownLocale,
+    /// Prompts of form validation.
+    pub form: FormLocale,
+    /// Labels of the progress bar.
+    pub progress: ProgressLocale,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+/// Prefixes the names of the texts with the module.
+fn prefixed<'a, const N: usize>(
+    module: &str,
+    texts: [(&str, &'a mut String); N],
+) -> Vec<(String, &'a mut String)> {
+    texts
+        .into_iter()
+        .map(|(field, text)| (format!("{module}.{field}"), text))
+        .collect()
+}
+
+/// Serializes the texts of a module.
+fn to_js(texts: &impl Serialize) -> Result<JsValue, FomanticError> {
+    serde_wasm_bindgen::to_value(texts)
+        .map_err(|e| FomanticError::Js(e.to_string()))
+}
+
+/// Converts the given texts to owned strings.
+fn owned<const N: usize>(texts: [&str; N]) -> [String; N] {
+    texts.map(str::to_string)
+}
+
+impl Locale {
+    /// The texts of `fomantic-ui` in english.
+    pub fn english() -> Self {
+        Self {
+            modal: ModalLocale {
+                ok: "Ok".to_string(),
+                cancel: "Cancel".to_string(),
+                close: "Close".to_string(),
+            },
+            calendar: CalendarLocale {
+                days: owned([
+                    "Sunday",
+                    "Monday",
+                    "Tuesday",
+                    "Wednesday",
+                    "Thursday",
+                    "Friday",
+                    "Saturday",
+                ]),
+                days_short: owned(["S", "M", "T", "W", "T", "F", "S"]),
+                months: owned([
+                    "January",
+                    "February",
+                    "March",
+                    "April",
+                    "May",
+                    "June",
+                    "July",
+                    "August",
+                    "September",
+                    "October",
+                    "November",
+                    "December",
+                ]),
+                months_short: owned([
+                    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug",
+                    "Sep", "Oct", "Nov", "Dec",
+                ]),
+                today: "Today".to_string(),
+                now: "Now".to_string(),
+                am: "AM".to_string(),
+                pm: "PM".to_string(),
+                week_no: "Week".to_string(),
+            },
+            dropdown: DropdownLocale {
+                add_result: "Add <b>{term}</b>".to_string(),
+                count: "{count} selected".to_string(),
+                max_selections: "Max {maxCount} selections".to_string(),
+                no_results: "No results found.".to_string(),
+                server_error: "There was an error contacting the server"
+                    .to_string(),
+            },
+            form: FormLocale {
+                empty: "{name} must have a value".to_string(),
+                checked: "{name} must be checked".to_string(),
+                email: "{name} must be a valid e-mail".to_string(),
+                url: "{name} must be a valid url".to_string(),
+                integer: "{name} must be an integer".to_string(),
+                decimal: "{name} must be a decimal number".to_string(),
+                number: "{name} must be set to a number".to_string(),
+                min_length: "{name} must be at least {ruleValue} characters"
+                    .to_string(),
+                max_length: "{name} cannot be longer than {ruleValue} \
+                             characters"
+                    .to_string(),
+                exact_length: "{name} must be exactly {ruleValue} characters"
+                    .to_string(),
+                matches: "{name} must match {ruleValue} field".to_string(),
+                different: "{name} must have a different value than \
+                            {ruleValue} field"
+                    .to_string(),
+            },
+            progress: ProgressLocale {
+                percent: "{percent}%".to_string(),
+                ratio: "{value} of {total}".to_string(),
+            },
+        }
+    }
+
+    /// The texts of `fomantic-ui` in german.
+    pub fn german() -> Self {
+        Self {
+            modal: ModalLocale {
+                ok: "Ok".to_string(),
+                cancel: "Abbrechen".to_string(),
+                close: "Schließen".to_string(),
+            },
+            calendar: CalendarLocale {
+                days: owned([
+                    "Sonntag",
+                    "Montag",
+                    "Dienstag",
+                    "Mittwoch",
+                    "Donnerstag",
+                    "Freitag",
+                    "Samstag",
+                ]),
+                days_short: owned(["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"]),
+                months: owned([
+                    "Januar",
+                    "Februar",
+                    "März",
+                    "April",
+                    "Mai",
+                    "Juni",
+                    "Juli",
+                    "August",
+                    "September",
+                    "Oktober",
+                    "November",
+                    "Dezember",
+                ]),
+                months_short: owned([
+                    "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug",
+                    "Sep", "Okt", "Nov", "Dez",
+                ]),
+                today: "Heute".to_string(),
+                now: "Jetzt".to_string(),
+                am: "AM".to_string(),
+                pm: "PM".to_string(),
+                week_no: "KW".to_string(),
+            },
+            dropdown: DropdownLocale {
+                add_result: "<b>{term}</b> hinzufügen".to_string(),
+                count: "{count} ausgewählt".to_string(),
+                max_selections: "Höchstens {maxCount} Einträge".to_string(),
+                no_results: "Keine Ergebnisse gefunden.".to_string(),
+                server_error: "Der Server konnte nicht erreicht werden"
+                    .to_string(),
+            },
+            form: FormLocale {
+                empty: "{name} darf nicht leer sein".to_string(),
+                checked: "{name} muss ausgewählt sein".to_string(),
+                email: "{name} muss eine gültige E-Mail-Adresse sein"
+                    .to_string(),
+                url: "{name} muss eine gültige URL sein".to_string(),
+                integer: "{name} muss eine ganze Zahl sein".to_string(),
+                decimal: "{name} muss eine Dezimalzahl sein".to_string(),
+                number: "{name} muss eine Zahl sein".to_string(),
+                min_length: "{name} muss mindestens {ruleValue} Zeichen lang \
+                             sein"
+                    .to_string(),
+                max_length: "{name} darf höchstens {ruleValue} Zeichen lang \
+                             sein"
+                    .to_string(),
+                exact_length: "{name} muss genau {ruleValue} Zeichen lang sein"
+                    .to_string(),
+                matches: "{name} muss mit dem Feld {ruleValue} übereinstimmen"
+                    .to_string(),
+                different: "{name} muss sich vom Feld {ruleValue} \
+                            unterscheiden"
+                    .to_string(),
+            },
+            progress: ProgressLocale {
+                percent: "{percent}%".to_string(),
+                ratio: "{value} von {total}".to_string(),
+            },
+        }
+    }
+
+    /// Returns the texts by their key, see [Locale::translate].
+    fn texts_mut(&mut self) -> Vec<(String, &mut String)> {
+        let ModalLocale { ok, cancel, close } = &mut self.modal;
+        let mut texts = prefixed(
+            "modal",
+            [("ok", ok), ("cancel", cancel), ("close", close)],
+        );
+        let CalendarLocale {
+            days,
+            days_short,
+            months,
+            months_short,
+            today,
+            now,
+            am,
+            pm,
+            week_no,
+        } = &mut self.calendar;
+        texts.extend(prefixed(
+            "calendar",
+            [
+                ("today", today),
+                ("now", now),
+                ("am", am),
+                ("pm", pm),
+                ("week_no", week_no),
+            ],
+        ));
+        for (name, list) in [
+            ("days", days.as_mut_slice()),
+            ("days_short", days_short.as_mut_slice()),
+            ("months", months.as_mut_slice()),
+            ("months_short", months_short.as_mut_slice()),
+        ] {
+            for (i, text) in list.iter_mut().enumerate() {
+                texts.push((format!("calendar.{name}.{i}"), text));
+            }
+        }
+        let DropdownLocale {
+            add_result,
+            count,
+            max_selections,
+            no_results,
+            server_error,
+        } = &mut self.dropdown;
+        texts.extend(prefixed(
+            "dropdown",
+            [
+                ("add_result", add_result),
+                ("count", count),
+                ("max_selections", max_selections),
+                ("no_results", no_results),
+                ("server_error", server_error),
+            ],
+        ));
+        let FormLocale {
+            empty,
+            checked,
+            email,
+            url,
+            integer,
+            decimal,
+            number,
+            min_length,
+            max_length,
+            exact_length,
+            matches,
+            different,
+        } = &mut self.form;
+        texts.extend(prefixed(
+            "form",
+            [
+                ("empty", empty),
+                ("checked", checked),
+                ("email", email),
+                ("url", url),
+                ("integer", integer),
+                ("decimal", decimal),
+                ("number", number),
+                ("min_length", min_length),
+                ("max_length", max_length),
+                ("exact_length", exact_length),
+                ("match", matches),
+                ("different", different),
+            ],
+        ));
+        let ProgressLocale { percent, ratio } = &mut self.progress;
+        texts.extend(prefixed(
+            "progress",
+            [("percent", percent), ("ratio", ratio)],
+        ));
+        texts
+    }
+
+    /// Replaces the texts that the translator knows, keeping the others.
+    ///
+    /// The keys are the module followed by the field, eg. `modal.ok`,
+    /// `dropdown.no_results` or `form.min_length`. The day and month names
+    /// are suffixed with their index, eg. `calendar.months.0` for january.
+    /// The form prompt of the `match` rule has the key `form.match`.
+    pub fn translate(mut self, translator: &impl Translator) -> Self {
+        for (key, text) in self.texts_mut() {
+            if let Some(translation) = translator.translate(&key) {
+                *text = translation;
+            }
+        }
+        self
+    }
+
+    /// Writes the texts to the defaults of the modules.
+    ///
+    /// Modules that are not loaded are skipped.
+    pub fn apply(&self) -> Result<(), FomanticError> {
+        for (module, setting, texts) in [
+            ("modal", "text", to_js(&self.modal)?),
+            ("calendar", "text", to_js(&self.calendar)?),
+            ("dropdown", "message", to_js(&self.dropdown)?),
+            ("form", "prompt", to_js(&self.form)?),
+            ("progress", "text", to_js(&self.progress)?),
+        ] {
+            let settings = js_sys::Object::new();
+            js_sys::Reflect::set(&settings, &setting.into(), &texts)
+                .map_err(FomanticError::from_js)?;
+            extend_defaults(module, &settings)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+//! Forwarding of the debug output of `fomantic-ui` to `tracing`.
+//!
+//! With debug output enabled, the modules collect their log messages in a
+//! performance log, that they print to the console as table. The bridge
+//! installed by [set_log_level] emits every entry as `tracing` event with
+//! the target `fomantic_ui` and the fields `module`, `element_id`,
+//! `behavior` and `time_ms`, and the errors of the modules as error events
+//! with the fields `module` and `message`. Output of other scripts is passed
+//! to the console as before.
+use crate::{
+    environment::{
+        module_defaults,
+        MODULES,
+    },
+    FomanticError,
+};
+use std::cell::{
+    Cell,
+    RefCell,
+};
+use tracing::level_filters::LevelFilter;
+use wasm_bindgen::prelude::*;
+
+thread_local! {
+    /// The level set by [set_log_level].
+    static LEVEL: Cell<LevelFilter> = const { Cell::new(LevelFilter::ERROR) };
+    /// Whether the console methods have been replaced.
+    static INSTALLED: Cell<bool> = const { Cell::new(false) };
+    /// The module whose performance log is currently printed.
+    static GROUP: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Sets which output of `fomantic-ui` is forwarded to `tracing`.
+///
+/// - [LevelFilter::OFF] silences the modules, including their errors.
+/// - [LevelFilter::ERROR] up to [LevelFilter::INFO] forwards errors only,
+///   the default of `fomantic-ui`.
+/// - [LevelFilter::DEBUG] enables the `debug` and `performance` settings.
+/// - [LevelFilter::TRACE] additionally enables the `verbose` setting.
+///
+/// The defaults of all loaded modules are changed, so modules have to be
+/// initialized afterwards to pick up the level.
+///
+/// ```no_run
+/// use tracing::level_filters::LevelFilter;
+///
+/// fomantic_ui::set_log_level(LevelFilter::DEBUG).unwrap();
+/// ```
+pub fn set_log_level(level: LevelFilter) -> Result<(), FomanticError> {
+    install()?;
+    let debug = level >= LevelFilter::DEBUG;
+    for module in MODULES {
+        let Some(defaults) = module_defaults(module)? else {
+            continue;
+        };
+        for (setting, value) in [
+            ("silent", level == LevelFilter::OFF),
+            ("debug", debug),
+            ("performance", debug),
+            ("verbose", level >= LevelFilter::TRACE),
+        ] {
+            js_sys::Reflect::set(&defaults, &setting.into(), &value.into())
+                .map_err(FomanticError::from_js)?;
+        }
+    }
+    LEVEL.set(level);
+    Ok(())
+}
+
+/// Returns the level set by [set_log_level].
+pub fn log_level() -> LevelFilter {
+    LEVEL.get()
+}
+
+/// Returns the name of the module if the console output starts with the
+/// prefix of a module, eg. `Modal: 12ms`.
+fn module_of(output: &JsValue) -> Option<String> {
+    let output = output.as_string()?;
+    let (name, _) = output.split_once(':')?;
+    let name = name.to_lowercase();
+    MODULES.contains(&name.as_str()).then_some(name)
+}
+
+/// Emits the entries of a performance log.
+fn emit_performance(module: &str, entries: &JsValue) {
+    let Some(entries) = entries.dyn_ref::<js_sys::Array>() else {
+        return;
+    };
+    for entry in entries.iter() {
+        let field = |name: &str| {
+            js_sys::Reflect::get(&entry, &name.into()).unwrap_or_default()
+        };
+        let behavior = field("Name").as_string().unwrap_or_default();
+        let element_id = js_sys::Reflect::get(&field("Element"), &"id".into())
+            .ok()
+            .and_then(|id| id.as_string())
+            .unwrap_or_default();
+        let time_ms = field("Execution Time").as_f64().unwrap_or_default();
+        let arguments = js_sys::JSON::stringify(&field("Arguments"))
+            .map(String::from)
+            .unwrap_or_default();
+        tracing::debug!(
+            target: "fomantic_ui",
+            module,
+            element_id,
+            behavior,
+            time_ms,
+            "{arguments}"
+        );
+    }
+}
+
+/// Handles the console output of `method`. Returns whether it has been
+/// forwarded to `tracing`.
+fn forward(method: &str, arguments: js_sys::Array) -> bool {
+    let first = arguments.get(0);
+    match method {
+        "error" => {
+            let Some(module) = module_of(&first) else {
+                return false;
+            };
+            let message = arguments
+                .slice(1, arguments.length())
+                .iter()
+                .map(|argument| {
+                    argument.as_string().unwrap_or_else(|| {
+                        js_sys::JSON::stringify(&argument)
+                            .map(String::from)
+                            .unwrap_or_default()
+                    })
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            tracing::error!(target: "fomantic_ui", module, message);
+            true
+        }
+        "groupCollapsed" => {
+            let module = module_of(&first);
+            let forwarded = module.is_some();
+            GROUP.set(module);
+            forwarded
+        }
+        "table" => GROUP.with_borrow(|group| {
+            group
+                .as_deref()
+                .map(|module| emit_performance(module, &first))
+                .is_some()
+        }),
+        "groupEnd" => GROUP.take().is_some(),
+        _ => false,
+    }
+}
+
+/// Replaces the console methods used by `fomantic-ui` with the bridge.
+fn install() -> Result<(), FomanticError> {
+    if INSTALLED.get() {
+        return Ok(());
+    }
+    let console = js_sys::Reflect::get(&js_sys::global(), &"console".into())
+        .map_err(FomanticError::from_js)?;
+    let wrap = js_sys::Function::new_with_args(
+        "original, handler",
+        "return function() { if \
+         (!handler(Array.prototype.slice.call(arguments))) { return \
+         original.apply(console, arguments); } };",
+    );
+    for method in ["error", "groupCollapsed", "table", "groupEnd"] {
+        let original = js_sys::Reflect::get(&console, &method.into())
+            .map_err(FomanticError::from_js)?;
+        let handler =
+            Closure::<dyn Fn(js_sys::Array) -> bool>::new(move |arguments| {
+                forward(method, arguments)
+            });
+        let wrapped = wrap
+            .call2(&JsValue::NULL, &original, handler.as_ref())
+            .map_err(FomanticError::from_js)?;
+        js_sys::Reflect::set(&console, &method.into(), &wrapped)
+            .map_err(FomanticError::from_js)?;
+        // the console keeps calling the handler for the lifetime of the page
+        handler.forget();
+    }
+    INSTALLED.set(true);
+    Ok(())
+}
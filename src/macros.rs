@@ -0,0 +1,93 @@
+//! Macros generating the repetitive parts of the bindings.
+
+/// Declares the configuration object of a module together with a builder
+/// setting its fields.
+///
+/// Every field becomes a setter of the JavaScript object, named by the
+/// string after `=`, and a method of the builder with the same name as the
+/// field. The builder methods take `&self`, as the JavaScript object is
+/// mutated in place, so settings can be chained and set conditionally:
+///
+/// ```ignore
+/// module_config! {
+///     /// Settings of a popup.
+///     pub(crate) struct PopupConfig(JsPopupConfig) {
+///         /// Sets the position of the popup.
+///         position: &str = "position",
+///         /// Sets the duration of the transition in milliseconds.
+///         duration: u32 = "duration",
+///     }
+/// }
+///
+/// let config = PopupConfig::new();
+/// config.position("top left").duration(200);
+/// JQuery::from_element(&target).init("popup", config.as_ref());
+/// ```
+///
+/// Settings that are not set keep the defaults of the module.
+// unused if only modules without configuration objects are enabled
+#[allow(unused_macros)]
+macro_rules! module_config {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($js:ident) {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $ty:ty = $js_name:literal,
+            )*
+        }
+    ) => {
+        #[wasm_bindgen::prelude::wasm_bindgen]
+        extern "C" {
+            /// The JavaScript configuration object.
+            #[wasm_bindgen(js_name = Object)]
+            $vis type $js;
+
+            /// Creates an empty configuration.
+            #[wasm_bindgen(constructor, js_class = Object)]
+            fn new() -> $js;
+
+            $(
+                $(#[$field_meta])*
+                #[wasm_bindgen(method, setter, js_name = $js_name)]
+                fn $field(this: &$js, value: $ty);
+            )*
+        }
+
+        $(#[$meta])*
+        $vis struct $name {
+            js: $js,
+        }
+
+        impl $name {
+            /// Creates an empty configuration, using the defaults of the
+            /// module.
+            $vis fn new() -> Self {
+                Self { js: $js::new() }
+            }
+
+            $(
+                $(#[$field_meta])*
+                $vis fn $field(&self, value: $ty) -> &Self {
+                    self.js.$field(value);
+                    self
+                }
+            )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl AsRef<wasm_bindgen::JsValue> for $name {
+            fn as_ref(&self) -> &wasm_bindgen::JsValue {
+                &self.js
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use module_config;
@@ -0,0 +1,452 @@
+//! A recording replacement of jQuery and `fomantic-ui`, to test the logic
+//! built on the bindings with `cargo test`, without a browser.
+//!
+//! While a [MockBackend] is installed on the current thread, selections
+//! created by [JQuery](crate::jq::JQuery) record the behaviors invoked and
+//! the settings and attributes set on them, instead of calling into
+//! JavaScript. Classes, attributes and properties are kept per selector, so
+//! reading them back works as expected.
+//!
+//! ```
+//! use fomantic_ui::{
+//!     jq::JQuery,
+//!     mock::MockBackend,
+//! };
+//!
+//! let mock = MockBackend::install();
+//! mock.stub("dropdown", "is visible", true);
+//!
+//! let dropdown = JQuery::select("#country");
+//! dropdown.toggle_class("disabled", true);
+//! dropdown.behavior("dropdown", "refresh");
+//!
+//! assert!(dropdown.has_class("disabled"));
+//! assert!(dropdown.behavior_bool("dropdown", "is visible"));
+//! assert_eq!(mock.behaviors("#country"), ["refresh", "is visible"]);
+//! ```
+//!
+//! Only selections and callback registries can be used without a browser,
+//! as every other JavaScript value panics outside of WebAssembly.
+use std::{
+    cell::RefCell,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+};
+
+/// A call recorded by the [MockBackend].
+#[derive(Clone, PartialEq, Debug)]
+pub enum MockCall {
+    /// A module has been initialized. The settings are only recorded if
+    /// they are initialized from
+    /// [ModuleSettings](crate::modules::settings::ModuleSettings).
+    Init {
+        /// The selector of the selection.
+        selector: String,
+        /// Name of the module, eg. `dropdown`.
+        module: String,
+        /// The settings, serialized to JSON.
+        settings: Option<serde_json::Value>,
+    },
+    /// A behavior of a module has been invoked.
+    Behavior {
+        /// The selector of the selection.
+        selector: String,
+        /// Name of the module, eg. `dropdown`.
+        module: String,
+        /// The behavior, eg. `refresh`.
+        behavior: String,
+    },
+    /// A method that is not a module has been called.
+    Call {
+        /// The selector of the selection.
+        selector: String,
+        /// Name of the method.
+        method: String,
+    },
+    /// An attribute has been set.
+    Attr {
+        /// The selector of the selection.
+        selector: String,
+        /// Name of the attribute.
+        name: String,
+        /// Value of the attribute.
+        value: String,
+    },
+    /// A class has been added or removed.
+    ToggleClass {
+        /// The selector of the selection.
+        selector: String,
+        /// The class.
+        class: String,
+        /// Whether the class has been added.
+        state: bool,
+    },
+    /// A boolean property has been set.
+    Prop {
+        /// The selector of the selection.
+        selector: String,
+        /// Name of the property.
+        name: String,
+        /// Value of the property.
+        value: bool,
+    },
+    /// The text content has been set.
+    Text {
+        /// The selector of the selection.
+        selector: String,
+        /// The text.
+        text: String,
+    },
+    /// Html has been prepended.
+    Prepend {
+        /// The selector of the selection.
+        selector: String,
+        /// The html.
+        html: String,
+    },
+    /// Data has been attached.
+    Data {
+        /// The selector of the selection.
+        selector: String,
+        /// The key of the data.
+        key: String,
+    },
+    /// An event has been triggered.
+    Trigger {
+        /// The selector of the selection.
+        selector: String,
+        /// Name of the event.
+        event: String,
+    },
+    /// An event handler has been attached.
+    On {
+        /// The selector of the selection.
+        selector: String,
+        /// Name of the event.
+        event: String,
+        /// The selector of the descendants the handler listens on.
+        target: String,
+    },
+    /// The elements have been removed.
+    Remove {
+        /// The selector of the selection.
+        selector: String,
+    },
+}
+
+impl MockCall {
+    /// Returns the selector of the selection the call has been made on.
+    pub fn selector(&self) -> &str {
+        match self {
+            Self::Init { selector, .. }
+            | Self::Behavior { selector, .. }
+            | Self::Call { selector, .. }
+            | Self::Attr { selector, .. }
+            | Self::ToggleClass { selector, .. }
+            | Self::Prop { selector, .. }
+            | Self::Text { selector, .. }
+            | Self::Prepend { selector, .. }
+            | Self::Data { selector, .. }
+            | Self::Trigger { selector, .. }
+            | Self::On { selector, .. }
+            | Self::Remove { selector } => selector,
+        }
+    }
+}
+
+/// The recorded calls and the state of the mocked elements.
+#[derive(Default)]
+struct State {
+    calls: Vec<MockCall>,
+    stubs: BTreeMap<(String, String), bool>,
+    lengths: BTreeMap<String, u32>,
+    classes: BTreeMap<String, BTreeSet<String>>,
+    attrs: BTreeMap<(String, String), String>,
+    props: BTreeMap<(String, String), bool>,
+    texts: BTreeMap<String, String>,
+    missing: BTreeSet<String>,
+}
+
+thread_local! {
+    static STATE: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+/// Runs the function on the state of the installed backend.
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    STATE.with_borrow_mut(|state| {
+        f(state
+            .as_mut()
+            .expect("the mock backend has been uninstalled"))
+    })
+}
+
+/// Whether a [MockBackend] is installed on the current thread.
+pub(crate) fn is_installed() -> bool {
+    STATE.with_borrow(Option::is_some)
+}
+
+/// Records the given call.
+pub(crate) fn record(call: MockCall) {
+    with_state(|state| state.calls.push(call));
+}
+
+/// Records the behavior and returns its stubbed result.
+pub(crate) fn record_behavior(
+    selector: &str,
+    module: &str,
+    behavior: &str,
+) -> bool {
+    record(MockCall::Behavior {
+        selector: selector.to_string(),
+        module: module.to_string(),
+        behavior: behavior.to_string(),
+    });
+    with_state(|state| {
+        state
+            .stubs
+            .get(&(module.to_string(), behavior.to_string()))
+            .copied()
+            .unwrap_or_default()
+    })
+}
+
+/// Whether the module has been initialized on the selector and not been
+/// destroyed since.
+pub(crate) fn is_initialized(selector: &str, module: &str) -> bool {
+    with_state(|state| {
+        state
+            .calls
+            .iter()
+            .fold(false, |initialized, call| match call {
+                MockCall::Init {
+                    selector: s,
+                    module: m,
+                    ..
+                } if s == selector && m == module => true,
+                MockCall::Behavior {
+                    selector: s,
+                    module: m,
+                    behavior,
+                } if s == selector && m == module && behavior == "destroy" => {
+                    false
+                }
+                _ => initialized,
+            })
+    })
+}
+
+/// Returns an error if the module has been removed with
+/// [MockBackend::remove_module].
+pub(crate) fn require(module: &str) -> Result<(), crate::FomanticError> {
+    match with_state(|state| state.missing.contains(module)) {
+        true => Err(crate::FomanticError::ModuleMissing(module.to_string())),
+        false => Ok(()),
+    }
+}
+
+/// Returns the selector a mocked selection of the element is created with.
+pub(crate) fn element_selector(element: &web_sys::Element) -> String {
+    match element.id() {
+        id if id.is_empty() => element.tag_name().to_lowercase(),
+        id => format!("#{id}"),
+    }
+}
+
+pub(crate) fn length(selector: &str) -> u32 {
+    with_state(|state| state.lengths.get(selector).copied().unwrap_or(1))
+}
+
+pub(crate) fn set_attr(selector: &str, name: &str, value: &str) {
+    record(MockCall::Attr {
+        selector: selector.to_string(),
+        name: name.to_string(),
+        value: value.to_string(),
+    });
+    with_state(|state| {
+        state
+            .attrs
+            .insert((selector.to_string(), name.to_string()), value.to_string())
+    });
+}
+
+pub(crate) fn toggle_class(selector: &str, class: &str, on: bool) {
+    record(MockCall::ToggleClass {
+        selector: selector.to_string(),
+        class: class.to_string(),
+        state: on,
+    });
+    with_state(|state| {
+        let classes = state.classes.entry(selector.to_string()).or_default();
+        if on {
+            classes.insert(class.to_string());
+        } else {
+            classes.remove(class);
+        }
+    });
+}
+
+pub(crate) fn has_class(selector: &str, class: &str) -> bool {
+    with_state(|state| {
+        state
+            .classes
+            .get(selector)
+            .is_some_and(|classes| classes.contains(class))
+    })
+}
+
+pub(crate) fn set_prop(selector: &str, name: &str, value: bool) {
+    record(MockCall::Prop {
+        selector: selector.to_string(),
+        name: name.to_string(),
+        value,
+    });
+    with_state(|state| {
+        state
+            .props
+            .insert((selector.to_string(), name.to_string()), value)
+    });
+}
+
+pub(crate) fn set_text(selector: &str, text: &str) {
+    record(MockCall::Text {
+        selector: selector.to_string(),
+        text: text.to_string(),
+    });
+    with_state(|state| {
+        state.texts.insert(selector.to_string(), text.to_string())
+    });
+}
+
+/// Records the calls to jQuery on the current thread while it is alive.
+///
+/// Installing a backend discards the recordings of a previous one, the
+/// backend is uninstalled when dropped.
+pub struct MockBackend {
+    // installed per thread
+    _thread: std::marker::PhantomData<*const ()>,
+}
+
+impl MockBackend {
+    /// Installs the backend on the current thread.
+    pub fn install() -> Self {
+        STATE.set(Some(State::default()));
+        Self {
+            _thread: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns all recorded calls in order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        with_state(|state| state.calls.clone())
+    }
+
+    /// Returns the calls made on the given selector in order.
+    pub fn calls_on(&self, selector: &str) -> Vec<MockCall> {
+        with_state(|state| {
+            state
+                .calls
+                .iter()
+                .filter(|call| call.selector() == selector)
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Returns the behaviors invoked on the given selector in order.
+    pub fn behaviors(&self, selector: &str) -> Vec<String> {
+        self.calls_on(selector)
+            .into_iter()
+            .filter_map(|call| match call {
+                MockCall::Behavior { behavior, .. } => Some(behavior),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the settings the given module has last been initialized with
+    /// on the selector.
+    ///
+    /// Returns `Some(None)` if the settings have not been recorded, see
+    /// [MockCall::Init].
+    pub fn settings(
+        &self,
+        selector: &str,
+        module: &str,
+    ) -> Option<Option<serde_json::Value>> {
+        self.calls_on(selector)
+            .into_iter()
+            .rev()
+            .find_map(|call| match call {
+                MockCall::Init {
+                    module: m,
+                    settings,
+                    ..
+                } if m == module => Some(settings),
+                _ => None,
+            })
+    }
+
+    /// Sets the result of the given behavior, eg. `is active` of `modal`,
+    /// for every selection. Behaviors that are not stubbed return `false`.
+    pub fn stub(&self, module: &str, behavior: &str, result: bool) {
+        with_state(|state| {
+            state
+                .stubs
+                .insert((module.to_string(), behavior.to_string()), result)
+        });
+    }
+
+    /// Makes the given module unavailable, as if its script was not loaded.
+    pub fn remove_module(&self, module: &str) {
+        with_state(|state| state.missing.insert(module.to_string()));
+    }
+
+    /// Sets the amount of elements matching the given selector, `1` if not
+    /// set.
+    pub fn set_length(&self, selector: &str, length: u32) {
+        with_state(|state| state.lengths.insert(selector.to_string(), length));
+    }
+
+    /// Returns the value of the attribute set on the selector.
+    pub fn attr(&self, selector: &str, name: &str) -> Option<String> {
+        with_state(|state| {
+            state
+                .attrs
+                .get(&(selector.to_string(), name.to_string()))
+                .cloned()
+        })
+    }
+
+    /// Returns the value of the property set on the selector.
+    pub fn prop(&self, selector: &str, name: &str) -> Option<bool> {
+        with_state(|state| {
+            state
+                .props
+                .get(&(selector.to_string(), name.to_string()))
+                .copied()
+        })
+    }
+
+    /// Returns the text set on the selector.
+    pub fn text(&self, selector: &str) -> Option<String> {
+        with_state(|state| state.texts.get(selector).cloned())
+    }
+
+    /// Returns whether the selector has the given class.
+    pub fn has_class(&self, selector: &str, class: &str) -> bool {
+        has_class(selector, class)
+    }
+
+    /// Discards the recorded calls, keeping the stubs and element state.
+    pub fn clear(&self) {
+        with_state(|state| state.calls.clear());
+    }
+}
+
+impl Drop for MockBackend {
+    fn drop(&mut self) {
+        STATE.set(None);
+    }
+}
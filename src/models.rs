@@ -1,5 +1,62 @@
 //! Intermediary models to be used with different components.
 
+mod data_provider;
+mod editable;
+mod filterable;
+mod form_model;
+mod identifiable;
+mod observable;
+mod range_model;
+mod selected;
+mod selection_set;
+mod sort;
+mod tree_node;
+mod validatable;
+
+pub use data_provider::{
+    DataProvider,
+    MemoryProvider,
+    PageRequest,
+    PageResponse,
+};
+pub use editable::{
+    parse_edit,
+    Editable,
+};
+pub use filterable::{
+    contains_ignore_case,
+    Filterable,
+};
+pub use form_model::{
+    FormModel,
+    FormRule,
+};
+pub use identifiable::Identifiable;
+pub use observable::{
+    ListenerId,
+    Observable,
+};
+pub use range_model::{
+    RangeModel,
+    RangeValue,
+};
+pub use selected::Selected;
+pub use selection_set::SelectionSet;
+pub use sort::{
+    SortDirection,
+    SortState,
+};
+pub use tree_node::{
+    depth_first,
+    visible_nodes,
+    DepthFirst,
+    TreeNode,
+};
+pub use validatable::{
+    Validatable,
+    ValidationErrors,
+};
+
 /// Implementors get the ability to be selected, unselected or toggled.
 /// Useful for eg. checkboxes with data attached.
 pub trait Selectable {
@@ -11,4 +68,46 @@ pub trait Selectable {
     fn toggle(&mut self);
     /// Returns the current selection state.
     fn is_selected(&self) -> bool;
+    /// Returns whether the data is only partially selected, eg. a group of
+    /// which only some items are selected. Takes precedence over
+    /// [is_selected](Selectable::is_selected) when displayed.
+    fn is_indeterminate(&self) -> bool {
+        false
+    }
+    /// Returns the aggregate selection state. Implementors that calculate
+    /// both flags from the same data, eg. groups, should compute it in a
+    /// single pass.
+    fn selection_state(&self) -> SelectionState {
+        if self.is_indeterminate() {
+            SelectionState::Some
+        } else if self.is_selected() {
+            SelectionState::All
+        } else {
+            SelectionState::None
+        }
+    }
+}
+
+/// The aggregate selection state of a group of items, eg. shown by a
+/// select-all checkbox.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SelectionState {
+    /// No item is selected, or the group is empty.
+    None,
+    /// Some, but not all items are selected.
+    Some,
+    /// All items are selected.
+    All,
+}
+
+impl SelectionState {
+    /// Returns the state of a group with `total` items, of which `selected`
+    /// are selected.
+    pub fn from_counts(selected: usize, total: usize) -> Self {
+        match selected {
+            0 => Self::None,
+            s if s >= total => Self::All,
+            _ => Self::Some,
+        }
+    }
 }
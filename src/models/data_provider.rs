@@ -0,0 +1,124 @@
+use super::{
+    Filterable,
+    SortDirection,
+    SortState,
+};
+use std::{
+    cmp::Ordering,
+    future::Future,
+    sync::Arc,
+};
+
+/// The parameters of a request to a [DataProvider].
+#[derive(Clone, PartialEq, Debug)]
+pub struct PageRequest {
+    /// The requested page, starting at zero.
+    pub page: usize,
+    /// Amount of items per page.
+    pub size: usize,
+    /// The requested sort order, ordered by priority.
+    pub sort: Vec<SortState>,
+    /// The current filter text.
+    pub filter: String,
+}
+
+/// A page of items returned by a [DataProvider].
+#[derive(Clone, PartialEq, Debug)]
+pub struct PageResponse<T> {
+    /// The items of the requested page.
+    pub items: Vec<T>,
+    /// Total amount of items matching the request, over all pages.
+    pub total: usize,
+}
+
+/// An asynchronous source of paginated data, eg. a server endpoint.
+///
+/// Shared by the components that load their data on demand, eg. the
+/// `RemoteTable`.
+pub trait DataProvider<T> {
+    /// Fetches the items matching the given request.
+    fn fetch(
+        &self,
+        request: PageRequest,
+    ) -> impl Future<Output = PageResponse<T>>;
+}
+
+/// Compares two items of a [MemoryProvider].
+type Comparator<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
+/// A [DataProvider] serving items from memory, eg. for tests and
+/// prototypes.
+///
+/// The items are filtered using their [Filterable] implementation and sorted
+/// by the comparators registered for the requested columns.
+#[derive(Clone)]
+pub struct MemoryProvider<T> {
+    items: Arc<Vec<T>>,
+    comparators: Vec<(usize, Comparator<T>)>,
+}
+
+impl<T> MemoryProvider<T> {
+    /// Creates a provider serving the given items.
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            items: Arc::new(items),
+            comparators: vec![],
+        }
+    }
+
+    /// Sets the comparator used when sorting by the given column.
+    ///
+    /// Columns without comparator are skipped when sorting.
+    pub fn comparator<F>(mut self, column: usize, comparator: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        self.comparators.retain(|(c, _)| *c != column);
+        self.comparators.push((column, Arc::new(comparator)));
+        self
+    }
+}
+
+impl<T: Filterable + Clone> DataProvider<T> for MemoryProvider<T> {
+    fn fetch(
+        &self,
+        request: PageRequest,
+    ) -> impl Future<Output = PageResponse<T>> {
+        let query = request.filter.trim();
+        let mut items = self
+            .items
+            .iter()
+            .filter(|item| item.matches(query))
+            .collect::<Vec<_>>();
+        let criteria = request
+            .sort
+            .iter()
+            .filter_map(|sort| {
+                self.comparators
+                    .iter()
+                    .find(|(column, _)| *column == sort.column)
+                    .map(|(_, comparator)| (sort.direction, comparator))
+            })
+            .collect::<Vec<_>>();
+        if !criteria.is_empty() {
+            items.sort_by(|a, b| {
+                criteria
+                    .iter()
+                    .map(|(direction, comparator)| match direction {
+                        SortDirection::Ascending => comparator(a, b),
+                        SortDirection::Descending => comparator(b, a),
+                    })
+                    .find(|o| *o != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+        let total = items.len();
+        let items = items
+            .into_iter()
+            .skip(request.page * request.size)
+            .take(request.size)
+            .cloned()
+            .collect();
+        std::future::ready(PageResponse { items, total })
+    }
+}
@@ -0,0 +1,34 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+};
+
+/// Implementors describe which of their fields can be edited, eg. in the
+/// cells of a table, and how an edit is applied.
+///
+/// Fields are referred to by name and edited as text, use [parse_edit] to
+/// convert the text into the type of the field.
+pub trait Editable {
+    /// Returns whether the field with the given name can be edited.
+    fn is_editable(&self, field: &str) -> bool;
+
+    /// Returns the current value of the field as text, `None` if there is no
+    /// such field.
+    fn field_value(&self, field: &str) -> Option<String>;
+
+    /// Sets the field to the given value. Returns an error message if the
+    /// value is invalid for the field.
+    fn apply_edit(&mut self, field: &str, value: &str) -> Result<(), String>;
+}
+
+/// Parses the text of an edit into the type of a field, the error is turned
+/// into a message.
+///
+/// Useful when implementing [Editable].
+pub fn parse_edit<T>(value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.trim().parse().map_err(|e: T::Err| e.to_string())
+}
@@ -0,0 +1,48 @@
+/// Implementors can be matched against a search text, eg. by the search
+/// input of a table.
+///
+/// Keeping the filter logic on the model allows the same rules to be used by
+/// every component showing the data.
+pub trait Filterable {
+    /// Returns whether the data matches the given query.
+    fn matches(&self, query: &str) -> bool;
+
+    /// Returns whether the field with the given name matches the query.
+    /// Defaults to [matches](Filterable::matches) for all fields.
+    fn matches_field(&self, field: &str, query: &str) -> bool {
+        let _ = field;
+        self.matches(query)
+    }
+}
+
+/// Returns whether `text` contains `query`, ignoring the case. An empty
+/// query matches every text.
+///
+/// Useful when implementing [Filterable].
+pub fn contains_ignore_case(text: &str, query: &str) -> bool {
+    query.is_empty() || text.to_lowercase().contains(&query.to_lowercase())
+}
+
+impl Filterable for str {
+    fn matches(&self, query: &str) -> bool {
+        contains_ignore_case(self, query)
+    }
+}
+
+impl Filterable for String {
+    fn matches(&self, query: &str) -> bool {
+        contains_ignore_case(self, query)
+    }
+}
+
+impl<T: Filterable> Filterable for Option<T> {
+    fn matches(&self, query: &str) -> bool {
+        self.as_ref()
+            .map_or(query.is_empty(), |data| data.matches(query))
+    }
+
+    fn matches_field(&self, field: &str, query: &str) -> bool {
+        self.as_ref()
+            .map_or(query.is_empty(), |data| data.matches_field(field, query))
+    }
+}
@@ -0,0 +1,83 @@
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+
+/// A validation rule of a form field, see the
+/// [form validation](https://fomantic-ui.com/behaviors/form.html#validation-rules)
+/// of `fomantic-ui`.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct FormRule {
+    /// The type of the rule, eg. `empty` or `minLength[6]`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The message shown if the rule fails, the default message of
+    /// `fomantic-ui` is used if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+}
+
+impl FormRule {
+    /// Creates a rule of the given type.
+    pub fn new(kind: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            prompt: None,
+        }
+    }
+
+    /// Sets the message shown if the rule fails.
+    pub fn with_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// The field must not be empty.
+    pub fn empty() -> Self {
+        Self::new("empty")
+    }
+
+    /// The field must be a valid email address.
+    pub fn email() -> Self {
+        Self::new("email")
+    }
+
+    /// The field must be an integer.
+    pub fn integer() -> Self {
+        Self::new("integer")
+    }
+
+    /// The field must be checked, eg. a checkbox.
+    pub fn checked() -> Self {
+        Self::new("checked")
+    }
+
+    /// The field must have at least the given amount of characters.
+    pub fn min_length(length: usize) -> Self {
+        Self::new(format!("minLength[{length}]"))
+    }
+
+    /// The field must have at most the given amount of characters.
+    pub fn max_length(length: usize) -> Self {
+        Self::new(format!("maxLength[{length}]"))
+    }
+}
+
+/// Implementors can be shown and edited in a `fomantic-ui` form, see
+/// [Form](crate::modules::form::Form).
+///
+/// The fields of the serialized model are mapped to the form fields by
+/// name. The [Default] of the model provides the initial values and the
+/// types of the fields.
+pub trait FormModel: Serialize + DeserializeOwned + Default {
+    /// Returns the name of the form field the given field of the model is
+    /// shown in. Defaults to the serialized name of the field.
+    fn field_name(field: &str) -> String {
+        field.to_string()
+    }
+
+    /// Returns the validation rules by field of the model.
+    fn rules() -> Vec<(&'static str, Vec<FormRule>)> {
+        vec![]
+    }
+}
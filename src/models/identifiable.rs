@@ -0,0 +1,11 @@
+/// Implementors have a stable key identifying them, eg. a database id.
+///
+/// Components use the key to recognize items when the data changes, so
+/// only the key, not the whole item, has to be hashable or comparable.
+pub trait Identifiable {
+    /// The type of the key.
+    type Id;
+
+    /// Returns the key of the item.
+    fn id(&self) -> Self::Id;
+}
@@ -0,0 +1,98 @@
+/// Identifies a listener of an [Observable], used to unsubscribe it.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ListenerId(usize);
+
+/// Called with the data of an [Observable] after every change.
+type Listener<T> = Box<dyn Fn(&T)>;
+
+/// Wraps data and notifies the registered listeners whenever it changes.
+///
+/// Allows reacting to changes of a model without a reactive framework, eg.
+/// when using the module bindings directly. With the `leptos` feature, the
+/// data can also be mirrored into a signal.
+pub struct Observable<T> {
+    data: T,
+    listeners: Vec<(ListenerId, Listener<T>)>,
+    next_id: usize,
+}
+
+impl<T: Default> Default for Observable<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Observable<T> {
+    /// Wraps the given data.
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            listeners: vec![],
+            next_id: 0,
+        }
+    }
+
+    /// Returns the wrapped data.
+    pub fn get(&self) -> &T {
+        &self.data
+    }
+
+    /// Replaces the data and notifies the listeners.
+    pub fn set(&mut self, data: T) {
+        self.data = data;
+        self.notify();
+    }
+
+    /// Modifies the data in place and notifies the listeners.
+    pub fn update(&mut self, f: impl FnOnce(&mut T)) {
+        f(&mut self.data);
+        self.notify();
+    }
+
+    /// Registers a listener that is called with the data after every change.
+    pub fn subscribe(&mut self, listener: impl Fn(&T) + 'static) -> ListenerId {
+        let id = ListenerId(self.next_id);
+        self.next_id += 1;
+        self.listeners.push((id, Box::new(listener)));
+        id
+    }
+
+    /// Removes the given listener.
+    pub fn unsubscribe(&mut self, id: ListenerId) {
+        self.listeners.retain(|(listener, _)| *listener != id);
+    }
+
+    /// Calls all listeners with the current data.
+    fn notify(&self) {
+        for (_, listener) in &self.listeners {
+            listener(&self.data);
+        }
+    }
+
+    /// Returns a signal that holds a copy of the data and is updated on every
+    /// change.
+    ///
+    /// The signal stops being updated once the current reactive owner, eg.
+    /// the calling component, is disposed.
+    #[cfg(feature = "leptos")]
+    pub fn to_signal(&mut self) -> leptos::prelude::ReadSignal<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        use leptos::prelude::*;
+        let (data, set_data) = signal(self.data.clone());
+        self.subscribe(move |changed| {
+            set_data.try_set(changed.clone());
+        });
+        data
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Observable<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Observable")
+            .field("data", &self.data)
+            .field("listeners", &self.listeners.len())
+            .finish()
+    }
+}
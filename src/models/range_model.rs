@@ -0,0 +1,270 @@
+/// Numbers that can be the values of a [RangeModel].
+pub trait RangeValue: Copy + PartialOrd {
+    /// Converts the value for calculating steps.
+    fn to_f64(self) -> f64;
+    /// Converts the result of a step calculation back, rounding if
+    /// necessary.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_range_value {
+    ($($t:ty),*) => {
+        $(
+            impl RangeValue for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_range_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl RangeValue for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl RangeValue for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// The selected values of a [RangeModel].
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Selection<T> {
+    Single(T),
+    Pair { low: T, high: T },
+}
+
+/// A range of numbers with either a single selected value or a selected
+/// `low`/`high` pair, eg. of a slider or a numeric filter.
+///
+/// Values are clamped to the range and, if a step is set, snapped to the
+/// closest step counted from `min`.
+///
+/// ```
+/// use fomantic_ui::models::RangeModel;
+///
+/// let mut volume = RangeModel::new(0, 100).with_step(5);
+/// volume.set_value(42);
+/// assert_eq!(volume.value(), 40);
+/// volume.set_value(120);
+/// assert_eq!(volume.value(), 100);
+///
+/// let mut price = RangeModel::pair(0.0, 50.0);
+/// price.set_low(60.0);
+/// assert_eq!(price.low(), 50.0);
+/// assert!(price.contains(50.0) && !price.contains(49.0));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RangeModel<T> {
+    min: T,
+    max: T,
+    step: Option<T>,
+    selection: Selection<T>,
+}
+
+impl<T: RangeValue> RangeModel<T> {
+    /// Creates a range with a single value, initially `min`.
+    ///
+    /// The bounds are swapped if `min` is larger than `max`.
+    pub fn new(min: T, max: T) -> Self {
+        let (min, max) = ordered(min, max);
+        Self {
+            min,
+            max,
+            step: None,
+            selection: Selection::Single(min),
+        }
+    }
+
+    /// Creates a range with a `low`/`high` pair, initially covering the
+    /// whole range.
+    pub fn pair(min: T, max: T) -> Self {
+        let (min, max) = ordered(min, max);
+        Self {
+            min,
+            max,
+            step: None,
+            selection: Selection::Pair {
+                low: min,
+                high: max,
+            },
+        }
+    }
+
+    /// Sets the distance between the values that can be selected. The
+    /// current values are snapped to it.
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = Some(step).filter(|step| step.to_f64() > 0.0);
+        self.selection = match self.selection {
+            Selection::Single(value) => Selection::Single(self.fit(value)),
+            Selection::Pair { low, high } => Selection::Pair {
+                low: self.fit(low),
+                high: self.fit(high),
+            },
+        };
+        self
+    }
+
+    /// Returns the lower bound of the range.
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// Returns the upper bound of the range.
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// Returns the step, if set.
+    pub fn step(&self) -> Option<T> {
+        self.step
+    }
+
+    /// Returns whether a `low`/`high` pair is selected.
+    pub fn is_pair(&self) -> bool {
+        matches!(self.selection, Selection::Pair { .. })
+    }
+
+    /// Returns the selected value, the `low` value of a pair.
+    pub fn value(&self) -> T {
+        self.low()
+    }
+
+    /// Selects the given value, the `low` value of a pair.
+    pub fn set_value(&mut self, value: T) {
+        match self.selection {
+            Selection::Single(_) => {
+                self.selection = Selection::Single(self.fit(value))
+            }
+            Selection::Pair { .. } => self.set_low(value),
+        }
+    }
+
+    /// Returns the lower selected value, the value of a single selection.
+    pub fn low(&self) -> T {
+        match self.selection {
+            Selection::Single(value) => value,
+            Selection::Pair { low, .. } => low,
+        }
+    }
+
+    /// Returns the upper selected value, the value of a single selection.
+    pub fn high(&self) -> T {
+        match self.selection {
+            Selection::Single(value) => value,
+            Selection::Pair { high, .. } => high,
+        }
+    }
+
+    /// Selects the lower value of a pair, which can not exceed `high`. Sets
+    /// the value of a single selection.
+    pub fn set_low(&mut self, low: T) {
+        match self.selection {
+            Selection::Single(_) => self.set_value(low),
+            Selection::Pair { high, .. } => {
+                let low = self.fit(low);
+                self.selection = Selection::Pair {
+                    low: if low > high { high } else { low },
+                    high,
+                };
+            }
+        }
+    }
+
+    /// Selects the upper value of a pair, which can not fall below `low`.
+    /// Sets the value of a single selection.
+    pub fn set_high(&mut self, high: T) {
+        match self.selection {
+            Selection::Single(_) => self.set_value(high),
+            Selection::Pair { low, .. } => {
+                let high = self.fit(high);
+                self.selection = Selection::Pair {
+                    low,
+                    high: if high < low { low } else { high },
+                };
+            }
+        }
+    }
+
+    /// Selects the pair of the given values, in either order. Sets the
+    /// value of a single selection to the lower one.
+    pub fn set_pair(&mut self, a: T, b: T) {
+        let (low, high) = ordered(self.fit(a), self.fit(b));
+        self.selection = match self.selection {
+            Selection::Single(_) => Selection::Single(low),
+            Selection::Pair { .. } => Selection::Pair { low, high },
+        };
+    }
+
+    /// Returns whether the given value is selected, eg. to filter rows.
+    ///
+    /// For a pair, the value has to be between `low` and `high`, for a
+    /// single selection between `min` and the value.
+    pub fn contains(&self, value: T) -> bool {
+        let (low, high) = match self.selection {
+            Selection::Single(selected) => (self.min, selected),
+            Selection::Pair { low, high } => (low, high),
+        };
+        low <= value && value <= high
+    }
+
+    /// Returns the position of the given value in the range from `0.0` to
+    /// `1.0`, eg. to draw a slider.
+    pub fn fraction(&self, value: T) -> f64 {
+        let span = self.max.to_f64() - self.min.to_f64();
+        if span <= 0.0 {
+            return 0.0;
+        }
+        ((value.to_f64() - self.min.to_f64()) / span).clamp(0.0, 1.0)
+    }
+
+    /// Clamps the value to the range and snaps it to the closest step.
+    fn fit(&self, value: T) -> T {
+        let value = clamp(value, self.min, self.max);
+        let Some(step) = self.step else {
+            return value;
+        };
+        let (min, step) = (self.min.to_f64(), step.to_f64());
+        let steps = ((value.to_f64() - min) / step).round();
+        // the last step might exceed the range if it does not divide it
+        clamp(T::from_f64(min + steps * step), self.min, self.max)
+    }
+}
+
+/// Returns the given values in ascending order.
+fn ordered<T: PartialOrd>(a: T, b: T) -> (T, T) {
+    if a > b {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+/// Limits the value to the given bounds.
+fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
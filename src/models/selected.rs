@@ -0,0 +1,124 @@
+use super::Selectable;
+
+/// Wraps arbitrary data with a selection flag, eg. the items of a list with
+/// checkboxes.
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct Selected<T> {
+    /// The wrapped data.
+    pub data: T,
+    /// Whether the data is selected.
+    pub selected: bool,
+}
+
+impl<T> Selected<T> {
+    /// Wraps the given data, initially unselected.
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            selected: false,
+        }
+    }
+}
+
+impl<T> From<T> for Selected<T> {
+    fn from(data: T) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T> std::ops::Deref for Selected<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> std::ops::DerefMut for Selected<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<T> Selectable for Selected<T> {
+    fn select(&mut self) {
+        self.selected = true;
+    }
+
+    fn deselect(&mut self) {
+        self.selected = false;
+    }
+
+    fn toggle(&mut self) {
+        self.selected = !self.selected;
+    }
+
+    fn is_selected(&self) -> bool {
+        self.selected
+    }
+}
+
+impl Selectable for bool {
+    fn select(&mut self) {
+        *self = true;
+    }
+
+    fn deselect(&mut self) {
+        *self = false;
+    }
+
+    fn toggle(&mut self) {
+        *self = !*self;
+    }
+
+    fn is_selected(&self) -> bool {
+        *self
+    }
+}
+
+/// The flag of the tuple holds the selection state of the data.
+impl<T> Selectable for (T, bool) {
+    fn select(&mut self) {
+        self.1 = true;
+    }
+
+    fn deselect(&mut self) {
+        self.1 = false;
+    }
+
+    fn toggle(&mut self) {
+        self.1 = !self.1;
+    }
+
+    fn is_selected(&self) -> bool {
+        self.1
+    }
+}
+
+/// Delegates to the contained data, `None` is never selected.
+impl<T: Selectable> Selectable for Option<T> {
+    fn select(&mut self) {
+        if let Some(data) = self {
+            data.select();
+        }
+    }
+
+    fn deselect(&mut self) {
+        if let Some(data) = self {
+            data.deselect();
+        }
+    }
+
+    fn toggle(&mut self) {
+        if let Some(data) = self {
+            data.toggle();
+        }
+    }
+
+    fn is_selected(&self) -> bool {
+        self.as_ref().is_some_and(Selectable::is_selected)
+    }
+
+    fn is_indeterminate(&self) -> bool {
+        self.as_ref().is_some_and(Selectable::is_indeterminate)
+    }
+}
@@ -0,0 +1,161 @@
+use super::{
+    Identifiable,
+    SelectionState,
+};
+use std::{
+    collections::{
+        hash_set,
+        HashSet,
+    },
+    hash::Hash,
+};
+
+/// The keys of the selected items of a multi-selection, eg. of a table or a
+/// group of checkboxes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SelectionSet<K: Eq + Hash> {
+    keys: HashSet<K>,
+}
+
+impl<K: Eq + Hash> Default for SelectionSet<K> {
+    fn default() -> Self {
+        Self {
+            keys: HashSet::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> SelectionSet<K> {
+    /// Creates an empty selection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selects the item with the given key. Returns `false` if it was
+    /// already selected.
+    pub fn select(&mut self, key: K) -> bool {
+        self.keys.insert(key)
+    }
+
+    /// Deselects the item with the given key. Returns `false` if it was not
+    /// selected.
+    pub fn deselect(&mut self, key: &K) -> bool {
+        self.keys.remove(key)
+    }
+
+    /// Toggles the selection of the item with the given key. Returns whether
+    /// it is selected afterwards.
+    pub fn toggle(&mut self, key: K) -> bool {
+        if self.keys.remove(&key) {
+            false
+        } else {
+            self.keys.insert(key)
+        }
+    }
+
+    /// Selects all items with the given keys.
+    pub fn select_all(&mut self, keys: impl IntoIterator<Item = K>) {
+        self.keys.extend(keys);
+    }
+
+    /// Deselects all items with the given keys, other items stay selected.
+    pub fn deselect_all<'a>(&mut self, keys: impl IntoIterator<Item = &'a K>)
+    where
+        K: 'a,
+    {
+        for key in keys {
+            self.keys.remove(key);
+        }
+    }
+
+    /// Deselects all items.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+
+    /// Returns whether the item with the given key is selected.
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Returns the amount of selected items.
+    pub fn count(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns how many of the given keys are selected.
+    pub fn count_of<'a>(&self, keys: impl IntoIterator<Item = &'a K>) -> usize
+    where
+        K: 'a,
+    {
+        keys.into_iter()
+            .filter(|key| self.keys.contains(key))
+            .count()
+    }
+
+    /// Toggles the selection of the given item by its key. Returns whether it
+    /// is selected afterwards.
+    pub fn toggle_item<T: Identifiable<Id = K>>(&mut self, item: &T) -> bool {
+        self.toggle(item.id())
+    }
+
+    /// Returns whether the given item is selected by its key.
+    pub fn is_item_selected<T: Identifiable<Id = K>>(&self, item: &T) -> bool {
+        self.keys.contains(&item.id())
+    }
+
+    /// Returns the aggregate selection state of the items with the given
+    /// keys, eg. the rows of a table.
+    pub fn state_of<'a>(
+        &self,
+        keys: impl IntoIterator<Item = &'a K>,
+    ) -> SelectionState
+    where
+        K: 'a,
+    {
+        let (mut selected, mut total) = (0, 0);
+        for key in keys {
+            total += 1;
+            if self.keys.contains(key) {
+                selected += 1;
+            }
+        }
+        SelectionState::from_counts(selected, total)
+    }
+
+    /// Returns whether no item is selected.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Iterates over the keys of the selected items in arbitrary order.
+    pub fn iter(&self) -> hash_set::Iter<'_, K> {
+        self.keys.iter()
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<K> for SelectionSet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        Self {
+            keys: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> IntoIterator for SelectionSet<K> {
+    type Item = K;
+    type IntoIter = hash_set::IntoIter<K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter()
+    }
+}
+
+impl<'a, K: Eq + Hash> IntoIterator for &'a SelectionSet<K> {
+    type Item = &'a K;
+    type IntoIter = hash_set::Iter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.iter()
+    }
+}
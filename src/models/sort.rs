@@ -0,0 +1,92 @@
+/// Direction of a sorted column.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortDirection {
+    /// Smallest values first.
+    #[default]
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+impl std::fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ascending => write!(f, "ascending"),
+            Self::Descending => write!(f, "descending"),
+        }
+    }
+}
+
+/// The column data is sorted by, eg. of a table.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SortState {
+    /// Index of the sorted column.
+    pub column: usize,
+    /// Direction of the sorting.
+    pub direction: SortDirection,
+}
+
+impl SortState {
+    /// Sorts the given column in ascending order.
+    pub fn ascending(column: usize) -> Self {
+        Self {
+            column,
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    /// Sorts the given column in descending order.
+    pub fn descending(column: usize) -> Self {
+        Self {
+            column,
+            direction: SortDirection::Descending,
+        }
+    }
+
+    /// Returns the state after the heading of `column` has been clicked.
+    ///
+    /// Clicking the sorted column reverses the direction, clicking another
+    /// column sorts it in ascending order.
+    pub fn toggle(state: Option<Self>, column: usize) -> Option<Self> {
+        match state {
+            Some(s) if s.column == column => Some(Self {
+                column,
+                direction: s.direction.reversed(),
+            }),
+            _ => Some(Self::ascending(column)),
+        }
+    }
+
+    /// Updates the sort order, ordered by priority, after the heading of
+    /// `column` has been clicked.
+    ///
+    /// Without `additive`, this behaves like [toggle](Self::toggle). With
+    /// `additive`, eg. when shift is pressed, the column is added with the
+    /// lowest priority, or its direction is reversed if already sorted.
+    pub fn toggle_multi(states: &mut Vec<Self>, column: usize, additive: bool) {
+        let position = states.iter().position(|s| s.column == column);
+        match (position, additive) {
+            (Some(pos), true) => {
+                states[pos].direction = states[pos].direction.reversed()
+            }
+            (None, true) => states.push(Self::ascending(column)),
+            (_, false) => {
+                let single = match states.as_slice() {
+                    [state] => Some(*state),
+                    _ => None,
+                };
+                *states = Self::toggle(single, column).into_iter().collect();
+            }
+        }
+    }
+}
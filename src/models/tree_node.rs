@@ -0,0 +1,77 @@
+use std::slice;
+
+/// Implementors are nodes of a hierarchy that can be expanded and collapsed,
+/// eg. the rows of a tree table or nested accordions.
+pub trait TreeNode: Sized {
+    /// Returns the direct children of the node.
+    fn children(&self) -> &[Self];
+    /// Returns whether the children of the node are shown.
+    fn is_expanded(&self) -> bool;
+    /// Shows or hides the children of the node.
+    fn set_expanded(&mut self, expanded: bool);
+
+    /// Toggles whether the children of the node are shown.
+    fn toggle_expanded(&mut self) {
+        self.set_expanded(!self.is_expanded());
+    }
+
+    /// Returns whether the node has no children.
+    fn is_leaf(&self) -> bool {
+        self.children().is_empty()
+    }
+
+    /// Iterates over the node and all its descendants, see [depth_first].
+    fn descendants(&self) -> DepthFirst<'_, Self> {
+        depth_first(slice::from_ref(self))
+    }
+}
+
+/// Iterator over the nodes of a tree in depth-first order, returned by
+/// [depth_first] and [visible_nodes].
+///
+/// Yields every node together with its depth, starting at zero for the
+/// roots.
+pub struct DepthFirst<'a, T> {
+    stack: Vec<slice::Iter<'a, T>>,
+    only_expanded: bool,
+}
+
+impl<'a, T: TreeNode> Iterator for DepthFirst<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let depth = self.stack.len().checked_sub(1)?;
+            match self.stack[depth].next() {
+                Some(node) => {
+                    if !self.only_expanded || node.is_expanded() {
+                        self.stack.push(node.children().iter());
+                    }
+                    return Some((depth, node));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Iterates over all nodes of the given trees in depth-first order, with
+/// their depth.
+pub fn depth_first<T: TreeNode>(roots: &[T]) -> DepthFirst<'_, T> {
+    DepthFirst {
+        stack: vec![roots.iter()],
+        only_expanded: false,
+    }
+}
+
+/// Iterates over the nodes of the given trees whose ancestors are all
+/// expanded in depth-first order, with their depth. These are the nodes a
+/// renderer shows.
+pub fn visible_nodes<T: TreeNode>(roots: &[T]) -> DepthFirst<'_, T> {
+    DepthFirst {
+        stack: vec![roots.iter()],
+        only_expanded: true,
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+/// The error messages of the invalid fields of a model, by field name.
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct ValidationErrors(BTreeMap<String, Vec<String>>);
+
+impl ValidationErrors {
+    /// Creates an empty result, meaning the model is valid.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error message to the given field.
+    pub fn add(
+        &mut self,
+        field: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        self.0.entry(field.into()).or_default().push(message.into());
+    }
+
+    /// Adds the error message to the given field if `valid` is `false`.
+    ///
+    /// Allows writing the rules of a model as a list of checks.
+    pub fn check(
+        &mut self,
+        valid: bool,
+        field: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        if !valid {
+            self.add(field, message);
+        }
+    }
+
+    /// Returns whether there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the error messages of the given field.
+    pub fn field(&self, field: &str) -> &[String] {
+        self.0.get(field).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns the first error message of the given field, eg. to be shown
+    /// next to its input.
+    pub fn first(&self, field: &str) -> Option<&str> {
+        self.field(field).first().map(String::as_str)
+    }
+
+    /// Iterates over the invalid fields and their error messages.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.0
+            .iter()
+            .map(|(field, messages)| (field.as_str(), messages.as_slice()))
+    }
+
+    /// Returns all error messages, ordered by field name.
+    pub fn messages(&self) -> impl Iterator<Item = &str> {
+        self.0.values().flatten().map(String::as_str)
+    }
+}
+
+/// Implementors can check whether their data is valid, eg. before a form is
+/// submitted.
+///
+/// Keeping the rules on the model allows using them both for displaying the
+/// errors in a form and for guarding the submission.
+pub trait Validatable {
+    /// Returns the errors of the invalid fields.
+    fn validate(&self) -> ValidationErrors;
+
+    /// Returns whether all fields are valid.
+    fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+}
@@ -1,4 +1,241 @@
 //! Modules of `fomantic-ui`.
 
+#[cfg(feature = "form")]
+pub mod form;
+#[cfg(feature = "modal")]
 pub mod modal;
+#[cfg(feature = "models")]
+pub mod settings;
+#[cfg(feature = "toast")]
 pub mod toast;
+
+use crate::{
+    callbacks::CallbackRegistry,
+    jq::JQuery,
+    FomanticError,
+};
+use std::any::Any;
+use wasm_bindgen::JsValue;
+
+/// An initialized instance of a `fomantic-ui` module, eg. a
+/// [Modal](modal::Modal).
+///
+/// Allows writing utilities that work with every module, eg. registries or
+/// guards destroying the instance when dropped.
+pub trait FomanticModule {
+    /// Name of the jQuery plugin of the module, eg. `modal`.
+    fn module_name(&self) -> &'static str;
+
+    /// Returns the element the module is attached to.
+    fn element(&self) -> JQuery;
+
+    /// Returns the closures passed to the instance, if the module has
+    /// handlers.
+    fn callbacks(&self) -> Option<&CallbackRegistry> {
+        None
+    }
+
+    /// Replaces the handler of the given event, eg. `onShow`, also after
+    /// the module has been initialized.
+    ///
+    /// The handler receives the first argument of the callback and returns
+    /// its result. Returns `false` if no handler has been set for the event
+    /// before initialization, as the module would not call it.
+    fn replace_handler<H>(&self, event: &str, handler: H) -> bool
+    where
+        H: FnMut(JsValue) -> JsValue + 'static,
+        Self: Sized,
+    {
+        self.callbacks().is_some_and(|callbacks| {
+            callbacks.contains(event) && callbacks.replace(event, handler)
+        })
+    }
+
+    /// Initializes the module with the given settings, replacing the
+    /// current ones.
+    fn try_initialize(&self, settings: &JsValue) -> Result<(), FomanticError> {
+        self.element().try_init(self.module_name(), settings)
+    }
+
+    /// Initializes the module with the given settings, replacing the
+    /// current ones. Errors are thrown as JavaScript exception.
+    fn initialize(&self, settings: &JsValue) {
+        self.try_initialize(settings).unwrap_or_else(|e| e.throw())
+    }
+
+    /// Invokes the given behavior, eg. `show`, and returns its result.
+    fn try_behavior(&self, behavior: &str) -> Result<JsValue, FomanticError> {
+        self.element().try_behavior(self.module_name(), behavior)
+    }
+
+    /// Invokes the given behavior, eg. `show`, and returns its result.
+    /// Errors are thrown as JavaScript exception.
+    fn behavior(&self, behavior: &str) -> JsValue {
+        self.try_behavior(behavior).unwrap_or_else(|e| e.throw())
+    }
+
+    /// Invokes the given behavior that takes a value and returns its result.
+    fn try_behavior_with(
+        &self,
+        behavior: &str,
+        value: JsValue,
+    ) -> Result<JsValue, FomanticError> {
+        self.element()
+            .try_behavior_with(self.module_name(), behavior, &value)
+    }
+
+    /// Invokes the given behavior that takes a value and returns its result.
+    /// Errors are thrown as JavaScript exception.
+    fn behavior_with(&self, behavior: &str, value: JsValue) -> JsValue {
+        self.try_behavior_with(behavior, value)
+            .unwrap_or_else(|e| e.throw())
+    }
+
+    /// Destroys the instance and removes all events. The handlers of the
+    /// instance are dropped.
+    fn destroy(&self) {
+        self.behavior("destroy");
+        if let Some(callbacks) = self.callbacks() {
+            callbacks.clear();
+        }
+    }
+
+    /// Moves the instance into a guard that destroys it when dropped, see
+    /// [InstanceGuard].
+    ///
+    /// The instance can still be used through the guard.
+    fn into_guard(self) -> InstanceGuard<Self>
+    where
+        Self: Sized,
+    {
+        InstanceGuard {
+            element: self.element(),
+            module: self.module_name(),
+            instance: self,
+            instance_callbacks: Self::callbacks,
+            resources: vec![],
+        }
+    }
+}
+
+/// Destroys a module instance when dropped and releases the closures passed
+/// to it.
+///
+/// `fomantic-ui` keeps the handlers of a module, and the event listeners it
+/// binds to the document or window, until the instance is destroyed, also
+/// after its element has been removed from the page. Dropping a [Closure]
+/// the module still calls throws, while keeping it leaks. The guard owns the
+/// closures together with the instance, so both go away at the same time,
+/// eg. when a component is unmounted on navigation:
+///
+/// ```no_run
+/// use fomantic_ui::{
+///     callbacks::CallbackRegistry,
+///     jq::JQuery,
+///     modules::InstanceGuard,
+/// };
+/// use wasm_bindgen::JsValue;
+///
+/// let callbacks = CallbackRegistry::new();
+/// let settings = js_sys::Object::new();
+/// let on_change = callbacks.set("onChange", |_| JsValue::UNDEFINED);
+/// js_sys::Reflect::set(&settings, &"onChange".into(), &on_change).unwrap();
+///
+/// let dropdown = JQuery::select("#country");
+/// dropdown.init("dropdown", &settings);
+/// let guard = InstanceGuard::new(dropdown, "dropdown").owning(callbacks);
+///
+/// // destroys the dropdown, then drops the handler
+/// drop(guard);
+/// ```
+///
+/// The modules wrapped by the crate have constructors returning a guard
+/// that holds the module, eg.
+/// [Modal::try_new_guarded](modal::Modal::try_new_guarded). The module is
+/// used through the guard, which dereferences to it.
+///
+/// [Closure]: wasm_bindgen::closure::Closure
+#[must_use = "the instance is destroyed when the guard is dropped"]
+pub struct InstanceGuard<M = ()> {
+    element: JQuery,
+    module: &'static str,
+    instance: M,
+    /// Returns the closures of the instance, as `M` is only known to be a
+    /// module when created by [FomanticModule::into_guard].
+    instance_callbacks: fn(&M) -> Option<&CallbackRegistry>,
+    resources: Vec<Box<dyn Any>>,
+}
+
+impl InstanceGuard {
+    /// Guards the instance of the module, eg. `dropdown`, initialized on the
+    /// element.
+    pub fn new(element: JQuery, module: &'static str) -> Self {
+        Self {
+            element,
+            module,
+            instance: (),
+            instance_callbacks: |_| None,
+            resources: vec![],
+        }
+    }
+}
+
+impl<M> InstanceGuard<M> {
+    /// Keeps the given value, eg. the closures or [CallbackRegistry] passed
+    /// to the instance, until the instance has been destroyed.
+    ///
+    /// The handlers of an owned [CallbackRegistry] can be replaced through
+    /// the guard, see [FomanticModule::replace_handler].
+    pub fn owning(mut self, resource: impl Any) -> Self {
+        self.resources.push(Box::new(resource));
+        self
+    }
+}
+
+impl<M> std::ops::Deref for InstanceGuard<M> {
+    type Target = M;
+
+    fn deref(&self) -> &Self::Target {
+        &self.instance
+    }
+}
+
+impl<M> std::ops::DerefMut for InstanceGuard<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.instance
+    }
+}
+
+impl<M> FomanticModule for InstanceGuard<M> {
+    fn module_name(&self) -> &'static str {
+        self.module
+    }
+
+    fn element(&self) -> JQuery {
+        self.element.clone()
+    }
+
+    // the guard is found before the instance it dereferences to, so the
+    // handlers of the instance, or a registry it owns, have to be passed on
+    fn callbacks(&self) -> Option<&CallbackRegistry> {
+        (self.instance_callbacks)(&self.instance).or_else(|| {
+            self.resources
+                .iter()
+                .find_map(|resource| resource.downcast_ref())
+        })
+    }
+}
+
+impl<M> Drop for InstanceGuard<M> {
+    fn drop(&mut self) {
+        // instances destroyed before, eg. removed modals, are left alone
+        if !self.element.is_initialized(self.module) {
+            return;
+        }
+        if let Err(e) = self.element.try_behavior(self.module, "destroy") {
+            tracing::warn!("Could not destroy the {}: {e}", self.module);
+        }
+        // the instance and resources are dropped after this, once the
+        // module is gone
+    }
+}
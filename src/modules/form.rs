@@ -0,0 +1,194 @@
+//! Form bindings.
+use crate::{
+    jq::JQuery,
+    models::{
+        FormModel,
+        FormRule,
+    },
+    modules::{
+        FomanticModule,
+        InstanceGuard,
+    },
+};
+use anyhow::anyhow;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+};
+use wasm_bindgen::prelude::*;
+
+/// The validation settings of a form field.
+#[derive(Serialize)]
+struct FieldSettings {
+    identifier: String,
+    rules: Vec<FormRule>,
+}
+
+/// The settings the form module is initialized with.
+#[derive(Serialize)]
+struct FormSettings {
+    fields: BTreeMap<String, FieldSettings>,
+    inline: bool,
+}
+
+/// Converts the given value into a plain JavaScript value.
+fn to_js<T: Serialize>(value: &T) -> anyhow::Result<JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| anyhow!("Could not serialize the form model: {e}"))
+}
+
+/// Reads a property of a JavaScript object.
+fn get(object: &JsValue, key: &str) -> anyhow::Result<JsValue> {
+    js_sys::Reflect::get(object, &key.into())
+        .map_err(|e| anyhow!("Could not read form field {key}: {e:?}"))
+}
+
+/// Sets a property of a JavaScript object.
+fn set(object: &JsValue, key: &str, value: &JsValue) -> anyhow::Result<()> {
+    js_sys::Reflect::set(object, &key.into(), value)
+        .map(|_| ())
+        .map_err(|e| anyhow!("Could not set form field {key}: {e:?}"))
+}
+
+/// A `fomantic-ui` form bound to the model `M`.
+///
+/// The form module is initialized with the validation rules of the model,
+/// its values can be written from and read into instances of the model.
+pub struct Form<M> {
+    js_form: JQuery,
+    /// The fields of the model with the name of their form field.
+    fields: Vec<(String, String)>,
+    /// The serialized default of the model.
+    defaults: JsValue,
+    model: PhantomData<M>,
+}
+
+impl<M: FormModel> Form<M> {
+    /// Initializes the form in the given element and fills it with the
+    /// values of the model.
+    pub fn from_model(
+        element: &web_sys::Element,
+        model: &M,
+    ) -> anyhow::Result<Self> {
+        let defaults = to_js(&M::default())?;
+        if !defaults.is_object() {
+            anyhow::bail!("The form model has to be serialized as a map.");
+        }
+        let fields =
+            js_sys::Object::keys(defaults.unchecked_ref::<js_sys::Object>())
+                .iter()
+                .filter_map(|field: JsValue| field.as_string())
+                .map(|field| {
+                    let name = M::field_name(&field);
+                    (field, name)
+                })
+                .collect();
+        let settings = FormSettings {
+            fields: M::rules()
+                .into_iter()
+                .map(|(field, rules)| {
+                    let identifier = M::field_name(field);
+                    (identifier.clone(), FieldSettings { identifier, rules })
+                })
+                .collect(),
+            inline: true,
+        };
+        let js_form = JQuery::try_from_element(element)?;
+        js_form.try_init("form", &to_js(&settings)?)?;
+        let form = Self {
+            js_form,
+            fields,
+            defaults,
+            model: PhantomData,
+        };
+        form.write(model)?;
+        Ok(form)
+    }
+
+    /// Initializes the form like [Form::from_model] and returns a guard
+    /// that destroys it when dropped, see [InstanceGuard].
+    pub fn from_model_guarded(
+        element: &web_sys::Element,
+        model: &M,
+    ) -> anyhow::Result<InstanceGuard<Self>> {
+        Self::from_model(element, model).map(FomanticModule::into_guard)
+    }
+
+    /// Fills the form with the values of the given model.
+    pub fn write(&self, model: &M) -> anyhow::Result<()> {
+        let values = to_js(model)?;
+        let form_values = JsValue::from(js_sys::Object::new());
+        for (field, name) in &self.fields {
+            set(&form_values, name, &get(&values, field)?)?;
+        }
+        self.js_form
+            .behavior_with("form", "set values", &form_values);
+        Ok(())
+    }
+
+    /// Reads the current values of the form into a new model.
+    ///
+    /// Fields missing in the form keep their default. Text is converted
+    /// into a number for fields that are numbers in the default model.
+    pub fn read(&self) -> anyhow::Result<M> {
+        let form_values = self.js_form.behavior("form", "get values");
+        let values = JsValue::from(js_sys::Object::new());
+        for (field, name) in &self.fields {
+            let default = get(&self.defaults, field)?;
+            let value = get(&form_values, name)?;
+            let value = match value.as_string() {
+                _ if value.is_undefined() => default,
+                Some(text) if default.as_f64().is_some() => {
+                    JsValue::from_f64(text.trim().parse().unwrap_or(f64::NAN))
+                }
+                _ => value,
+            };
+            set(&values, field, &value)?;
+        }
+        serde_wasm_bindgen::from_value(values)
+            .map_err(|e| anyhow!("Could not read the form model: {e}"))
+    }
+
+    /// Replaces the given model with the current values of the form.
+    pub fn update_model(&self, model: &mut M) -> anyhow::Result<()> {
+        *model = self.read()?;
+        Ok(())
+    }
+
+    /// Validates the form, showing the messages of the failed rules.
+    /// Returns whether all rules passed.
+    pub fn validate(&self) -> bool {
+        self.js_form.behavior("form", "validate form");
+        self.is_valid()
+    }
+
+    /// Returns whether all rules pass, without showing messages.
+    pub fn is_valid(&self) -> bool {
+        self.js_form
+            .behavior("form", "is valid")
+            .as_bool()
+            .unwrap_or(false)
+    }
+
+    /// Resets the fields to their initial values and removes the errors.
+    pub fn reset(&self) {
+        self.js_form.behavior("form", "reset");
+    }
+
+    /// Destroys instance and removes all events.
+    pub fn destroy(&self) {
+        self.js_form.behavior("form", "destroy");
+    }
+}
+
+impl<M: FormModel> FomanticModule for Form<M> {
+    fn module_name(&self) -> &'static str {
+        "form"
+    }
+
+    fn element(&self) -> JQuery {
+        self.js_form.clone()
+    }
+}
@@ -1,32 +1,204 @@
 //! Modal bindings.
-use crate::action::{
-    Action,
-    JsActionConfig,
+#[cfg(feature = "models")]
+use crate::modules::settings::{
+    ModalSettings,
+    ModuleSettings,
+};
+use crate::{
+    a11y::FocusTrap,
+    action::{
+        Action,
+        ActionHandle,
+        JsActionConfig,
+    },
+    callbacks::CallbackRegistry,
+    environment::ensure_module,
+    events::EventStream,
+    jq::{
+        invoke,
+        JQuery,
+    },
+    key::shortcut_event,
+    modules::{
+        FomanticModule,
+        InstanceGuard,
+    },
+    style::{
+        Classes,
+        TransitionName,
+    },
+    FomanticError,
+    Key,
+};
+use gloo::events::EventListener;
+use std::{
+    cell::OnceCell,
+    rc::Rc,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
 };
 use wasm_bindgen::prelude::*;
 
+/// Counter used to generate unique element ids for ARIA references.
+static ARIA_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The ARIA role of a [Modal].
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum ModalRole {
+    /// A regular dialog.
+    #[default]
+    Dialog,
+    /// A dialog that conveys an urgent message and requires a response.
+    AlertDialog,
+}
+
+impl std::fmt::Display for ModalRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dialog => write!(f, "dialog"),
+            Self::AlertDialog => write!(f, "alertdialog"),
+        }
+    }
+}
+
+/// Accessibility attributes of a [Modal].
+#[derive(Default, Clone)]
+pub struct ModalAria {
+    /// The role of the modal element.
+    pub role: ModalRole,
+    /// Id of the element that labels the modal. The header of the modal is used if not set.
+    pub labelled_by: Option<String>,
+    /// Id of the element that describes the modal. The content of the modal is used if not set.
+    pub described_by: Option<String>,
+}
+
+/// An event of a [Modal], see [ModalConfig::events].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModalEvent {
+    /// The modal starts to show.
+    Show,
+    /// The modal has finished its show animation.
+    Visible,
+    /// The modal starts to hide.
+    Hide,
+    /// The modal has finished its hide animation.
+    Hidden,
+    /// A positive, approve or ok button has been pressed.
+    Approve,
+    /// A negative, deny or cancel button has been pressed.
+    Deny,
+}
+
 /// The configuration of a modal.
+///
+/// `fomantic-ui` copies the configuration when the modal is created, so it
+/// has to be complete before it is passed to [Modal::try_new].
 pub struct ModalConfig {
     pub(crate) js_config: JsModalConfig,
-    on_show: Closure<dyn Fn() -> bool>,
-    on_visible: Closure<dyn Fn() -> bool>,
-    on_hide: Closure<dyn Fn(JsValue) -> bool>,
-    on_hidden: Closure<dyn Fn() -> bool>,
-    on_approve: Closure<dyn Fn(JsValue) -> bool>,
-    on_deny: Closure<dyn Fn(JsValue) -> bool>,
+    callbacks: CallbackRegistry,
+    action_handler_list: Vec<Closure<dyn FnMut() -> bool>>,
+    shortcuts: Vec<(usize, Key)>,
+    /// The modal element, set once the modal has been created, so the
+    /// handles of the actions can find their buttons.
+    root: Rc<OnceCell<JQuery>>,
 }
 
 impl ModalConfig {
+    /// Wraps the given JavaScript configuration.
+    fn from_js(js_config: JsModalConfig) -> Self {
+        Self {
+            js_config,
+            callbacks: CallbackRegistry::new(),
+            action_handler_list: vec![],
+            shortcuts: vec![],
+            root: Rc::new(OnceCell::new()),
+        }
+    }
+
+    /// Creates a configuration from the given settings.
+    ///
+    /// Handlers can be set on the returned configuration.
+    #[cfg(feature = "models")]
+    pub fn try_from_settings(
+        settings: &ModalSettings,
+    ) -> Result<Self, FomanticError> {
+        Ok(Self::from_js(settings.to_js()?.unchecked_into()))
+    }
+
+    /// Sets the title of the modal.
+    pub fn with_title(self, title: &str) -> Self {
+        self.js_config.set_title(title);
+        self
+    }
+
+    /// Sets the content of the modal.
+    pub fn with_content(self, value: &str) -> Self {
+        self.js_config.set_content(value);
+        self
+    }
+
+    /// Sets the class of the modal.
+    pub fn with_class(self, class: impl Into<Classes>) -> Self {
+        self.js_config.set_class(&class.into().to_string());
+        self
+    }
+
+    /// Wether a close icon should be shown.
+    pub fn with_close_icon(self, value: bool) -> Self {
+        self.js_config.set_close_icon(value);
+        self
+    }
+
+    /// Sets the animation used to show and hide the modal.
+    pub fn with_transition(self, transition: TransitionName) -> Self {
+        self.js_config
+            .set_transition(JsValue::from_str(&transition.to_string()));
+        self
+    }
+
+    /// Sets the actions shown on the modal.
+    pub fn with_actions(mut self, actions: Vec<Action>) -> Self {
+        self.set_actions(actions);
+        self
+    }
+
+    /// Sets the actions shown on the modal.
+    ///
+    /// Returns a handle for every action, in the same order, that can be used
+    /// to modify the action once the modal has been created.
+    pub fn set_actions(&mut self, actions: Vec<Action>) -> Vec<ActionHandle> {
+        let mut js_actions = vec![];
+        let mut handles = vec![];
+        self.action_handler_list.clear();
+        self.shortcuts.clear();
+        for (idx, act) in actions.into_iter().enumerate() {
+            if let Some(key) = act.shortcut {
+                self.shortcuts.push((idx, key));
+            }
+            let handle =
+                ActionHandle::new(Rc::clone(&self.root), idx, act.icon);
+            *act.handle.borrow_mut() = Some(handle.clone());
+            handles.push(handle);
+            self.action_handler_list.push(act.click);
+            js_actions.push(act.js_config);
+        }
+        self.js_config.set_actions(js_actions.into_boxed_slice());
+        handles
+    }
+
     /// Is called when a modal starts to show. If the function returns false, the modal will not be shown.
     pub fn set_on_show<H: Fn() -> bool + 'static>(&mut self, handler: H) {
-        self.on_show = Closure::new(handler);
-        self.js_config.set_on_show(&self.on_show);
+        let function = self.callbacks.set("onShow", move |_| handler().into());
+        self.js_config.set_on_show(&function);
     }
 
     /// Is called after a modal has finished showing animating.
     pub fn set_on_visible<H: Fn() -> bool + 'static>(&mut self, handler: H) {
-        self.on_visible = Closure::new(handler);
-        self.js_config.set_on_visible(&self.on_visible);
+        let function =
+            self.callbacks.set("onVisible", move |_| handler().into());
+        self.js_config.set_on_visible(&function);
     }
 
     /// Is called after a modal starts to hide. If the function returns false, the modal will not hide.
@@ -34,14 +206,17 @@ impl ModalConfig {
         &mut self,
         handler: H,
     ) {
-        self.on_hide = Closure::new(handler);
-        self.js_config.set_on_hide(&self.on_hide);
+        let function = self
+            .callbacks
+            .set("onHide", move |element| handler(element).into());
+        self.js_config.set_on_hide(&function);
     }
 
     /// Is called after a modal has finished hiding animation.
     pub fn set_on_hidden<H: Fn() -> bool + 'static>(&mut self, handler: H) {
-        self.on_hidden = Closure::new(handler);
-        self.js_config.set_on_hidden(&self.on_hidden);
+        let function =
+            self.callbacks.set("onHidden", move |_| handler().into());
+        self.js_config.set_on_hidden(&function);
     }
 
     /// Is called after a positive, approve or ok button is pressed. If the function returns false, the modal will not hide.
@@ -49,8 +224,10 @@ impl ModalConfig {
         &mut self,
         handler: H,
     ) {
-        self.on_approve = Closure::new(handler);
-        self.js_config.set_on_approve(&self.on_approve);
+        let function = self
+            .callbacks
+            .set("onApprove", move |element| handler(element).into());
+        self.js_config.set_on_approve(&function);
     }
 
     /// Is called after a negative, deny or cancel button is pressed. If the function returns false the modal will not hide.
@@ -58,22 +235,45 @@ impl ModalConfig {
         &mut self,
         handler: H,
     ) {
-        self.on_deny = Closure::new(handler);
-        self.js_config.set_on_deny(&self.on_deny);
+        let function = self
+            .callbacks
+            .set("onDeny", move |element| handler(element).into());
+        self.js_config.set_on_deny(&function);
+    }
+
+    /// Returns a stream of the events of the modal.
+    ///
+    /// Replaces the handlers set before, the modal is neither kept open nor
+    /// prevented from showing.
+    pub fn events(&mut self) -> EventStream<ModalEvent> {
+        let (sender, stream) = EventStream::channel();
+        let emit = |event| {
+            let sender = sender.clone();
+            move || {
+                sender.send(event);
+                true
+            }
+        };
+        let emit_with = |event| {
+            let sender = sender.clone();
+            move |_: JsValue| {
+                sender.send(event);
+                true
+            }
+        };
+        self.set_on_show(emit(ModalEvent::Show));
+        self.set_on_visible(emit(ModalEvent::Visible));
+        self.set_on_hide(emit_with(ModalEvent::Hide));
+        self.set_on_hidden(emit(ModalEvent::Hidden));
+        self.set_on_approve(emit_with(ModalEvent::Approve));
+        self.set_on_deny(emit_with(ModalEvent::Deny));
+        stream
     }
 }
 
 impl Default for ModalConfig {
     fn default() -> Self {
-        Self {
-            js_config: JsModalConfig::new(),
-            on_show: Closure::new(|| true),
-            on_visible: Closure::new(|| true),
-            on_hide: Closure::new(|_| true),
-            on_hidden: Closure::new(|| true),
-            on_approve: Closure::new(|_| true),
-            on_deny: Closure::new(|_| true),
-        }
+        Self::from_js(JsModalConfig::new())
     }
 }
 
@@ -87,187 +287,358 @@ impl std::ops::Deref for ModalConfig {
 /// A modal.
 #[allow(unused)]
 pub struct Modal {
-    js_modal: JsModal,
+    js_modal: JQuery,
     modal_config: ModalConfig,
-    action_handler_list: Vec<Closure<dyn Fn() -> bool>>,
     alert_handler: Option<Closure<dyn Fn()>>,
     confirm_handler: Option<Closure<dyn Fn(bool)>>,
     prompt_handler: Option<Closure<dyn Fn(Option<String>)>>,
+    shortcut_listener: Option<EventListener>,
+    focus_trap: Option<FocusTrap>,
 }
 
 impl Default for Modal {
     fn default() -> Self {
-        let modal_config = ModalConfig::default();
+        Self::new(ModalConfig::default())
+    }
+}
+
+impl Modal {
+    /// Wraps the created modal element.
+    fn from_parts(js_modal: JQuery, modal_config: ModalConfig) -> Self {
         Self {
-            js_modal: new_modal(&modal_config),
+            js_modal,
             modal_config,
-            action_handler_list: vec![],
             alert_handler: None,
             confirm_handler: None,
             prompt_handler: None,
+            shortcut_listener: None,
+            focus_trap: None,
         }
     }
-}
 
-impl Modal {
-    /// Creates a new modal.
+    /// Creates a new modal with the given configuration, including its
+    /// title, content and actions.
+    pub fn try_new(
+        mut modal_config: ModalConfig,
+    ) -> Result<Self, FomanticError> {
+        ensure_module("modal")?;
+        let js_modal = invoke("modal", &[&modal_config])?;
+        // the configuration is only used for a single modal
+        let _ = modal_config.root.set(js_modal.clone());
+        let shortcuts = std::mem::take(&mut modal_config.shortcuts);
+        let mut modal = Self::from_parts(js_modal, modal_config);
+        modal.install_shortcuts(shortcuts);
+        Ok(modal)
+    }
+
+    /// Creates a new modal. Errors are thrown as JavaScript exception, see
+    /// [Modal::try_new].
     pub fn new(modal_config: ModalConfig) -> Self {
-        Self {
-            js_modal: new_modal(&modal_config),
-            modal_config,
-            ..Default::default()
-        }
+        Self::try_new(modal_config).unwrap_or_else(|e| e.throw())
+    }
+
+    /// Creates a new modal that is destroyed together with its handlers
+    /// when the returned guard is dropped, see [InstanceGuard].
+    pub fn try_new_guarded(
+        modal_config: ModalConfig,
+    ) -> Result<InstanceGuard<Self>, FomanticError> {
+        Self::try_new(modal_config).map(FomanticModule::into_guard)
+    }
+
+    /// Creates a new modal that is destroyed when the returned guard is
+    /// dropped. Errors are thrown as JavaScript exception, see
+    /// [Modal::try_new_guarded].
+    pub fn new_guarded(modal_config: ModalConfig) -> InstanceGuard<Self> {
+        Self::try_new_guarded(modal_config).unwrap_or_else(|e| e.throw())
     }
 
     /// Creates an `Alert` modal.
-    pub fn new_alert<H>(title: &str, content: &str, handler: H) -> Self
+    pub fn try_new_alert<H>(
+        title: &str,
+        content: &str,
+        handler: H,
+    ) -> Result<Self, FomanticError>
     where
         H: Fn() + 'static,
     {
+        ensure_module("modal")?;
         let handler = Closure::new(handler);
-        let js_modal = new_modal_alert("alert", title, content, &handler);
-        Self {
-            js_modal,
-            modal_config: ModalConfig::default(),
+        let js_modal = invoke(
+            "modal",
+            &[
+                &"alert".into(),
+                &title.into(),
+                &content.into(),
+                handler.as_ref(),
+            ],
+        )?;
+        Ok(Self {
             alert_handler: Some(handler),
-            ..Default::default()
-        }
+            ..Self::from_parts(js_modal, ModalConfig::default())
+        })
+    }
+
+    /// Creates an `Alert` modal. Errors are thrown as JavaScript exception,
+    /// see [Modal::try_new_alert].
+    pub fn new_alert<H>(title: &str, content: &str, handler: H) -> Self
+    where
+        H: Fn() + 'static,
+    {
+        Self::try_new_alert(title, content, handler)
+            .unwrap_or_else(|e| e.throw())
     }
 
     /// Creates a `Confirm` modal.
-    pub fn new_confirm<H>(title: &str, content: &str, handler: H) -> Self
+    pub fn try_new_confirm<H>(
+        title: &str,
+        content: &str,
+        handler: H,
+    ) -> Result<Self, FomanticError>
     where
         H: Fn(bool) + 'static,
     {
+        ensure_module("modal")?;
         let handler = Closure::new(handler);
-        let js_modal = new_modal_confirm("confirm", title, content, &handler);
-        Self {
-            js_modal,
-            modal_config: ModalConfig::default(),
+        let js_modal = invoke(
+            "modal",
+            &[
+                &"confirm".into(),
+                &title.into(),
+                &content.into(),
+                handler.as_ref(),
+            ],
+        )?;
+        Ok(Self {
             confirm_handler: Some(handler),
-            ..Default::default()
-        }
+            ..Self::from_parts(js_modal, ModalConfig::default())
+        })
+    }
+
+    /// Creates a `Confirm` modal. Errors are thrown as JavaScript exception,
+    /// see [Modal::try_new_confirm].
+    pub fn new_confirm<H>(title: &str, content: &str, handler: H) -> Self
+    where
+        H: Fn(bool) + 'static,
+    {
+        Self::try_new_confirm(title, content, handler)
+            .unwrap_or_else(|e| e.throw())
     }
 
     /// Creates a `Prompt` modal.
-    pub fn new_prompt<H: 'static>(
+    pub fn try_new_prompt<H>(
         title: &str,
         content: &str,
         handler: H,
-    ) -> Self
+    ) -> Result<Self, FomanticError>
     where
-        H: Fn(Option<String>),
+        H: Fn(Option<String>) + 'static,
     {
+        ensure_module("modal")?;
         let handler = Closure::new(handler);
-        let js_modal = new_modal_prompt("prompt", title, content, &handler);
-        Self {
-            js_modal,
-            modal_config: ModalConfig::default(),
+        let js_modal = invoke(
+            "modal",
+            &[
+                &"prompt".into(),
+                &title.into(),
+                &content.into(),
+                handler.as_ref(),
+            ],
+        )?;
+        Ok(Self {
             prompt_handler: Some(handler),
-            ..Default::default()
-        }
-    }
-
-    /// Sets the title of the modal.
-    pub fn with_title(self, title: &str) -> Self {
-        self.modal_config.set_title(title);
-        self
+            ..Self::from_parts(js_modal, ModalConfig::default())
+        })
     }
 
-    /// Sets the content of the modal.
-    pub fn with_content(self, value: &str) -> Self {
-        self.modal_config.set_content(value);
-        self
+    /// Creates a `Prompt` modal. Errors are thrown as JavaScript exception,
+    /// see [Modal::try_new_prompt].
+    pub fn new_prompt<H>(title: &str, content: &str, handler: H) -> Self
+    where
+        H: Fn(Option<String>) + 'static,
+    {
+        Self::try_new_prompt(title, content, handler)
+            .unwrap_or_else(|e| e.throw())
     }
 
-    /// Sets the title of the modal.
-    pub fn with_class(self, class: &str) -> Self {
-        self.modal_config.set_class(class);
+    /// Sets the accessibility attributes of the modal.
+    ///
+    /// The modal is marked with `aria-modal` and wired to its labelling and
+    /// describing elements, so screen readers announce it when it is shown.
+    pub fn with_aria(self, aria: ModalAria) -> Self {
+        self.js_modal.attr("role", &aria.role.to_string());
+        self.js_modal.attr("aria-modal", "true");
+        self.reference_element("aria-labelledby", aria.labelled_by, ".header");
+        self.reference_element(
+            "aria-describedby",
+            aria.described_by,
+            ".content",
+        );
         self
     }
 
-    /// Wether a close icon should be shown.
-    pub fn with_close_icon(self, value: bool) -> Self {
-        self.modal_config.set_close_icon(value);
+    /// Keeps the keyboard focus within the modal while it is active, see
+    /// [FocusTrap].
+    pub fn with_focus_trap(mut self) -> Self {
+        if let Some(element) = self.js_modal.element(0) {
+            let js_modal = self.js_modal.clone();
+            self.focus_trap =
+                Some(FocusTrap::while_active(&element, move || {
+                    js_modal.behavior_bool("modal", "is active")
+                }));
+        }
         self
     }
 
-    /// Sets the actions shown on the modal.
-    pub fn with_actions(mut self, actions: Vec<Action>) -> Self {
-        let mut js_actions = vec![];
-        for act in actions {
-            self.action_handler_list.push(act.click);
-            js_actions.push(act.js_config);
+    /// Points the given ARIA attribute to the element with the given id, or
+    /// to the modal child matching `fallback_selector` if no id is given.
+    fn reference_element(
+        &self,
+        attribute: &str,
+        id: Option<String>,
+        fallback_selector: &str,
+    ) {
+        let id = match id {
+            Some(id) => id,
+            None => {
+                let element = self.js_modal.find(fallback_selector);
+                if element.length() == 0 {
+                    return;
+                }
+                let id = format!(
+                    "fomantic-modal-aria-{}",
+                    ARIA_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+                );
+                element.attr("id", &id);
+                id
+            }
+        };
+        self.js_modal.attr(attribute, &id);
+    }
+
+    /// Listens for the shortcuts of the actions while the modal is active.
+    ///
+    /// Keys typed into editable elements are ignored. The listener is
+    /// removed when the modal is dropped.
+    fn install_shortcuts(&mut self, shortcuts: Vec<(usize, Key)>) {
+        if shortcuts.is_empty() {
+            self.shortcut_listener = None;
+            return;
         }
-        self.modal_config
-            .js_config
-            .set_actions(js_actions.into_boxed_slice());
-        self
+        let js_modal = self.js_modal.clone();
+        let listener = EventListener::new(
+            &gloo::utils::document(),
+            "keydown",
+            move |event| {
+                let Some(event) = shortcut_event(event) else {
+                    return;
+                };
+                let key = event.key();
+                let Some((idx, _)) =
+                    shortcuts.iter().find(|(_, k)| k.matches(&key))
+                else {
+                    return;
+                };
+                if !js_modal.behavior_bool("modal", "is active") {
+                    return;
+                }
+                let button =
+                    js_modal.find(".actions > .button").eq(*idx as i32);
+                if button.is_empty() || button.has_class("disabled") {
+                    return;
+                }
+                event.prevent_default();
+                button.trigger("click");
+            },
+        );
+        self.shortcut_listener = Some(listener);
     }
 
     /// Shows the modal.
     pub fn show(&self) {
-        self.js_modal.modal("show");
+        self.js_modal.behavior("modal", "show");
     }
 
     /// Hides the modal.
     pub fn hide(&self) {
-        self.js_modal.modal("hide");
+        self.js_modal.behavior("modal", "hide");
     }
 
     /// Toggles the modal.
     pub fn toggle(&self) {
-        self.js_modal.modal("toggle");
+        self.js_modal.behavior("modal", "toggle");
     }
 
     /// Refreshes centering of modal on page.
     pub fn refresh(&self) {
-        self.js_modal.modal("refresh");
+        self.js_modal.behavior("modal", "refresh");
     }
 
     /// Shows associated page dimmer.
     pub fn show_dimmer(&self) {
-        self.js_modal.modal("show dimmer");
+        self.js_modal.behavior("modal", "show dimmer");
     }
 
     /// Hides associated page dimmer.
     pub fn hide_dimmer(&self) {
-        self.js_modal.modal("hide dimmer");
+        self.js_modal.behavior("modal", "hide dimmer");
     }
 
     /// Hides all modals not selected modal in a dimmer.
     pub fn hide_others(&self) {
-        self.js_modal.modal("hide others");
+        self.js_modal.behavior("modal", "hide others");
     }
 
     /// Hides all visible modals in the same dimmer.
     pub fn hide_all(&self) {
-        self.js_modal.modal("hide all");
+        self.js_modal.behavior("modal", "hide all");
     }
 
     /// Caches current modal size.
     pub fn cache_sizes(&self) {
-        self.js_modal.modal("cache sizes");
+        self.js_modal.behavior("modal", "cache sizes");
     }
 
     /// Returns whether the modal can fit on the page.
     pub fn can_fit(&self) -> bool {
-        self.js_modal.modal_returns_bool("can fit")
+        self.js_modal.behavior_bool("modal", "can fit")
     }
 
     /// Returns whether the modal is active.
     pub fn is_active(&self) -> bool {
-        self.js_modal.modal_returns_bool("is active")
+        self.js_modal.behavior_bool("modal", "is active")
     }
 
     /// Sets modal to active.
     pub fn set_active(&self) {
-        self.js_modal.modal("set active");
+        self.js_modal.behavior("modal", "set active");
     }
 
     /// Destroys instance and removes all events.
+    ///
+    /// The handlers of the [ModalConfig] are dropped.
     pub fn destroy(&self) {
-        self.js_modal.modal("destroy");
+        self.js_modal.behavior("modal", "destroy");
+        self.modal_config.callbacks.clear();
+    }
+
+    /// Destroys instance and removes the modal element from the page.
+    pub fn remove(&self) {
+        self.destroy();
+        self.js_modal.remove();
+    }
+}
+
+impl FomanticModule for Modal {
+    fn module_name(&self) -> &'static str {
+        "modal"
+    }
+
+    fn callbacks(&self) -> Option<&CallbackRegistry> {
+        Some(&self.modal_config.callbacks)
+    }
+
+    fn element(&self) -> JQuery {
+        self.js_modal.clone()
     }
 }
 
@@ -346,7 +717,7 @@ extern "C" {
     ///
     /// Alternatively you can provide an object to set individual values for hide/show transitions as well as hide/show duration.
     ///
-    /// ```
+    /// ```js
     /// {
     ///     showMethod   : 'fade',
     ///     showDuration : 200,
@@ -366,46 +737,34 @@ extern "C" {
     pub fn set_queue(this: &JsModalConfig, value: bool);
 
     /// Is called when a modal starts to show. If the function returns false, the modal will not be shown.
-    #[wasm_bindgen(method, setter)]
-    pub(crate) fn set_on_show(
-        this: &JsModalConfig,
-        value: &Closure<dyn Fn() -> bool>,
-    );
+    #[wasm_bindgen(method, setter, js_name = "onShow")]
+    pub(crate) fn set_on_show(this: &JsModalConfig, value: &js_sys::Function);
 
     /// Is called after a modal has finished showing animating.
-    #[wasm_bindgen(method, setter)]
+    #[wasm_bindgen(method, setter, js_name = "onVisible")]
     pub(crate) fn set_on_visible(
         this: &JsModalConfig,
-        value: &Closure<dyn Fn() -> bool>,
+        value: &js_sys::Function,
     );
 
     /// Is called after a modal starts to hide. If the function returns false, the modal will not hide.
-    #[wasm_bindgen(method, setter)]
-    pub(crate) fn set_on_hide(
-        this: &JsModalConfig,
-        value: &Closure<dyn Fn(JsValue) -> bool>,
-    );
+    #[wasm_bindgen(method, setter, js_name = "onHide")]
+    pub(crate) fn set_on_hide(this: &JsModalConfig, value: &js_sys::Function);
 
     /// Is called after a modal has finished hiding animation.
-    #[wasm_bindgen(method, setter)]
-    pub(crate) fn set_on_hidden(
-        this: &JsModalConfig,
-        value: &Closure<dyn Fn() -> bool>,
-    );
+    #[wasm_bindgen(method, setter, js_name = "onHidden")]
+    pub(crate) fn set_on_hidden(this: &JsModalConfig, value: &js_sys::Function);
 
     /// Is called after a positive, approve or ok button is pressed. If the function returns false, the modal will not hide.
-    #[wasm_bindgen(method, setter)]
+    #[wasm_bindgen(method, setter, js_name = "onApprove")]
     pub(crate) fn set_on_approve(
         this: &JsModalConfig,
-        value: &Closure<dyn Fn(JsValue) -> bool>,
+        value: &js_sys::Function,
     );
 
     /// Is called after a negative, deny or cancel button is pressed. If the function returns false the modal will not hide.
-    #[wasm_bindgen(method, setter)]
-    pub(crate) fn set_on_deny(
-        this: &JsModalConfig,
-        value: &Closure<dyn Fn(JsValue) -> bool>,
-    );
+    #[wasm_bindgen(method, setter, js_name = "onDeny")]
+    pub(crate) fn set_on_deny(this: &JsModalConfig, value: &js_sys::Function);
 
     /// Used internally to determine if the webkit custom scrollbar was clicked to prevent hiding the dimmer. This should be set to the same (numeric) value as defined for @customScrollbarWidth in site.less in case you are using a different theme.
     #[wasm_bindgen(method, setter)]
@@ -433,241 +792,4 @@ extern "C" {
         this: &JsModalConfig,
         value: Box<[JsActionConfig]>,
     );
-
-    /// A modal.
-    pub(crate) type JsModal;
-
-    /// Internal function to create the modal on JavaScript side.
-    #[wasm_bindgen(js_namespace=["$"], js_name="modal")]
-    fn new_modal(props: &JsModalConfig) -> JsModal;
-
-    /// Internal function to create the modal alert template.
-    #[wasm_bindgen(js_namespace=["$"], js_name="modal")]
-    fn new_modal_alert(
-        props: &str,
-        title: &str,
-        content: &str,
-        handler: &Closure<dyn Fn()>,
-    ) -> JsModal;
-
-    /// Internal function to create the modal confirm template.
-    #[wasm_bindgen(js_namespace=["$"], js_name="modal")]
-    fn new_modal_confirm(
-        props: &str,
-        title: &str,
-        content: &str,
-        handler: &Closure<dyn Fn(bool)>,
-    ) -> JsModal;
-
-    /// Internal function to create the modal prompt template.
-    #[wasm_bindgen(js_namespace=["$"], js_name="modal")]
-    fn new_modal_prompt(
-        props: &str,
-        title: &str,
-        content: &str,
-        handler: &Closure<dyn Fn(Option<String>)>,
-    ) -> JsModal;
-
-    #[wasm_bindgen(method, js_name = "modal")]
-    pub fn modal(this: &JsModal, behavior: &str);
-
-    #[wasm_bindgen(method, js_name = "modal")]
-    pub fn modal_returns_bool(this: &JsModal, behavior: &str) -> bool;
-
-}
-
-/*
-/// [Modal] action configuration.
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ModalAction {
-    /// Text of the action.
-    pub text: String,
-    /// The class of the action element.
-    pub class: String,
-}
-
-/// [Modal] configuration options.
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ModalConfig {
-    /// Title of the modal.
-    pub title: String,
-    /// Class to be added to the modal.
-    pub class: String,
-    /// Whether a close icon should be shown.
-    pub close_icon: bool,
-    /// The content of the modal.
-    pub content: String,
-    /// Available actions.
-    pub actions: Vec<ModalAction>,
-}
-
-#[wasm_bindgen]
-extern "C" {
-    /// A modal.
-    pub type Modal;
-
-    /// Internal function to create the modal on JavaScript side.
-    #[wasm_bindgen(js_namespace=["$"], js_name="modal")]
-    fn new_modal(props: &JsValue) -> Modal;
-
-    /// Internal function to create the modal on JavaScript side by using a selector.
-    #[wasm_bindgen(js_name = "$", catch)]
-    fn new_modal_from_selector(selector: &JsValue) -> Result<Modal, JsValue>;
-
-    /// Internal function to create the modal alert template.
-    #[wasm_bindgen(js_namespace=["$"], js_name="modal")]
-    fn new_modal_alert(
-        props: &JsValue,
-        title: String,
-        content: String,
-        handler: &Closure<dyn Fn()>,
-    ) -> Modal;
-
-    /// Internal function to create the modal confirm template.
-    #[wasm_bindgen(js_namespace=["$"], js_name="modal")]
-    fn new_modal_confirm(
-        props: &JsValue,
-        title: String,
-        content: String,
-        handler: &Closure<dyn Fn(bool)>,
-    ) -> Modal;
-
-    /// Internal function to create the modal prompt template.
-    #[wasm_bindgen(js_namespace=["$"], js_name="modal")]
-    fn new_modal_prompt(
-        props: &JsValue,
-        title: String,
-        content: String,
-        handler: &Closure<dyn Fn(Option<String>)>,
-    ) -> Modal;
-
-    #[wasm_bindgen(method, js_name = "modal")]
-    pub fn modal(this: &Modal, behavior: &JsValue);
-
-    #[wasm_bindgen(method, js_name = "modal")]
-    pub fn modal_returns_bool(this: &Modal, behavior: &JsValue) -> bool;
-}
-
-impl Modal {
-    /// Creates a new modal.
-    pub fn new(config: &ModalConfig) -> anyhow::Result<Self> {
-        let modal_config_js =
-            <JsValue as JsValueSerdeExt>::from_serde(&config).map_err(|e| anyhow!(e))?;
-        Ok(new_modal(&modal_config_js))
-    }
-
-    /// Creates an `Alert` modal.
-    pub fn new_alert<H: 'static>(title: String, content: String, handler: H) -> anyhow::Result<Self>
-    where
-        H: Fn(),
-    {
-        let handler = Closure::new(handler);
-        let result = new_modal_alert(&JsValue::from("alert"), title, content, &handler);
-        handler.forget();
-        Ok(result)
-    }
-
-    /// Creates a `Confirm` modal.
-    pub fn new_confirm<H: 'static>(
-        title: String,
-        content: String,
-        handler: H,
-    ) -> anyhow::Result<Self>
-    where
-        H: Fn(bool),
-    {
-        let handler = Closure::new(handler);
-        let result = new_modal_confirm(&JsValue::from("confirm"), title, content, &handler);
-        handler.forget();
-        Ok(result)
-    }
-
-    /// Creates a `Prompt` modal.
-    pub fn new_prompt<H: 'static>(
-        title: String,
-        content: String,
-        handler: H,
-    ) -> anyhow::Result<Self>
-    where
-        H: Fn(Option<String>),
-    {
-        let handler = Closure::new(handler);
-        let result = new_modal_prompt(&JsValue::from("prompt"), title, content, &handler);
-        handler.forget();
-        Ok(result)
-    }
-
-    /// Queries the modal by the given selector.
-    pub fn query_from_selector(selector: &str) -> anyhow::Result<Self> {
-        Ok(new_modal_from_selector(&JsValue::from(selector))
-            .map_err(|e| anyhow!(format!("{e:#?}")))?)
-    }
-
-    /// Shows the modal.
-    pub fn show(&self) {
-        self.modal(&JsValue::from("show"));
-    }
-
-    /// Hides the modal.
-    pub fn hide(&self) {
-        self.modal(&JsValue::from("hide"));
-    }
-
-    /// Toggles the modal.
-    pub fn toggle(&self) {
-        self.modal(&JsValue::from("toggle"));
-    }
-
-    /// Refreshes centering of modal on page.
-    pub fn refresh(&self) {
-        self.modal(&JsValue::from("refresh"));
-    }
-
-    /// Shows associated page dimmer.
-    pub fn show_dimmer(&self) {
-        self.modal(&JsValue::from("show dimmer"));
-    }
-
-    /// Hides associated page dimmer.
-    pub fn hide_dimmer(&self) {
-        self.modal(&JsValue::from("hide dimmer"));
-    }
-
-    /// Hides all modals not selected modal in a dimmer.
-    pub fn hide_others(&self) {
-        self.modal(&JsValue::from("hide others"));
-    }
-
-    /// Hides all visible modals in the same dimmer.
-    pub fn hide_all(&self) {
-        self.modal(&JsValue::from("hide all"));
-    }
-
-    /// Caches current modal size.
-    pub fn cache_sizes(&self) {
-        self.modal(&JsValue::from("cache sizes"));
-    }
-
-    /// Returns whether the modal can fit on the page.
-    pub fn can_fit(&self) -> bool {
-        self.modal_returns_bool(&JsValue::from("can fit"))
-    }
-
-    /// Returns whether the modal is active.
-    pub fn is_active(&self) -> bool {
-        self.modal_returns_bool(&JsValue::from("is active"))
-    }
-
-    /// Sets modal to active.
-    pub fn set_active(&self) {
-        self.modal(&JsValue::from("set active"));
-    }
-
-    /// Destroys instance and removes all events.
-    pub fn destroy(&self) {
-        self.modal(&JsValue::from("destroy"));
-    }
 }
-*/
@@ -0,0 +1,492 @@
+//! Plain Rust settings of the `fomantic-ui` modules.
+//!
+//! Unlike the configurations built on JavaScript objects, eg.
+//! [ModalConfig](super::modal::ModalConfig), the settings are ordinary
+//! structs that can be cloned, compared, printed and created outside of the
+//! main thread. They are converted into the JavaScript settings with
+//! `serde_wasm_bindgen` when the module is initialized. Handlers can not be
+//! serialized, they are set on the configuration created from the settings.
+//!
+//! ```no_run
+//! # #[cfg(feature = "modal")]
+//! # {
+//! use fomantic_ui::{
+//!     modules::{
+//!         modal::{
+//!             Modal,
+//!             ModalConfig,
+//!         },
+//!         settings::{
+//!             ActionSettings,
+//!             ModalSettings,
+//!         },
+//!     },
+//!     style::TransitionName,
+//! };
+//!
+//! let settings = ModalSettings {
+//!     title: Some("Delete file".into()),
+//!     content: Some("The file can not be restored.".into()),
+//!     transition: Some(TransitionName::Scale),
+//!     actions: vec![
+//!         ActionSettings::new("Cancel").with_class("negative"),
+//!         ActionSettings::new("Delete").with_class("positive"),
+//!     ],
+//!     ..Default::default()
+//! };
+//! let mut config = ModalConfig::try_from_settings(&settings).unwrap();
+//! config.set_on_approve(|_| true);
+//! Modal::new(config).show();
+//! # }
+//! ```
+#[cfg(feature = "toast")]
+use crate::modules::toast::{
+    ToastDisplayTime,
+    ToastPosition,
+    ToastProgressBarPosition,
+};
+use crate::{
+    environment::ensure_module,
+    jq::JQuery,
+    style::{
+        IconName,
+        TransitionName,
+    },
+    FomanticError,
+};
+use serde::{
+    Serialize,
+    Serializer,
+};
+use wasm_bindgen::JsValue;
+
+/// Settings of a `fomantic-ui` module that are serialized into its
+/// JavaScript settings.
+pub trait ModuleSettings: Serialize {
+    /// Name of the jQuery plugin of the module, eg. `dropdown`.
+    const MODULE: &'static str;
+
+    /// Converts the settings into a plain JavaScript object.
+    fn to_js(&self) -> Result<JsValue, FomanticError> {
+        self.serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| {
+                FomanticError::Js(format!(
+                    "Could not serialize the {} settings: {e}",
+                    Self::MODULE
+                ))
+            })
+    }
+
+    /// Initializes the module on the given elements with the settings.
+    fn try_init(&self, element: &JQuery) -> Result<(), FomanticError> {
+        #[cfg(feature = "mock")]
+        if let Some(selector) = element.mock_selector() {
+            crate::mock::record(crate::mock::MockCall::Init {
+                selector: selector.to_string(),
+                module: Self::MODULE.to_string(),
+                settings: serde_json::to_value(self).ok(),
+            });
+            return Ok(());
+        }
+        ensure_module(Self::MODULE)?;
+        element.try_init(Self::MODULE, &self.to_js()?)
+    }
+
+    /// Initializes the module on the given elements with the settings.
+    /// Errors are thrown as JavaScript exception.
+    fn init(&self, element: &JQuery) {
+        self.try_init(element).unwrap_or_else(|e| e.throw())
+    }
+}
+
+impl Serialize for TransitionName {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for IconName {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "toast")]
+impl Serialize for ToastPosition {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "toast")]
+impl Serialize for ToastProgressBarPosition {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "toast")]
+impl Serialize for ToastDisplayTime {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Time(time) => serializer.serialize_u32(*time),
+            Self::UntilClicked => serializer.serialize_u32(0),
+            Self::BasedOnWordAmount => serializer.serialize_str("auto"),
+        }
+    }
+}
+
+/// An action button of a modal or toast.
+///
+/// Clicking an action of a modal approves or denies it, depending on its
+/// class, eg. `positive` or `negative`.
+#[cfg(any(feature = "modal", feature = "toast"))]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+pub struct ActionSettings {
+    /// Text of the button.
+    pub text: String,
+    /// Classes of the button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Icon shown on the button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<IconName>,
+}
+
+#[cfg(any(feature = "modal", feature = "toast"))]
+impl ActionSettings {
+    /// Creates an action with the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the classes of the button.
+    pub fn with_class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Sets the icon of the button.
+    pub fn with_icon(mut self, icon: impl Into<IconName>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+}
+
+/// Settings of a modal, see
+/// [ModalConfig::try_from_settings](super::modal::ModalConfig::try_from_settings).
+#[cfg(feature = "modal")]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModalSettings {
+    /// Title of the modal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Content of the modal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Classes added to the modal, eg. `small`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Whether a close icon is shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_icon: Option<bool>,
+    /// Action buttons shown at the bottom of the modal.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<ActionSettings>,
+    /// Whether the modal can be closed by clicking on the dimmer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closable: Option<bool>,
+    /// Whether other visible modals stay open when the modal is shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_multiple: Option<bool>,
+    /// Whether the modal is closed when the ESC key is pressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyboard_shortcuts: Option<bool>,
+    /// Whether the first input of the modal receives the focus.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autofocus: Option<bool>,
+    /// Whether the previously focused element is focused again on hide.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restore_focus: Option<bool>,
+    /// Whether an inverted dimmer is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inverted: Option<bool>,
+    /// Whether the dimmer blurs the background.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blurring: Option<bool>,
+    /// Whether the modal is vertically centered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub centered: Option<bool>,
+    /// Animation used to show and hide the modal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transition: Option<TransitionName>,
+    /// Duration of the animation in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+    /// Texts of the buttons of the alert, confirm and prompt modals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<ModalText>,
+}
+
+/// Texts of the buttons of the modal templates.
+#[cfg(feature = "modal")]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModalText {
+    /// Text of the ok button, eg. of an alert.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok: Option<String>,
+    /// Text of the cancel button, eg. of a confirm.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel: Option<String>,
+    /// Title of the close icon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<String>,
+}
+
+#[cfg(feature = "modal")]
+impl ModuleSettings for ModalSettings {
+    const MODULE: &'static str = "modal";
+}
+
+/// The animations used to show and hide a toast.
+#[cfg(feature = "toast")]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToastTransitionSettings {
+    /// Animation used to show the toast.
+    pub show_method: TransitionName,
+    /// Animation used to hide the toast.
+    pub hide_method: TransitionName,
+}
+
+/// Settings of a toast, see
+/// [ToastConfig::try_from_settings](super::toast::ToastConfig::try_from_settings).
+#[cfg(feature = "toast")]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToastSettings {
+    /// Title of the toast.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Message of the toast.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Classes added to the toast, eg. `success`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Icon shown in front of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_icon: Option<IconName>,
+    /// Position of the toast on the page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<ToastPosition>,
+    /// How long the toast is visible.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_time: Option<ToastDisplayTime>,
+    /// Whether the newest toast is shown on top.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub newest_on_top: Option<bool>,
+    /// Whether the toasts are stacked horizontally.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal: Option<bool>,
+    /// Whether a close icon is shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_icon: Option<bool>,
+    /// Where the progress bar is shown, none is shown if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_progress: Option<ToastProgressBarPosition>,
+    /// Whether the progress bar is raised instead of lowered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_up: Option<bool>,
+    /// Animations used to show and hide the toast.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transition: Option<ToastTransitionSettings>,
+    /// Action buttons of the toast.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<ActionSettings>,
+}
+
+#[cfg(feature = "toast")]
+impl ModuleSettings for ToastSettings {
+    const MODULE: &'static str = "toast";
+}
+
+/// Settings of a dropdown.
+#[cfg(feature = "dropdown")]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DropdownSettings {
+    /// Event that opens the dropdown, eg. `click` or `hover`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on: Option<String>,
+    /// Whether the user can add values that are not in the menu.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_additions: Option<bool>,
+    /// Whether the selection can be cleared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearable: Option<bool>,
+    /// Whether the search matches anywhere in the text, not only at the
+    /// beginning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_text_search: Option<bool>,
+    /// Maximum amount of selections of a multiple dropdown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selections: Option<u32>,
+    /// Text shown if nothing is selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    /// Direction the menu opens to, `auto`, `upward` or `downward`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<String>,
+    /// What happens when an item is chosen, eg. `activate` or `hide`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// Animation used to show and hide the menu.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transition: Option<TransitionName>,
+    /// Duration of the animation in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+    /// Messages shown in the menu.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<DropdownMessage>,
+}
+
+/// Messages shown in the menu of a dropdown.
+///
+/// `{term}`, `{count}` and `{maxCount}` are replaced by `fomantic-ui`.
+#[cfg(feature = "dropdown")]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DropdownMessage {
+    /// Shown for a value that can be added, eg. `Add <b>{term}</b>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_result: Option<String>,
+    /// Shown for the amount of selections, eg. `{count} selected`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<String>,
+    /// Shown if no more values can be selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selections: Option<String>,
+    /// Shown if the search has no results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_results: Option<String>,
+    /// Shown if the remote values could not be loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_error: Option<String>,
+}
+
+#[cfg(feature = "dropdown")]
+impl ModuleSettings for DropdownSettings {
+    const MODULE: &'static str = "dropdown";
+}
+
+/// Settings of a popup.
+#[cfg(feature = "popup")]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PopupSettings {
+    /// Text content of the popup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Header of the popup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Position of the popup, eg. `top left`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    /// Event that shows the popup, eg. `hover`, `click` or `focus`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on: Option<String>,
+    /// Whether the popup is inserted next to the target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline: Option<bool>,
+    /// Whether the popup stays open while it is hovered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hoverable: Option<bool>,
+    /// Classes added to the popup, eg. `inverted`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variation: Option<String>,
+    /// Offset from the target in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    /// Animation used to show and hide the popup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transition: Option<TransitionName>,
+    /// Duration of the animation in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+}
+
+#[cfg(feature = "popup")]
+impl ModuleSettings for PopupSettings {
+    const MODULE: &'static str = "popup";
+}
+
+/// Settings of a checkbox.
+#[cfg(feature = "checkbox")]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckboxSettings {
+    /// Whether a checked radio button can be unchecked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncheckable: Option<bool>,
+    /// Whether the callbacks are called on initialization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fire_on_init: Option<bool>,
+    /// Whether the checkbox is toggled with the enter key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_enter_key: Option<bool>,
+}
+
+#[cfg(feature = "checkbox")]
+impl ModuleSettings for CheckboxSettings {
+    const MODULE: &'static str = "checkbox";
+}
+
+/// Settings of a transition.
+#[cfg(feature = "transition")]
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitionSettings {
+    /// The animation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<TransitionName>,
+    /// Duration of the animation in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+    /// Delay between the animations of a group of elements in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<u32>,
+    /// Whether a group of elements is animated in reverse order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse: Option<bool>,
+    /// Whether the animation is queued after the running ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<bool>,
+}
+
+#[cfg(feature = "transition")]
+impl ModuleSettings for TransitionSettings {
+    const MODULE: &'static str = "transition";
+}
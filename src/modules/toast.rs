@@ -1,11 +1,50 @@
 //! Toast bindings.
+#[cfg(feature = "models")]
+use crate::modules::settings::{
+    ModuleSettings,
+    ToastSettings,
+};
 use crate::{
     action::JsActionConfig,
+    callbacks::CallbackRegistry,
+    environment::ensure_module,
+    events::EventStream,
+    jq::{
+        invoke,
+        JQuery,
+    },
+    key::shortcut_event,
+    modules::{
+        FomanticModule,
+        InstanceGuard,
+    },
+    style::{
+        Classes,
+        IconName,
+        Mirror,
+        TransitionName,
+    },
     Action,
+    FomanticError,
+    Key,
+};
+use gloo::events::EventListener;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
 };
 use wasm_bindgen::prelude::*;
 
+/// Counter used to generate unique classes for the buttons of actions with
+/// a shortcut.
+static SHORTCUT_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
 /// Display time of the [Toast].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ToastDisplayTime {
     /// The toast will be visible for the specified amount of time in milliseconds.
     Time(u32),
@@ -26,7 +65,7 @@ impl std::fmt::Display for ToastDisplayTime {
 }
 
 /// Location of the progress bar in a toast.
-#[derive(Default)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ToastProgressBarPosition {
     /// Show it at the bottom of the toast.
     #[default]
@@ -56,7 +95,7 @@ pub struct ToastProgressBar {
 }
 
 /// Location of the toast.
-#[derive(Default)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ToastPosition {
     /// Show it at the bottom right of the viewport.
     #[default]
@@ -86,11 +125,238 @@ impl std::fmt::Display for ToastPosition {
     }
 }
 
+impl Mirror for ToastPosition {
+    fn mirrored(self) -> Self {
+        match self {
+            Self::BottomRight => Self::BottomLeft,
+            Self::BottomLeft => Self::BottomRight,
+            Self::TopRight => Self::TopLeft,
+            Self::TopLeft => Self::TopRight,
+            other => other,
+        }
+    }
+}
+
+/// Politeness setting of the live region that announces toasts to assistive technologies.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum AriaLive {
+    /// Toasts are not announced.
+    Off,
+    /// Toasts are announced when the user is idle.
+    #[default]
+    Polite,
+    /// Toasts are announced immediately, interrupting the user.
+    Assertive,
+}
+
+impl AriaLive {
+    /// The ARIA role matching the politeness setting.
+    pub fn role(&self) -> Option<&'static str> {
+        match self {
+            Self::Off => None,
+            Self::Polite => Some("status"),
+            Self::Assertive => Some("alert"),
+        }
+    }
+
+    /// Marks the elements of the selection as live region.
+    pub fn apply(&self, selection: &JQuery) {
+        selection.attr("aria-live", &self.to_string());
+        selection.attr("aria-atomic", "false");
+        if let Some(role) = self.role() {
+            selection.attr("role", role);
+        }
+    }
+}
+
+impl std::fmt::Display for AriaLive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::Polite => write!(f, "polite"),
+            Self::Assertive => write!(f, "assertive"),
+        }
+    }
+}
+
+/// The classes `fomantic-ui` gives toast containers, which it also looks
+/// them up by.
+const CONTAINER_CLASS: &str = "ui toast-container";
+
+/// Returns the classes of a toast container with the given additional class.
+fn container_classes(class: Option<&str>) -> String {
+    match class {
+        Some(class) => format!("{CONTAINER_CLASS} {class}"),
+        None => CONTAINER_CLASS.to_string(),
+    }
+}
+
+/// Turns the given class names into a selector, eg. `.ui.toast-container`.
+fn class_selector(classes: &str) -> String {
+    classes
+        .split_whitespace()
+        .map(|class| format!(".{class}"))
+        .collect()
+}
+
+/// Builds the selector of the toast container with the given additional
+/// class at the given position.
+fn container_selector(
+    context: Option<&str>,
+    class: Option<&str>,
+    position: ToastPosition,
+) -> String {
+    let context = context.unwrap_or("body");
+    let classes = container_classes(class);
+    format!(
+        "{context} > {}{}",
+        class_selector(&classes),
+        class_selector(&position.to_string())
+    )
+}
+
+/// A region on the page that hosts toasts.
+///
+/// Every region gets its own container element, so toasts shown in different
+/// regions (eg. top right alerts and bottom left chat notifications) stack
+/// independently from each other.
+#[derive(Clone)]
+pub struct ToastRegion {
+    position: ToastPosition,
+    context: Option<String>,
+    container_class: Option<String>,
+    newest_on_top: bool,
+    horizontal: bool,
+    aria_live: Option<AriaLive>,
+}
+
+impl ToastRegion {
+    /// Creates a new region at the given position.
+    pub fn new(position: ToastPosition) -> Self {
+        Self {
+            position,
+            context: None,
+            container_class: None,
+            newest_on_top: false,
+            horizontal: false,
+            aria_live: None,
+        }
+    }
+
+    /// Selector of the element the toast container is appended to. Defaults to `body`.
+    pub fn context(mut self, selector: &str) -> Self {
+        self.context = Some(selector.to_string());
+        self
+    }
+
+    /// CSS class added to the container element that holds the toasts, eg.
+    /// to style it.
+    ///
+    /// Regions with different classes at the same position get their own
+    /// containers.
+    pub fn container_class(mut self, class: &str) -> Self {
+        self.container_class = Some(class.to_string());
+        self
+    }
+
+    /// Wether the newest toast should be displayed on top.
+    pub fn newest_on_top(mut self, is_on_top: bool) -> Self {
+        self.newest_on_top = is_on_top;
+        self
+    }
+
+    /// Wether the toasts should be stacked horizontal.
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// How toasts shown in this region are announced by screen readers.
+    pub fn aria_live(mut self, aria_live: AriaLive) -> Self {
+        self.aria_live = Some(aria_live);
+        self
+    }
+
+    /// Returns the position of the region.
+    pub fn get_position(&self) -> ToastPosition {
+        self.position
+    }
+
+    /// Selects the container element of the region. It exists once a toast
+    /// has been shown in the region.
+    pub fn container(&self) -> JQuery {
+        JQuery::select(&container_selector(
+            self.context.as_deref(),
+            self.container_class.as_deref(),
+            self.position,
+        ))
+    }
+
+    /// Shows a toast with the given configuration inside this region.
+    ///
+    /// The configuration is kept until the toast is removed, see
+    /// [Toast::show].
+    pub fn show(&self, config: ToastConfig) -> Toast {
+        Toast::show(config.region(self))
+    }
+
+    fn apply(&self, config: &mut ToastConfig) {
+        let js_config = &config.js_config;
+        js_config.set_position(&self.position.to_string());
+        js_config.set_newest_on_top(self.newest_on_top);
+        js_config.set_horizontal(self.horizontal);
+        if let Some(ref context) = self.context {
+            js_config.set_context(context);
+        }
+        if let Some(ref class) = self.container_class {
+            // fomantic-ui finds the container of a toast by its selector, so
+            // it has to match the classes of the container
+            let classes = container_classes(Some(class));
+            let class_names = JsToastClassNames::new();
+            class_names.set_container(&classes);
+            js_config.set_class_name(&class_names);
+            let selectors = JsToastSelectors::new();
+            selectors.set_container(&class_selector(&classes));
+            js_config.set_selector(&selectors);
+        }
+        config.position = self.position;
+        config.context.clone_from(&self.context);
+        config.container_class.clone_from(&self.container_class);
+        if self.aria_live.is_some() {
+            config.aria_live = self.aria_live;
+        }
+    }
+}
+
+/// An event of a [Toast], see [ToastConfig::events].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ToastEvent {
+    /// The toast starts to show.
+    Show,
+    /// The toast has finished its show animation.
+    Visible,
+    /// The toast has been clicked.
+    Click,
+    /// The toast starts to hide.
+    Hide,
+    /// The toast has finished its hide animation.
+    Hidden,
+}
+
+/// Sets the handler of a [ToastEvent] on the JavaScript configuration.
+type EventSetter = fn(&JsToastConfig, &js_sys::Function);
+
 /// Configuration for a [Toast] module.
 pub struct ToastConfig {
     #[allow(unused)]
     handler: Closure<dyn Fn()>,
-    action_handler_list: Vec<Closure<dyn Fn() -> bool>>,
+    action_handler_list: Vec<Closure<dyn FnMut() -> bool>>,
+    shortcut_listener: Option<EventListener>,
+    callbacks: CallbackRegistry,
+    position: ToastPosition,
+    context: Option<String>,
+    container_class: Option<String>,
+    aria_live: Option<AriaLive>,
     pub(crate) js_config: JsToastConfig,
 }
 
@@ -103,9 +369,29 @@ impl ToastConfig {
             js_config,
             handler,
             action_handler_list: vec![],
+            shortcut_listener: None,
+            callbacks: CallbackRegistry::new(),
+            position: ToastPosition::default(),
+            context: None,
+            container_class: None,
+            aria_live: None,
         }
     }
 
+    /// Creates a configuration from the given settings.
+    ///
+    /// Handlers can be set on the returned configuration.
+    #[cfg(feature = "models")]
+    pub fn try_from_settings(
+        settings: &ToastSettings,
+    ) -> Result<Self, FomanticError> {
+        Ok(Self {
+            js_config: settings.to_js()?.unchecked_into(),
+            position: settings.position.unwrap_or_default(),
+            ..Self::new()
+        })
+    }
+
     /// Sets the message of the toast.
     pub fn with_message(self, message: &str) -> Self {
         self.js_config.set_message(message);
@@ -118,6 +404,53 @@ impl ToastConfig {
         self
     }
 
+    /// Shows the given icon next to the message.
+    pub fn with_icon(self, icon: impl Into<IconName>) -> Self {
+        self.js_config.set_show_icon(icon.into().name());
+        self
+    }
+
+    /// Returns a stream of the events of the toast.
+    ///
+    /// Like the handlers of the actions, the forwarding handlers are owned by
+    /// the configuration.
+    pub fn events(&mut self) -> EventStream<ToastEvent> {
+        let (sender, stream) = EventStream::channel();
+        let setters: [(ToastEvent, &str, EventSetter); 5] = [
+            (ToastEvent::Show, "onShow", JsToastConfig::set_on_show),
+            (
+                ToastEvent::Visible,
+                "onVisible",
+                JsToastConfig::set_on_visible,
+            ),
+            (ToastEvent::Click, "onClick", JsToastConfig::set_on_click),
+            (ToastEvent::Hide, "onHide", JsToastConfig::set_on_hide),
+            (ToastEvent::Hidden, "onHidden", JsToastConfig::set_on_hidden),
+        ];
+        for (event, name, set) in setters {
+            let sender = sender.clone();
+            let function = self.callbacks.set(name, move |_| {
+                sender.send(event);
+                JsValue::TRUE
+            });
+            set(&self.js_config, &function);
+        }
+        stream
+    }
+
+    /// Sets the animations used to show and hide the toast.
+    pub fn with_transition(
+        self,
+        show: TransitionName,
+        hide: TransitionName,
+    ) -> Self {
+        let transition = JsToastTransition::new();
+        transition.set_show_method(&show.to_string());
+        transition.set_hide_method(&hide.to_string());
+        self.js_config.set_transition(&transition);
+        self
+    }
+
     /// Adds a progress bar to the toast.
     pub fn with_progress_bar(self, progress_bar: ToastProgressBar) -> Self {
         self.js_config
@@ -129,15 +462,32 @@ impl ToastConfig {
         self
     }
 
-    /// Sets the title of the toast.
-    pub fn with_class(self, class: &str) -> Self {
-        self.js_config.set_class(class);
+    /// Sets the class of the toast.
+    pub fn with_class(self, class: impl Into<Classes>) -> Self {
+        self.js_config.set_class(&class.into().to_string());
         self
     }
 
     /// Sets the position of the toast.
-    pub fn position(self, position: ToastPosition) -> Self {
+    pub fn position(mut self, position: ToastPosition) -> Self {
         self.js_config.set_position(&position.to_string());
+        self.position = position;
+        self
+    }
+
+    /// How the toast is announced by screen readers.
+    ///
+    /// The toast container is turned into a live region with the matching
+    /// `role` and `aria-live` attributes.
+    pub fn aria_live(mut self, aria_live: AriaLive) -> Self {
+        self.aria_live = Some(aria_live);
+        self
+    }
+
+    /// Places the toast in the given region. This overrides the position and
+    /// stacking settings of the configuration.
+    pub fn region(mut self, region: &ToastRegion) -> Self {
+        region.apply(&mut self);
         self
     }
 
@@ -162,13 +512,64 @@ impl ToastConfig {
     /// Sets the actions shown on the toast.
     pub fn with_actions(mut self, actions: Vec<Action>) -> Self {
         let mut js_actions = vec![];
-        for act in actions {
+        let mut shortcuts = vec![];
+        for mut act in actions {
+            if let Some(key) = act.shortcut {
+                let class = format!(
+                    "fomantic-toast-action-{}",
+                    SHORTCUT_ID_COUNTER.fetch_add(1, Ordering::Relaxed)
+                );
+                act.add_class(&class);
+                shortcuts.push((class, key));
+            }
             self.action_handler_list.push(act.click);
             js_actions.push(act.js_config);
         }
         self.js_config.set_actions(js_actions.into_boxed_slice());
+        self.install_shortcuts(shortcuts);
         self
     }
+
+    /// Listens for the shortcuts of the actions while the toast is shown.
+    ///
+    /// The buttons are looked up by their class, so a shortcut only clicks
+    /// its action while the toast is on the page. Keys typed into editable
+    /// elements are ignored. The listener is removed when the configuration
+    /// is dropped.
+    fn install_shortcuts(&mut self, shortcuts: Vec<(String, Key)>) {
+        if shortcuts.is_empty() {
+            self.shortcut_listener = None;
+            return;
+        }
+        let listener = EventListener::new(
+            &gloo::utils::document(),
+            "keydown",
+            move |event| {
+                let Some(event) = shortcut_event(event) else {
+                    return;
+                };
+                let key = event.key();
+                let Some((class, _)) =
+                    shortcuts.iter().find(|(_, k)| k.matches(&key))
+                else {
+                    return;
+                };
+                let button = JQuery::select(&format!(".{class}"));
+                if button.is_empty() || button.has_class("disabled") {
+                    return;
+                }
+                event.prevent_default();
+                button.eq(0).trigger("click");
+            },
+        );
+        self.shortcut_listener = Some(listener);
+    }
+}
+
+impl Default for ToastConfig {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[wasm_bindgen]
@@ -201,6 +602,24 @@ extern "C" {
     #[wasm_bindgen(method, setter, js_name = "newestOnTop")]
     pub(crate) fn set_newest_on_top(this: &JsToastConfig, is_on_top: bool);
 
+    /// Set the selector of the element the toast container is appended to.
+    #[wasm_bindgen(method, setter, js_name = "context")]
+    pub(crate) fn set_context(this: &JsToastConfig, context: &str);
+
+    /// Set the class names used by the toast.
+    #[wasm_bindgen(method, setter, js_name = "className")]
+    pub(crate) fn set_class_name(
+        this: &JsToastConfig,
+        class_names: &JsToastClassNames,
+    );
+
+    /// Set the selectors used to find the elements of the toast.
+    #[wasm_bindgen(method, setter, js_name = "selector")]
+    pub(crate) fn set_selector(
+        this: &JsToastConfig,
+        selectors: &JsToastSelectors,
+    );
+
     /// Set wether the toasts should stack horizontal.
     #[wasm_bindgen(method, setter, js_name = "horizontal")]
     pub(crate) fn set_horizontal(this: &JsToastConfig, horizontal: bool);
@@ -209,6 +628,53 @@ extern "C" {
     #[wasm_bindgen(method, setter, js_name = "displayTime")]
     pub(crate) fn set_display_time(this: &JsToastConfig, display_time: &str);
 
+    /// Set the icon shown next to the message.
+    #[wasm_bindgen(method, setter, js_name = "showIcon")]
+    pub(crate) fn set_show_icon(this: &JsToastConfig, icon: &str);
+
+    /// Set the handler called when the toast starts to show.
+    #[wasm_bindgen(method, setter, js_name = "onShow")]
+    pub(crate) fn set_on_show(this: &JsToastConfig, handler: &js_sys::Function);
+
+    /// Set the handler called when the toast has finished showing.
+    #[wasm_bindgen(method, setter, js_name = "onVisible")]
+    pub(crate) fn set_on_visible(
+        this: &JsToastConfig,
+        handler: &js_sys::Function,
+    );
+
+    /// Set the handler called when the toast is clicked.
+    #[wasm_bindgen(method, setter, js_name = "onClick")]
+    pub(crate) fn set_on_click(
+        this: &JsToastConfig,
+        handler: &js_sys::Function,
+    );
+
+    /// Set the handler called when the toast starts to hide.
+    #[wasm_bindgen(method, setter, js_name = "onHide")]
+    pub(crate) fn set_on_hide(this: &JsToastConfig, handler: &js_sys::Function);
+
+    /// Set the handler called when the toast has finished hiding.
+    #[wasm_bindgen(method, setter, js_name = "onHidden")]
+    pub(crate) fn set_on_hidden(
+        this: &JsToastConfig,
+        handler: &js_sys::Function,
+    );
+
+    /// Set the handler called before the toast is removed from the page.
+    #[wasm_bindgen(method, setter, js_name = "onRemove")]
+    pub(crate) fn set_on_remove(
+        this: &JsToastConfig,
+        handler: &js_sys::Function,
+    );
+
+    /// Set the animations of the toast.
+    #[wasm_bindgen(method, setter, js_name = "transition")]
+    pub(crate) fn set_transition(
+        this: &JsToastConfig,
+        transition: &JsToastTransition,
+    );
+
     /// Set the progress bar position.
     #[wasm_bindgen(method, setter, js_name = "showProgress")]
     pub(crate) fn set_progress_bar_position(
@@ -242,25 +708,134 @@ extern "C" {
         handler: &Closure<dyn Fn()>,
     );
 
-    /// A toast.
-    pub type Toast;
+    /// The animations of a toast.
+    #[wasm_bindgen(js_name = Object)]
+    pub(crate) type JsToastTransition;
+
+    /// Transition object constructor.
+    #[wasm_bindgen(constructor, js_class = Object)]
+    pub(crate) fn new() -> JsToastTransition;
 
-    /// Internal function to create the toast on JavaScript side.
-    #[wasm_bindgen(js_namespace=["$"], js_name="toast")]
-    fn new_toast(config: &JsToastConfig) -> Toast;
+    /// Set the animation used to show the toast.
+    #[wasm_bindgen(method, setter, js_name = "showMethod")]
+    pub(crate) fn set_show_method(this: &JsToastTransition, method: &str);
+
+    /// Set the animation used to hide the toast.
+    #[wasm_bindgen(method, setter, js_name = "hideMethod")]
+    pub(crate) fn set_hide_method(this: &JsToastTransition, method: &str);
+
+    /// The JavaScript class name object of a toast.
+    #[wasm_bindgen(js_name = Object)]
+    pub(crate) type JsToastClassNames;
+
+    /// Class name object constructor.
+    #[wasm_bindgen(constructor, js_class = Object)]
+    pub(crate) fn new() -> JsToastClassNames;
+
+    /// Set the class of the container holding the toasts.
+    #[wasm_bindgen(method, setter, js_name = "container")]
+    pub(crate) fn set_container(this: &JsToastClassNames, class: &str);
+
+    /// The JavaScript selector object of a toast.
+    #[wasm_bindgen(js_name = Object)]
+    pub(crate) type JsToastSelectors;
+
+    /// Selector object constructor.
+    #[wasm_bindgen(constructor, js_class = Object)]
+    pub(crate) fn new() -> JsToastSelectors;
+
+    /// Set the selector of the container holding the toasts.
+    #[wasm_bindgen(method, setter, js_name = "container")]
+    pub(crate) fn set_container(this: &JsToastSelectors, selector: &str);
+}
+
+/// A toast.
+#[derive(Clone, Debug)]
+pub struct Toast {
+    element: JQuery,
 }
 
 impl Toast {
     /// Creates a new [Toast].
+    ///
+    /// The configuration owns the handlers of the toast, so it has to be
+    /// kept until the toast is removed, see [Toast::try_show].
+    pub fn try_new(config: &ToastConfig) -> Result<Self, FomanticError> {
+        let toast = Self::create(&config.js_config)?;
+        if let Some(aria_live) = config.aria_live {
+            aria_live.apply(&JQuery::select(&container_selector(
+                config.context.as_deref(),
+                config.container_class.as_deref(),
+                config.position,
+            )));
+        }
+        Ok(toast)
+    }
+
+    /// Creates a new [Toast]. Errors are thrown as JavaScript exception, see
+    /// [Toast::try_new].
     pub fn new(config: &ToastConfig) -> Self {
-        new_toast(&config.js_config)
+        Self::try_new(config).unwrap_or_else(|e| e.throw())
+    }
+
+    /// Creates a new [Toast] that keeps its configuration, and with it the
+    /// handlers of its actions and events, until it is removed.
+    ///
+    /// Unlike [Toast::try_new], the configuration does not have to be kept
+    /// by the caller.
+    pub fn try_show(config: ToastConfig) -> Result<Self, FomanticError> {
+        let kept = Rc::new(RefCell::new(None));
+        let release = Rc::clone(&kept);
+        let on_remove = config.callbacks.set("onRemove", move |_| {
+            // the handler is owned by the configuration, so it is dropped
+            // once the module is done with the toast
+            let config: Option<ToastConfig> = release.take();
+            wasm_bindgen_futures::spawn_local(async move { drop(config) });
+            JsValue::TRUE
+        });
+        config.js_config.set_on_remove(&on_remove);
+        let toast = Self::try_new(&config)?;
+        *kept.borrow_mut() = Some(config);
+        Ok(toast)
+    }
+
+    /// Creates a new [Toast] that keeps its configuration until it is
+    /// removed. Errors are thrown as JavaScript exception, see
+    /// [Toast::try_show].
+    pub fn show(config: ToastConfig) -> Self {
+        Self::try_show(config).unwrap_or_else(|e| e.throw())
+    }
+
+    /// Creates a new [Toast] that is removed when the returned guard is
+    /// dropped, see [InstanceGuard]. The guard keeps the configuration, the
+    /// handlers of its events can be replaced through the guard.
+    pub fn try_new_guarded(
+        mut config: ToastConfig,
+    ) -> Result<InstanceGuard<Self>, FomanticError> {
+        let toast = Self::try_new(&config)?;
+        let callbacks = std::mem::take(&mut config.callbacks);
+        Ok(toast.into_guard().owning(callbacks).owning(config))
+    }
+
+    /// Creates a new [Toast] that is removed when the returned guard is
+    /// dropped. Errors are thrown as JavaScript exception, see
+    /// [Toast::try_new_guarded].
+    pub fn new_guarded(config: ToastConfig) -> InstanceGuard<Self> {
+        Self::try_new_guarded(config).unwrap_or_else(|e| e.throw())
+    }
+
+    /// Shows a toast with the given JavaScript configuration.
+    fn create(config: &JsToastConfig) -> Result<Self, FomanticError> {
+        ensure_module("toast")?;
+        let element = invoke("toast", &[config])?;
+        Ok(Self { element })
     }
 
     /// Shorthand function for a minimal [Toast] that just displays a message.
     pub fn minimal(message: &str) -> Self {
         let config = JsToastConfig::new();
         config.set_message(message);
-        new_toast(&config)
+        Self::create(&config).unwrap_or_else(|e| e.throw())
     }
 
     /// Shorthand function for a titled [Toast] that displays a titled message.
@@ -268,7 +843,7 @@ impl Toast {
         let config = JsToastConfig::new();
         config.set_title(title);
         config.set_message(message);
-        new_toast(&config)
+        Self::create(&config).unwrap_or_else(|e| e.throw())
     }
 
     /// Shorthand function for a [Toast] with a message and progress bar.
@@ -276,7 +851,7 @@ impl Toast {
         let config = JsToastConfig::new();
         config.set_message(message);
         config.set_progress_bar_position(&progress_bar.position.to_string());
-        new_toast(&config)
+        Self::create(&config).unwrap_or_else(|e| e.throw())
     }
 
     /// Shorthand function for a [Toast] with a message and progress bar.
@@ -289,7 +864,17 @@ impl Toast {
         config.set_title(title);
         config.set_message(message);
         config.set_progress_bar_position(&progress_bar.position.to_string());
-        new_toast(&config)
+        Self::create(&config).unwrap_or_else(|e| e.throw())
+    }
+}
+
+impl FomanticModule for Toast {
+    fn module_name(&self) -> &'static str {
+        "toast"
+    }
+
+    fn element(&self) -> JQuery {
+        self.element.clone()
     }
 }
 
@@ -328,3 +913,47 @@ extern "C" {
 
 }
     */
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        class_selector,
+        container_classes,
+        container_selector,
+        ToastPosition,
+    };
+
+    #[test]
+    fn appends_the_class_of_the_container() {
+        assert_eq!(container_classes(None), "ui toast-container");
+        assert_eq!(
+            container_classes(Some("chat toasts")),
+            "ui toast-container chat toasts"
+        );
+    }
+
+    #[test]
+    fn turns_classes_into_selectors() {
+        assert_eq!(
+            class_selector("ui  toast-container "),
+            ".ui.toast-container"
+        );
+        assert_eq!(class_selector(""), "");
+    }
+
+    #[test]
+    fn selects_the_container_of_a_region() {
+        assert_eq!(
+            container_selector(None, None, ToastPosition::TopRight),
+            "body > .ui.toast-container.top.right"
+        );
+        assert_eq!(
+            container_selector(
+                Some("#chat"),
+                Some("chat-toasts"),
+                ToastPosition::BottomLeft
+            ),
+            "#chat > .ui.toast-container.chat-toasts.bottom.left"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Application wide defaults of the modules.
+//!
+//! The settings are merged into the defaults of the modules,
+//! `$.fn.<module>.settings`, so they apply to every module initialized
+//! afterwards. Fields that are not set keep the defaults of `fomantic-ui`.
+//!
+//! ```no_run
+//! # #[cfg(all(feature = "dropdown", feature = "modal", feature = "toast"))]
+//! # {
+//! use fomantic_ui::{
+//!     modules::settings::{
+//!         DropdownMessage,
+//!         DropdownSettings,
+//!         ModalSettings,
+//!         ToastSettings,
+//!     },
+//!     style::TransitionName,
+//! };
+//! use tracing::level_filters::LevelFilter;
+//!
+//! fomantic_ui::settings()
+//!     .modal(ModalSettings {
+//!         transition: Some(TransitionName::Fade),
+//!         duration: Some(200),
+//!         ..Default::default()
+//!     })
+//!     .toast(ToastSettings {
+//!         newest_on_top: Some(true),
+//!         ..Default::default()
+//!     })
+//!     .dropdown(DropdownSettings {
+//!         message: Some(DropdownMessage {
+//!             no_results: Some("Nothing found".into()),
+//!             ..Default::default()
+//!         }),
+//!         ..Default::default()
+//!     })
+//!     .log_level(LevelFilter::DEBUG)
+//!     .apply()
+//!     .unwrap();
+//! # }
+//! ```
+use crate::{
+    environment::extend_defaults,
+    locale::Locale,
+    modules::settings::ModuleSettings,
+    FomanticError,
+};
+use tracing::level_filters::LevelFilter;
+use wasm_bindgen::JsValue;
+
+/// Collects the defaults of the modules, see [settings].
+#[derive(Default)]
+pub struct GlobalSettings {
+    modules: Vec<(&'static str, Result<JsValue, FomanticError>)>,
+    locale: Option<Locale>,
+    log_level: Option<LevelFilter>,
+}
+
+/// Returns the entry point to configure the defaults of all modules.
+pub fn settings() -> GlobalSettings {
+    GlobalSettings::default()
+}
+
+impl GlobalSettings {
+    /// Sets the defaults of the module of the given settings.
+    pub fn module<S: ModuleSettings>(mut self, settings: S) -> Self {
+        self.modules.push((S::MODULE, settings.to_js()));
+        self
+    }
+
+    /// Sets the defaults of modals.
+    #[cfg(feature = "modal")]
+    pub fn modal(
+        self,
+        settings: crate::modules::settings::ModalSettings,
+    ) -> Self {
+        self.module(settings)
+    }
+
+    /// Sets the defaults of toasts.
+    #[cfg(feature = "toast")]
+    pub fn toast(
+        self,
+        settings: crate::modules::settings::ToastSettings,
+    ) -> Self {
+        self.module(settings)
+    }
+
+    /// Sets the defaults of dropdowns.
+    #[cfg(feature = "dropdown")]
+    pub fn dropdown(
+        self,
+        settings: crate::modules::settings::DropdownSettings,
+    ) -> Self {
+        self.module(settings)
+    }
+
+    /// Sets the defaults of popups.
+    #[cfg(feature = "popup")]
+    pub fn popup(
+        self,
+        settings: crate::modules::settings::PopupSettings,
+    ) -> Self {
+        self.module(settings)
+    }
+
+    /// Sets the defaults of checkboxes.
+    #[cfg(feature = "checkbox")]
+    pub fn checkbox(
+        self,
+        settings: crate::modules::settings::CheckboxSettings,
+    ) -> Self {
+        self.module(settings)
+    }
+
+    /// Sets the defaults of transitions.
+    #[cfg(feature = "transition")]
+    pub fn transition(
+        self,
+        settings: crate::modules::settings::TransitionSettings,
+    ) -> Self {
+        self.module(settings)
+    }
+
+    /// Sets the texts of all modules, applied after the settings of the
+    /// single modules, see [Locale::apply].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Sets the debug output of all modules, see
+    /// [set_log_level](crate::set_log_level).
+    pub fn log_level(mut self, level: LevelFilter) -> Self {
+        self.log_level = Some(level);
+        self
+    }
+
+    /// Writes the settings to the defaults of the modules.
+    ///
+    /// Fails if a module is not loaded, the defaults of the modules before
+    /// are written anyway.
+    pub fn apply(self) -> Result<(), FomanticError> {
+        for (module, settings) in self.modules {
+            if !extend_defaults(module, &settings?)? {
+                return Err(FomanticError::ModuleMissing(module.into()));
+            }
+        }
+        if let Some(locale) = self.locale {
+            locale.apply()?;
+        }
+        if let Some(level) = self.log_level {
+            crate::set_log_level(level)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,605 @@
+//! Typed styling variations shared by the modules and components.
+
+mod icon_name;
+
+pub use icon_name::IconName;
+
+/// Colors supported by `fomantic-ui` elements.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Color {
+    /// Red.
+    Red,
+    /// Orange.
+    Orange,
+    /// Yellow.
+    Yellow,
+    /// Olive.
+    Olive,
+    /// Green.
+    Green,
+    /// Teal.
+    Teal,
+    /// Blue.
+    Blue,
+    /// Violet.
+    Violet,
+    /// Purple.
+    Purple,
+    /// Pink.
+    Pink,
+    /// Brown.
+    Brown,
+    /// Grey.
+    Grey,
+    /// Black.
+    Black,
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Red => "red",
+            Self::Orange => "orange",
+            Self::Yellow => "yellow",
+            Self::Olive => "olive",
+            Self::Green => "green",
+            Self::Teal => "teal",
+            Self::Blue => "blue",
+            Self::Violet => "violet",
+            Self::Purple => "purple",
+            Self::Pink => "pink",
+            Self::Brown => "brown",
+            Self::Grey => "grey",
+            Self::Black => "black",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Sizes supported by `fomantic-ui` elements.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Size {
+    /// Mini.
+    Mini,
+    /// Tiny.
+    Tiny,
+    /// Small.
+    Small,
+    /// Medium, the default size of most elements.
+    Medium,
+    /// Large.
+    Large,
+    /// Big.
+    Big,
+    /// Huge.
+    Huge,
+    /// Massive.
+    Massive,
+}
+
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Mini => "mini",
+            Self::Tiny => "tiny",
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+            Self::Big => "big",
+            Self::Huge => "huge",
+            Self::Massive => "massive",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Writing direction of the page or a component.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Direction {
+    /// Left to right, eg. for english.
+    #[default]
+    Ltr,
+    /// Right to left, eg. for arabic or hebrew.
+    Rtl,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ltr => write!(f, "ltr"),
+            Self::Rtl => write!(f, "rtl"),
+        }
+    }
+}
+
+/// Variations that refer to the left or right side, mirrored for right to
+/// left layouts.
+pub trait Mirror: Sized {
+    /// Swaps left and right.
+    fn mirrored(self) -> Self;
+
+    /// Mirrors the variation in right to left layouts.
+    fn for_direction(self, direction: Direction) -> Self {
+        match direction {
+            Direction::Ltr => self,
+            Direction::Rtl => self.mirrored(),
+        }
+    }
+}
+
+/// Horizontal alignment of text.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextAlign {
+    /// Left aligned.
+    Left,
+    /// Centered.
+    Center,
+    /// Right aligned.
+    Right,
+    /// Justified.
+    Justified,
+}
+
+impl std::fmt::Display for TextAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Left => "left aligned",
+            Self::Center => "center aligned",
+            Self::Right => "right aligned",
+            Self::Justified => "justified",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Width of a column, in sixteenths of the available space.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ColumnWidth {
+    /// One sixteenth.
+    One,
+    /// Two sixteenths.
+    Two,
+    /// Three sixteenths.
+    Three,
+    /// Four sixteenths.
+    Four,
+    /// Five sixteenths.
+    Five,
+    /// Six sixteenths.
+    Six,
+    /// Seven sixteenths.
+    Seven,
+    /// Eight sixteenths.
+    Eight,
+    /// Nine sixteenths.
+    Nine,
+    /// Ten sixteenths.
+    Ten,
+    /// Eleven sixteenths.
+    Eleven,
+    /// Twelve sixteenths.
+    Twelve,
+    /// Thirteen sixteenths.
+    Thirteen,
+    /// Fourteen sixteenths.
+    Fourteen,
+    /// Fifteen sixteenths.
+    Fifteen,
+    /// The full width.
+    Sixteen,
+}
+
+impl ColumnWidth {
+    /// The number word used by `fomantic-ui` class names.
+    pub(crate) fn as_word(&self) -> &'static str {
+        match self {
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Three => "three",
+            Self::Four => "four",
+            Self::Five => "five",
+            Self::Six => "six",
+            Self::Seven => "seven",
+            Self::Eight => "eight",
+            Self::Nine => "nine",
+            Self::Ten => "ten",
+            Self::Eleven => "eleven",
+            Self::Twelve => "twelve",
+            Self::Thirteen => "thirteen",
+            Self::Fourteen => "fourteen",
+            Self::Fifteen => "fifteen",
+            Self::Sixteen => "sixteen",
+        }
+    }
+}
+
+impl std::fmt::Display for ColumnWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} wide", self.as_word())
+    }
+}
+
+/// Vertical alignment of content.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VerticalAlign {
+    /// Aligned to the top.
+    Top,
+    /// Centered.
+    Middle,
+    /// Aligned to the bottom.
+    Bottom,
+}
+
+impl std::fmt::Display for VerticalAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Top => "top aligned",
+            Self::Middle => "middle aligned",
+            Self::Bottom => "bottom aligned",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Device classes that responsive variations can target.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Device {
+    /// Mobile phones.
+    Mobile,
+    /// Tablets.
+    Tablet,
+    /// Small monitors.
+    Computer,
+    /// Large monitors.
+    LargeScreen,
+    /// Very wide monitors.
+    Widescreen,
+}
+
+impl std::fmt::Display for Device {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Mobile => "mobile",
+            Self::Tablet => "tablet",
+            Self::Computer => "computer",
+            Self::LargeScreen => "large screen",
+            Self::Widescreen => "widescreen",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl Mirror for TextAlign {
+    fn mirrored(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            other => other,
+        }
+    }
+}
+
+/// Floating of an element within its container.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Float {
+    /// Floated to the left.
+    Left,
+    /// Floated to the right.
+    Right,
+}
+
+impl std::fmt::Display for Float {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Left => write!(f, "left floated"),
+            Self::Right => write!(f, "right floated"),
+        }
+    }
+}
+
+impl Mirror for Float {
+    fn mirrored(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Emphasis of an element, eg. a button.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Emphasis {
+    /// The main action.
+    Primary,
+    /// An action of lower importance.
+    Secondary,
+    /// A positive consequence.
+    Positive,
+    /// A negative consequence.
+    Negative,
+}
+
+impl std::fmt::Display for Emphasis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Primary => "primary",
+            Self::Secondary => "secondary",
+            Self::Positive => "positive",
+            Self::Negative => "negative",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A list of class names, composed from the typed variations.
+///
+/// Accepted wherever a class is expected. Plain strings convert into it, so
+/// custom classes can still be used.
+///
+/// ```
+/// use fomantic_ui::style::{
+///     Classes,
+///     Color,
+///     Float,
+///     Size,
+/// };
+///
+/// let classes = Classes::new()
+///     .color(Color::Red)
+///     .size(Size::Small)
+///     .float(Float::Right)
+///     .with("basic");
+/// // "red small right floated basic"
+/// let class = classes.to_string();
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Classes(Vec<String>);
+
+impl Classes {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the given class, empty classes are skipped.
+    pub fn with(mut self, class: impl std::fmt::Display) -> Self {
+        let class = class.to_string();
+        if !class.trim().is_empty() {
+            self.0.push(class.trim().to_string());
+        }
+        self
+    }
+
+    /// Appends the given class if `condition` is met.
+    pub fn with_if(
+        self,
+        condition: bool,
+        class: impl std::fmt::Display,
+    ) -> Self {
+        if condition {
+            self.with(class)
+        } else {
+            self
+        }
+    }
+
+    /// Appends a color.
+    pub fn color(self, color: Color) -> Self {
+        self.with(color)
+    }
+
+    /// Appends a size.
+    pub fn size(self, size: Size) -> Self {
+        self.with(size)
+    }
+
+    /// Appends a text alignment.
+    pub fn text_align(self, text_align: TextAlign) -> Self {
+        self.with(text_align)
+    }
+
+    /// Appends a floating.
+    pub fn float(self, float: Float) -> Self {
+        self.with(float)
+    }
+
+    /// Appends an emphasis.
+    pub fn emphasis(self, emphasis: Emphasis) -> Self {
+        self.with(emphasis)
+    }
+
+    /// Whether the list contains no class.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Display for Classes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join(" "))
+    }
+}
+
+impl From<&str> for Classes {
+    fn from(class: &str) -> Self {
+        Self::new().with(class)
+    }
+}
+
+impl From<String> for Classes {
+    fn from(class: String) -> Self {
+        Self::new().with(class)
+    }
+}
+
+impl From<Color> for Classes {
+    fn from(color: Color) -> Self {
+        Self::new().color(color)
+    }
+}
+
+impl From<Size> for Classes {
+    fn from(size: Size) -> Self {
+        Self::new().size(size)
+    }
+}
+
+impl From<TextAlign> for Classes {
+    fn from(text_align: TextAlign) -> Self {
+        Self::new().text_align(text_align)
+    }
+}
+
+impl From<Float> for Classes {
+    fn from(float: Float) -> Self {
+        Self::new().float(float)
+    }
+}
+
+impl From<Emphasis> for Classes {
+    fn from(emphasis: Emphasis) -> Self {
+        Self::new().emphasis(emphasis)
+    }
+}
+
+/// Animations of the `fomantic-ui` transition module.
+///
+/// Used by every module that animates its elements, eg. modals, toasts and
+/// dropdowns.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransitionName {
+    /// Scales the element from or to its center.
+    Scale,
+    /// Zooms the element.
+    Zoom,
+    /// Fades the element.
+    Fade,
+    /// Fades the element while moving it up.
+    FadeUp,
+    /// Fades the element while moving it down.
+    FadeDown,
+    /// Fades the element while moving it left.
+    FadeLeft,
+    /// Fades the element while moving it right.
+    FadeRight,
+    /// Flips the element horizontally.
+    HorizontalFlip,
+    /// Flips the element vertically.
+    VerticalFlip,
+    /// Drops the element from above.
+    Drop,
+    /// Flies the element to the left.
+    FlyLeft,
+    /// Flies the element to the right.
+    FlyRight,
+    /// Flies the element up.
+    FlyUp,
+    /// Flies the element down.
+    FlyDown,
+    /// Swings the element to the left.
+    SwingLeft,
+    /// Swings the element to the right.
+    SwingRight,
+    /// Swings the element up.
+    SwingUp,
+    /// Swings the element down.
+    SwingDown,
+    /// Browses to the element, like flipping through cards.
+    Browse,
+    /// Browses to the element from the right.
+    BrowseRight,
+    /// Slides the element down.
+    SlideDown,
+    /// Slides the element up.
+    SlideUp,
+    /// Slides the element to the left.
+    SlideLeft,
+    /// Slides the element to the right.
+    SlideRight,
+    /// Jiggles the element, without hiding or showing it.
+    Jiggle,
+    /// Flashes the element, without hiding or showing it.
+    Flash,
+    /// Shakes the element, without hiding or showing it.
+    Shake,
+    /// Pulses the element, without hiding or showing it.
+    Pulse,
+    /// Tada, without hiding or showing the element.
+    Tada,
+    /// Bounces the element, without hiding or showing it.
+    Bounce,
+    /// Glows the element, without hiding or showing it.
+    Glow,
+}
+
+impl TransitionName {
+    /// Whether the animation only draws attention to the element, instead
+    /// of hiding or showing it.
+    pub fn is_static(&self) -> bool {
+        matches!(
+            self,
+            Self::Jiggle
+                | Self::Flash
+                | Self::Shake
+                | Self::Pulse
+                | Self::Tada
+                | Self::Bounce
+                | Self::Glow
+        )
+    }
+}
+
+impl Mirror for TransitionName {
+    fn mirrored(self) -> Self {
+        match self {
+            Self::FadeLeft => Self::FadeRight,
+            Self::FadeRight => Self::FadeLeft,
+            Self::FlyLeft => Self::FlyRight,
+            Self::FlyRight => Self::FlyLeft,
+            Self::SwingLeft => Self::SwingRight,
+            Self::SwingRight => Self::SwingLeft,
+            Self::SlideLeft => Self::SlideRight,
+            Self::SlideRight => Self::SlideLeft,
+            other => other,
+        }
+    }
+}
+
+impl std::fmt::Display for TransitionName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Scale => "scale",
+            Self::Zoom => "zoom",
+            Self::Fade => "fade",
+            Self::FadeUp => "fade up",
+            Self::FadeDown => "fade down",
+            Self::FadeLeft => "fade left",
+            Self::FadeRight => "fade right",
+            Self::HorizontalFlip => "horizontal flip",
+            Self::VerticalFlip => "vertical flip",
+            Self::Drop => "drop",
+            Self::FlyLeft => "fly left",
+            Self::FlyRight => "fly right",
+            Self::FlyUp => "fly up",
+            Self::FlyDown => "fly down",
+            Self::SwingLeft => "swing left",
+            Self::SwingRight => "swing right",
+            Self::SwingUp => "swing up",
+            Self::SwingDown => "swing down",
+            Self::Browse => "browse",
+            Self::BrowseRight => "browse right",
+            Self::SlideDown => "slide down",
+            Self::SlideUp => "slide up",
+            Self::SlideLeft => "slide left",
+            Self::SlideRight => "slide right",
+            Self::Jiggle => "jiggle",
+            Self::Flash => "flash",
+            Self::Shake => "shake",
+            Self::Pulse => "pulse",
+            Self::Tada => "tada",
+            Self::Bounce => "bounce",
+            Self::Glow => "glow",
+        };
+        write!(f, "{s}")
+    }
+}
@@ -0,0 +1,601 @@
+// Generated by scripts/gen_icon_names.py, do not edit.
+
+/// Icons of the `fomantic-ui` icon set.
+///
+/// Displayed as the class string of the icon, eg. `check circle icon`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IconName {
+    /// `address book`.
+    AddressBook,
+    /// `address card`.
+    AddressCard,
+    /// `angle down`.
+    AngleDown,
+    /// `angle left`.
+    AngleLeft,
+    /// `angle right`.
+    AngleRight,
+    /// `angle up`.
+    AngleUp,
+    /// `archive`.
+    Archive,
+    /// `arrow down`.
+    ArrowDown,
+    /// `arrow left`.
+    ArrowLeft,
+    /// `arrow right`.
+    ArrowRight,
+    /// `arrow up`.
+    ArrowUp,
+    /// `arrows alternate`.
+    ArrowsAlternate,
+    /// `asterisk`.
+    Asterisk,
+    /// `at`.
+    At,
+    /// `ban`.
+    Ban,
+    /// `bars`.
+    Bars,
+    /// `bell`.
+    Bell,
+    /// `bell outline`.
+    BellOutline,
+    /// `bell slash`.
+    BellSlash,
+    /// `bold`.
+    Bold,
+    /// `book`.
+    Book,
+    /// `bookmark`.
+    Bookmark,
+    /// `bookmark outline`.
+    BookmarkOutline,
+    /// `box`.
+    Box,
+    /// `briefcase`.
+    Briefcase,
+    /// `bug`.
+    Bug,
+    /// `building`.
+    Building,
+    /// `bullhorn`.
+    Bullhorn,
+    /// `calculator`.
+    Calculator,
+    /// `calendar`.
+    Calendar,
+    /// `calendar alternate`.
+    CalendarAlternate,
+    /// `calendar outline`.
+    CalendarOutline,
+    /// `camera`.
+    Camera,
+    /// `caret down`.
+    CaretDown,
+    /// `caret left`.
+    CaretLeft,
+    /// `caret right`.
+    CaretRight,
+    /// `caret up`.
+    CaretUp,
+    /// `chart bar`.
+    ChartBar,
+    /// `chart line`.
+    ChartLine,
+    /// `chart pie`.
+    ChartPie,
+    /// `check`.
+    Check,
+    /// `check circle`.
+    CheckCircle,
+    /// `check circle outline`.
+    CheckCircleOutline,
+    /// `check square`.
+    CheckSquare,
+    /// `check square outline`.
+    CheckSquareOutline,
+    /// `chevron down`.
+    ChevronDown,
+    /// `chevron left`.
+    ChevronLeft,
+    /// `chevron right`.
+    ChevronRight,
+    /// `chevron up`.
+    ChevronUp,
+    /// `circle`.
+    Circle,
+    /// `circle outline`.
+    CircleOutline,
+    /// `clipboard`.
+    Clipboard,
+    /// `clock`.
+    Clock,
+    /// `clock outline`.
+    ClockOutline,
+    /// `clone`.
+    Clone,
+    /// `close`.
+    Close,
+    /// `cloud`.
+    Cloud,
+    /// `code`.
+    Code,
+    /// `cog`.
+    Cog,
+    /// `cogs`.
+    Cogs,
+    /// `comment`.
+    Comment,
+    /// `comment outline`.
+    CommentOutline,
+    /// `comments`.
+    Comments,
+    /// `compress`.
+    Compress,
+    /// `copy`.
+    Copy,
+    /// `copy outline`.
+    CopyOutline,
+    /// `credit card`.
+    CreditCard,
+    /// `cut`.
+    Cut,
+    /// `database`.
+    Database,
+    /// `delete`.
+    Delete,
+    /// `desktop`.
+    Desktop,
+    /// `download`.
+    Download,
+    /// `dropdown`.
+    Dropdown,
+    /// `edit`.
+    Edit,
+    /// `edit outline`.
+    EditOutline,
+    /// `ellipsis horizontal`.
+    EllipsisHorizontal,
+    /// `ellipsis vertical`.
+    EllipsisVertical,
+    /// `envelope`.
+    Envelope,
+    /// `envelope outline`.
+    EnvelopeOutline,
+    /// `eraser`.
+    Eraser,
+    /// `exclamation`.
+    Exclamation,
+    /// `exclamation circle`.
+    ExclamationCircle,
+    /// `exclamation triangle`.
+    ExclamationTriangle,
+    /// `expand`.
+    Expand,
+    /// `external alternate`.
+    ExternalAlternate,
+    /// `eye`.
+    Eye,
+    /// `eye slash`.
+    EyeSlash,
+    /// `file`.
+    File,
+    /// `file alternate`.
+    FileAlternate,
+    /// `file outline`.
+    FileOutline,
+    /// `filter`.
+    Filter,
+    /// `flag`.
+    Flag,
+    /// `folder`.
+    Folder,
+    /// `folder open`.
+    FolderOpen,
+    /// `folder outline`.
+    FolderOutline,
+    /// `github`.
+    Github,
+    /// `globe`.
+    Globe,
+    /// `hashtag`.
+    Hashtag,
+    /// `heart`.
+    Heart,
+    /// `heart outline`.
+    HeartOutline,
+    /// `history`.
+    History,
+    /// `home`.
+    Home,
+    /// `image`.
+    Image,
+    /// `image outline`.
+    ImageOutline,
+    /// `inbox`.
+    Inbox,
+    /// `info`.
+    Info,
+    /// `info circle`.
+    InfoCircle,
+    /// `italic`.
+    Italic,
+    /// `key`.
+    Key,
+    /// `language`.
+    Language,
+    /// `laptop`.
+    Laptop,
+    /// `link`.
+    Link,
+    /// `linkify`.
+    Linkify,
+    /// `list`.
+    List,
+    /// `list ol`.
+    ListOl,
+    /// `list ul`.
+    ListUl,
+    /// `location arrow`.
+    LocationArrow,
+    /// `lock`.
+    Lock,
+    /// `lock open`.
+    LockOpen,
+    /// `mail`.
+    Mail,
+    /// `map`.
+    Map,
+    /// `map marker alternate`.
+    MapMarkerAlternate,
+    /// `microphone`.
+    Microphone,
+    /// `minus`.
+    Minus,
+    /// `minus circle`.
+    MinusCircle,
+    /// `mobile alternate`.
+    MobileAlternate,
+    /// `moon`.
+    Moon,
+    /// `paper plane`.
+    PaperPlane,
+    /// `paperclip`.
+    Paperclip,
+    /// `pause`.
+    Pause,
+    /// `pen`.
+    Pen,
+    /// `pencil alternate`.
+    PencilAlternate,
+    /// `phone`.
+    Phone,
+    /// `play`.
+    Play,
+    /// `plus`.
+    Plus,
+    /// `plus circle`.
+    PlusCircle,
+    /// `power off`.
+    PowerOff,
+    /// `print`.
+    Print,
+    /// `question`.
+    Question,
+    /// `question circle`.
+    QuestionCircle,
+    /// `question circle outline`.
+    QuestionCircleOutline,
+    /// `redo`.
+    Redo,
+    /// `save`.
+    Save,
+    /// `search`.
+    Search,
+    /// `search minus`.
+    SearchMinus,
+    /// `search plus`.
+    SearchPlus,
+    /// `share`.
+    Share,
+    /// `share alternate`.
+    ShareAlternate,
+    /// `shield alternate`.
+    ShieldAlternate,
+    /// `shopping cart`.
+    ShoppingCart,
+    /// `sign in alternate`.
+    SignInAlternate,
+    /// `sign out alternate`.
+    SignOutAlternate,
+    /// `sitemap`.
+    Sitemap,
+    /// `sort`.
+    Sort,
+    /// `sort down`.
+    SortDown,
+    /// `sort up`.
+    SortUp,
+    /// `spinner`.
+    Spinner,
+    /// `square`.
+    Square,
+    /// `square outline`.
+    SquareOutline,
+    /// `star`.
+    Star,
+    /// `star half`.
+    StarHalf,
+    /// `star outline`.
+    StarOutline,
+    /// `stop`.
+    Stop,
+    /// `sun`.
+    Sun,
+    /// `sync`.
+    Sync,
+    /// `table`.
+    Table,
+    /// `tag`.
+    Tag,
+    /// `tags`.
+    Tags,
+    /// `tasks`.
+    Tasks,
+    /// `thumbs down`.
+    ThumbsDown,
+    /// `thumbs up`.
+    ThumbsUp,
+    /// `times`.
+    Times,
+    /// `times circle`.
+    TimesCircle,
+    /// `trash`.
+    Trash,
+    /// `trash alternate`.
+    TrashAlternate,
+    /// `trash alternate outline`.
+    TrashAlternateOutline,
+    /// `truck`.
+    Truck,
+    /// `undo`.
+    Undo,
+    /// `unlock`.
+    Unlock,
+    /// `upload`.
+    Upload,
+    /// `user`.
+    User,
+    /// `user circle`.
+    UserCircle,
+    /// `user outline`.
+    UserOutline,
+    /// `user plus`.
+    UserPlus,
+    /// `users`.
+    Users,
+    /// `video`.
+    Video,
+    /// `volume off`.
+    VolumeOff,
+    /// `volume up`.
+    VolumeUp,
+    /// `wifi`.
+    Wifi,
+    /// `wrench`.
+    Wrench,
+    /// An icon that is not listed, eg. of a custom icon font.
+    Custom(&'static str),
+}
+
+impl IconName {
+    /// Returns the name of the icon without the `icon` class.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Self::AddressBook => "address book",
+            Self::AddressCard => "address card",
+            Self::AngleDown => "angle down",
+            Self::AngleLeft => "angle left",
+            Self::AngleRight => "angle right",
+            Self::AngleUp => "angle up",
+            Self::Archive => "archive",
+            Self::ArrowDown => "arrow down",
+            Self::ArrowLeft => "arrow left",
+            Self::ArrowRight => "arrow right",
+            Self::ArrowUp => "arrow up",
+            Self::ArrowsAlternate => "arrows alternate",
+            Self::Asterisk => "asterisk",
+            Self::At => "at",
+            Self::Ban => "ban",
+            Self::Bars => "bars",
+            Self::Bell => "bell",
+            Self::BellOutline => "bell outline",
+            Self::BellSlash => "bell slash",
+            Self::Bold => "bold",
+            Self::Book => "book",
+            Self::Bookmark => "bookmark",
+            Self::BookmarkOutline => "bookmark outline",
+            Self::Box => "box",
+            Self::Briefcase => "briefcase",
+            Self::Bug => "bug",
+            Self::Building => "building",
+            Self::Bullhorn => "bullhorn",
+            Self::Calculator => "calculator",
+            Self::Calendar => "calendar",
+            Self::CalendarAlternate => "calendar alternate",
+            Self::CalendarOutline => "calendar outline",
+            Self::Camera => "camera",
+            Self::CaretDown => "caret down",
+            Self::CaretLeft => "caret left",
+            Self::CaretRight => "caret right",
+            Self::CaretUp => "caret up",
+            Self::ChartBar => "chart bar",
+            Self::ChartLine => "chart line",
+            Self::ChartPie => "chart pie",
+            Self::Check => "check",
+            Self::CheckCircle => "check circle",
+            Self::CheckCircleOutline => "check circle outline",
+            Self::CheckSquare => "check square",
+            Self::CheckSquareOutline => "check square outline",
+            Self::ChevronDown => "chevron down",
+            Self::ChevronLeft => "chevron left",
+            Self::ChevronRight => "chevron right",
+            Self::ChevronUp => "chevron up",
+            Self::Circle => "circle",
+            Self::CircleOutline => "circle outline",
+            Self::Clipboard => "clipboard",
+            Self::Clock => "clock",
+            Self::ClockOutline => "clock outline",
+            Self::Clone => "clone",
+            Self::Close => "close",
+            Self::Cloud => "cloud",
+            Self::Code => "code",
+            Self::Cog => "cog",
+            Self::Cogs => "cogs",
+            Self::Comment => "comment",
+            Self::CommentOutline => "comment outline",
+            Self::Comments => "comments",
+            Self::Compress => "compress",
+            Self::Copy => "copy",
+            Self::CopyOutline => "copy outline",
+            Self::CreditCard => "credit card",
+            Self::Cut => "cut",
+            Self::Database => "database",
+            Self::Delete => "delete",
+            Self::Desktop => "desktop",
+            Self::Download => "download",
+            Self::Dropdown => "dropdown",
+            Self::Edit => "edit",
+            Self::EditOutline => "edit outline",
+            Self::EllipsisHorizontal => "ellipsis horizontal",
+            Self::EllipsisVertical => "ellipsis vertical",
+            Self::Envelope => "envelope",
+            Self::EnvelopeOutline => "envelope outline",
+            Self::Eraser => "eraser",
+            Self::Exclamation => "exclamation",
+            Self::ExclamationCircle => "exclamation circle",
+            Self::ExclamationTriangle => "exclamation triangle",
+            Self::Expand => "expand",
+            Self::ExternalAlternate => "external alternate",
+            Self::Eye => "eye",
+            Self::EyeSlash => "eye slash",
+            Self::File => "file",
+            Self::FileAlternate => "file alternate",
+            Self::FileOutline => "file outline",
+            Self::Filter => "filter",
+            Self::Flag => "flag",
+            Self::Folder => "folder",
+            Self::FolderOpen => "folder open",
+            Self::FolderOutline => "folder outline",
+            Self::Github => "github",
+            Self::Globe => "globe",
+            Self::Hashtag => "hashtag",
+            Self::Heart => "heart",
+            Self::HeartOutline => "heart outline",
+            Self::History => "history",
+            Self::Home => "home",
+            Self::Image => "image",
+            Self::ImageOutline => "image outline",
+            Self::Inbox => "inbox",
+            Self::Info => "info",
+            Self::InfoCircle => "info circle",
+            Self::Italic => "italic",
+            Self::Key => "key",
+            Self::Language => "language",
+            Self::Laptop => "laptop",
+            Self::Link => "link",
+            Self::Linkify => "linkify",
+            Self::List => "list",
+            Self::ListOl => "list ol",
+            Self::ListUl => "list ul",
+            Self::LocationArrow => "location arrow",
+            Self::Lock => "lock",
+            Self::LockOpen => "lock open",
+            Self::Mail => "mail",
+            Self::Map => "map",
+            Self::MapMarkerAlternate => "map marker alternate",
+            Self::Microphone => "microphone",
+            Self::Minus => "minus",
+            Self::MinusCircle => "minus circle",
+            Self::MobileAlternate => "mobile alternate",
+            Self::Moon => "moon",
+            Self::PaperPlane => "paper plane",
+            Self::Paperclip => "paperclip",
+            Self::Pause => "pause",
+            Self::Pen => "pen",
+            Self::PencilAlternate => "pencil alternate",
+            Self::Phone => "phone",
+            Self::Play => "play",
+            Self::Plus => "plus",
+            Self::PlusCircle => "plus circle",
+            Self::PowerOff => "power off",
+            Self::Print => "print",
+            Self::Question => "question",
+            Self::QuestionCircle => "question circle",
+            Self::QuestionCircleOutline => "question circle outline",
+            Self::Redo => "redo",
+            Self::Save => "save",
+            Self::Search => "search",
+            Self::SearchMinus => "search minus",
+            Self::SearchPlus => "search plus",
+            Self::Share => "share",
+            Self::ShareAlternate => "share alternate",
+            Self::ShieldAlternate => "shield alternate",
+            Self::ShoppingCart => "shopping cart",
+            Self::SignInAlternate => "sign in alternate",
+            Self::SignOutAlternate => "sign out alternate",
+            Self::Sitemap => "sitemap",
+            Self::Sort => "sort",
+            Self::SortDown => "sort down",
+            Self::SortUp => "sort up",
+            Self::Spinner => "spinner",
+            Self::Square => "square",
+            Self::SquareOutline => "square outline",
+            Self::Star => "star",
+            Self::StarHalf => "star half",
+            Self::StarOutline => "star outline",
+            Self::Stop => "stop",
+            Self::Sun => "sun",
+            Self::Sync => "sync",
+            Self::Table => "table",
+            Self::Tag => "tag",
+            Self::Tags => "tags",
+            Self::Tasks => "tasks",
+            Self::ThumbsDown => "thumbs down",
+            Self::ThumbsUp => "thumbs up",
+            Self::Times => "times",
+            Self::TimesCircle => "times circle",
+            Self::Trash => "trash",
+            Self::TrashAlternate => "trash alternate",
+            Self::TrashAlternateOutline => "trash alternate outline",
+            Self::Truck => "truck",
+            Self::Undo => "undo",
+            Self::Unlock => "unlock",
+            Self::Upload => "upload",
+            Self::User => "user",
+            Self::UserCircle => "user circle",
+            Self::UserOutline => "user outline",
+            Self::UserPlus => "user plus",
+            Self::Users => "users",
+            Self::Video => "video",
+            Self::VolumeOff => "volume off",
+            Self::VolumeUp => "volume up",
+            Self::Wifi => "wifi",
+            Self::Wrench => "wrench",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl std::fmt::Display for IconName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} icon", self.name())
+    }
+}
+
+impl From<&'static str> for IconName {
+    fn from(name: &'static str) -> Self {
+        Self::Custom(name)
+    }
+}
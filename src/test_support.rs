@@ -0,0 +1,198 @@
+//! Utilities for testing wrappers of `fomantic-ui` in a browser with
+//! `wasm-bindgen-test`.
+//!
+//! ```no_run
+//! use fomantic_ui::test_support::{
+//!     load_fomantic,
+//!     CallCounter,
+//!     Fixture,
+//! };
+//!
+//! # async fn test() {
+//! load_fomantic().await.unwrap();
+//! let fixture = Fixture::new(r#"<button class="ui button">Save</button>"#);
+//! let counter = CallCounter::new();
+//! let handler = counter.handler();
+//! // pass the handler to the wrapper under test, then
+//! fixture.click(".button");
+//! assert_eq!(counter.count(), 1);
+//! # }
+//! ```
+use crate::{
+    events::EventStream,
+    FomanticError,
+};
+use futures_core::Stream;
+use std::{
+    cell::Cell,
+    pin::Pin,
+    rc::Rc,
+};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+/// Loads jQuery and the stylesheet and script of `fomantic-ui` from a CDN.
+pub const FIXTURE: &str = include_str!("test_support/fixture.html");
+
+/// Loads jQuery and `fomantic-ui` into the page, see [FIXTURE].
+///
+/// Does nothing if they are loaded already, so every test can call it.
+pub async fn load_fomantic() -> Result<(), FomanticError> {
+    if crate::ensure_loaded().is_ok() {
+        return Ok(());
+    }
+    load_fixture(FIXTURE).await
+}
+
+/// Adds the stylesheets and scripts of the given html to the head of the
+/// page, waiting for each script to be executed before the next one is
+/// added.
+pub async fn load_fixture(html: &str) -> Result<(), FomanticError> {
+    let document = gloo::utils::document();
+    let head = gloo::utils::head();
+    let template = document
+        .create_element("div")
+        .map_err(FomanticError::from_js)?;
+    template.set_inner_html(html);
+    let children = template.children();
+    for index in 0..children.length() {
+        let Some(child) = children.item(index) else {
+            continue;
+        };
+        let Some(src) = child.get_attribute("src") else {
+            // stylesheets are loaded when attached
+            head.append_child(
+                &child.clone_node().map_err(FomanticError::from_js)?,
+            )
+            .map_err(FomanticError::from_js)?;
+            continue;
+        };
+        // scripts inserted as html are not executed, they are recreated
+        let script = document
+            .create_element("script")
+            .map_err(FomanticError::from_js)?
+            .unchecked_into::<web_sys::HtmlElement>();
+        script
+            .set_attribute("src", &src)
+            .map_err(FomanticError::from_js)?;
+        let loaded = js_sys::Promise::new(&mut |resolve, reject| {
+            script.set_onload(Some(&resolve));
+            script.set_onerror(Some(&reject));
+        });
+        head.append_child(&script).map_err(FomanticError::from_js)?;
+        JsFuture::from(loaded)
+            .await
+            .map_err(|_| FomanticError::Js(format!("Could not load {src}")))?;
+    }
+    Ok(())
+}
+
+/// Waits for the given amount of milliseconds, eg. until an animation has
+/// finished.
+pub async fn sleep(millis: i32) {
+    let timeout = js_sys::Promise::new(&mut |resolve, _| {
+        let _ = gloo::utils::window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve, millis,
+            );
+    });
+    let _ = JsFuture::from(timeout).await;
+}
+
+/// Waits for the next event of the stream, eg. of a
+/// [ModalConfig](crate::modules::modal::ModalConfig::events).
+///
+/// Returns `None` if the module has been dropped.
+pub async fn next_event<E>(stream: &mut EventStream<E>) -> Option<E> {
+    std::future::poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx)).await
+}
+
+/// Markup attached to the body of the page while the fixture is alive.
+pub struct Fixture {
+    element: web_sys::Element,
+}
+
+impl Fixture {
+    /// Attaches the given html to the body of the page.
+    pub fn new(html: &str) -> Self {
+        let element = gloo::utils::document()
+            .create_element("div")
+            .expect_throw("Could not create the fixture element");
+        element.set_inner_html(html);
+        gloo::utils::body()
+            .append_child(&element)
+            .expect_throw("Could not attach the fixture element");
+        Self { element }
+    }
+
+    /// Returns the element containing the markup.
+    pub fn element(&self) -> &web_sys::Element {
+        &self.element
+    }
+
+    /// Returns the first element of the fixture matching the selector.
+    ///
+    /// Panics if there is none, failing the test.
+    pub fn query(&self, selector: &str) -> web_sys::Element {
+        self.element
+            .query_selector(selector)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| panic!("No element matches {selector}"))
+    }
+
+    /// Clicks the first element of the fixture matching the selector.
+    pub fn click(&self, selector: &str) {
+        self.query(selector)
+            .unchecked_into::<web_sys::HtmlElement>()
+            .click();
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        self.element.remove();
+    }
+}
+
+/// Clicks the first element of the page matching the selector, eg. an
+/// action of a modal that is not part of a [Fixture].
+///
+/// Panics if there is none, failing the test.
+pub fn click(selector: &str) {
+    gloo::utils::document()
+        .query_selector(selector)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| panic!("No element matches {selector}"))
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+}
+
+/// Counts how often the handlers created by it are called.
+#[derive(Clone, Default)]
+pub struct CallCounter {
+    count: Rc<Cell<usize>>,
+}
+
+impl CallCounter {
+    /// Creates a counter that has not been called.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a handler incrementing the counter and returning `true`, as
+    /// expected by most module callbacks.
+    pub fn handler(&self) -> impl Fn() -> bool + 'static {
+        let count = Rc::clone(&self.count);
+        move || {
+            count.set(count.get() + 1);
+            true
+        }
+    }
+
+    /// Returns how often the handlers have been called.
+    pub fn count(&self) -> usize {
+        self.count.get()
+    }
+}
@@ -0,0 +1,256 @@
+//! Light and dark themes.
+//!
+//! `fomantic-ui` 2.9 has no global dark mode, dark pages use the `inverted`
+//! variation of the components instead. [apply_theme] toggles `inverted` on
+//! the components listed in [INVERTIBLE] and sets `data-theme` (`light` or
+//! `dark`) and `color-scheme` on the root element, so custom styles can
+//! follow the theme. Elements marked with `data-theme-fixed` keep their
+//! variation.
+//!
+//! The writing direction of the page is set the same way, see
+//! [set_direction].
+//!
+//! Components added afterwards are not switched, they have to use the
+//! variation themselves, eg. through the `inverted` setting of the leptos
+//! components, or the theme has to be applied again.
+//!
+//! ```no_run
+//! use fomantic_ui::theme::{
+//!     self,
+//!     ThemeMode,
+//! };
+//!
+//! // restores the choice of the user, following the system by default
+//! theme::apply_theme(theme::stored_theme().unwrap_or_default());
+//! theme::set_variable("--accent", "#2185d0");
+//!
+//! // later, eg. in the handler of a toggle
+//! theme::set_theme(ThemeMode::Dark);
+//! ```
+use crate::{
+    jq::JQuery,
+    style::Direction,
+    FomanticError,
+};
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+
+/// Selectors of the components that are switched to their `inverted`
+/// variation in the dark theme.
+pub const INVERTIBLE: &[&str] = &[
+    ".ui.accordion",
+    ".ui.divider",
+    ".ui.form",
+    ".ui.header",
+    ".ui.list",
+    ".ui.menu",
+    ".ui.message",
+    ".ui.modal",
+    ".ui.placeholder",
+    ".ui.popup",
+    ".ui.segment",
+    ".ui.table",
+];
+
+/// Key of the local storage the preference of the user is kept under.
+pub const STORAGE_KEY: &str = "fomantic-ui-theme";
+
+/// Media query matching if the system prefers dark colors.
+const PREFERS_DARK: &str = "(prefers-color-scheme: dark)";
+
+/// The theme chosen by the user.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ThemeMode {
+    /// Light colors.
+    Light,
+    /// Dark colors, using the `inverted` variation of the components.
+    Dark,
+    /// Follows the color scheme preferred by the system.
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    /// Parses the name written by [ThemeMode]'s `Display` implementation.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "light" => Some(Self::Light),
+            "dark" => Some(Self::Dark),
+            "system" => Some(Self::System),
+            _ => None,
+        }
+    }
+
+    /// Whether the mode results in dark colors, asking the system for
+    /// [ThemeMode::System].
+    pub fn is_dark(self) -> bool {
+        match self {
+            Self::Light => false,
+            Self::Dark => true,
+            Self::System => prefers_dark(),
+        }
+    }
+}
+
+impl std::fmt::Display for ThemeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+            Self::System => "system",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Whether the system prefers dark colors.
+pub fn prefers_dark() -> bool {
+    matches!(
+        gloo::utils::window().match_media(PREFERS_DARK),
+        Ok(Some(media)) if media.matches()
+    )
+}
+
+/// Calls the handler with the preference of the system whenever it changes.
+///
+/// The handler is removed when the returned listener is dropped. Returns
+/// `None` if the browser does not support media queries.
+pub fn on_system_change(
+    mut handler: impl FnMut(bool) + 'static,
+) -> Option<EventListener> {
+    let media = gloo::utils::window().match_media(PREFERS_DARK).ok()??;
+    Some(EventListener::new(&media.clone(), "change", move |_| {
+        handler(media.matches())
+    }))
+}
+
+/// Returns the root element of the document.
+fn root() -> Result<web_sys::HtmlElement, FomanticError> {
+    gloo::utils::document_element()
+        .dyn_into()
+        .map_err(|e| FomanticError::from_js(e.into()))
+}
+
+/// Applies the theme to the page without remembering it.
+///
+/// Returns whether the dark colors are used.
+pub fn try_apply_theme(mode: ThemeMode) -> Result<bool, FomanticError> {
+    let dark = mode.is_dark();
+    let scheme = if dark { "dark" } else { "light" };
+    let root = root()?;
+    root.set_attribute("data-theme", scheme)
+        .map_err(FomanticError::from_js)?;
+    root.style()
+        .set_property("color-scheme", scheme)
+        .map_err(FomanticError::from_js)?;
+    let selector = INVERTIBLE
+        .iter()
+        .map(|component| format!("{component}:not([data-theme-fixed])"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    JQuery::try_select(&selector)?.toggle_class("inverted", dark);
+    Ok(dark)
+}
+
+/// Applies the theme to the page without remembering it.
+///
+/// Throws if jQuery is not loaded, see [try_apply_theme].
+pub fn apply_theme(mode: ThemeMode) -> bool {
+    try_apply_theme(mode).unwrap_or_else(|e| e.throw())
+}
+
+/// Applies the theme and remembers it in the local storage, see
+/// [stored_theme].
+pub fn try_set_theme(mode: ThemeMode) -> Result<bool, FomanticError> {
+    try_store_theme(mode)?;
+    try_apply_theme(mode)
+}
+
+/// Applies the theme and remembers it in the local storage.
+///
+/// Throws if jQuery is not loaded, see [try_set_theme].
+pub fn set_theme(mode: ThemeMode) -> bool {
+    try_set_theme(mode).unwrap_or_else(|e| e.throw())
+}
+
+/// Returns the local storage, `None` if it is disabled.
+fn storage() -> Result<Option<web_sys::Storage>, FomanticError> {
+    gloo::utils::window()
+        .local_storage()
+        .map_err(FomanticError::from_js)
+}
+
+/// Remembers the theme in the local storage without applying it.
+///
+/// Does nothing if the local storage is disabled.
+pub fn try_store_theme(mode: ThemeMode) -> Result<(), FomanticError> {
+    let Some(storage) = storage()? else {
+        return Ok(());
+    };
+    storage
+        .set_item(STORAGE_KEY, &mode.to_string())
+        .map_err(FomanticError::from_js)
+}
+
+/// Returns the theme remembered by [set_theme], `None` if there is none or
+/// the local storage is disabled.
+pub fn stored_theme() -> Option<ThemeMode> {
+    let name = storage().ok()??.get_item(STORAGE_KEY).ok()??;
+    ThemeMode::from_name(&name)
+}
+
+/// Sets the writing direction of the page on the root element.
+///
+/// The right to left layout of the components requires the RTL build of the
+/// `fomantic-ui` stylesheet, `semantic.rtl.min.css`. Positions passed to the
+/// modules are not mirrored, use
+/// [Mirror::for_direction](crate::style::Mirror::for_direction) or the
+/// `direction` of the leptos components for that.
+pub fn try_set_direction(direction: Direction) -> Result<(), FomanticError> {
+    root()?
+        .set_attribute("dir", &direction.to_string())
+        .map_err(FomanticError::from_js)
+}
+
+/// Sets the writing direction of the page on the root element.
+///
+/// Throws if the attribute can not be set, see [try_set_direction].
+pub fn set_direction(direction: Direction) {
+    try_set_direction(direction).unwrap_or_else(|e| e.throw())
+}
+
+/// Returns the writing direction of the page set on the root element.
+pub fn direction() -> Direction {
+    match gloo::utils::document_element()
+        .get_attribute("dir")
+        .as_deref()
+    {
+        Some("rtl") => Direction::Rtl,
+        _ => Direction::Ltr,
+    }
+}
+
+/// Sets a CSS custom property on the root element, eg. `--accent`, to be
+/// used by the styles of the page.
+pub fn try_set_variable(name: &str, value: &str) -> Result<(), FomanticError> {
+    root()?
+        .style()
+        .set_property(name, value)
+        .map_err(FomanticError::from_js)
+}
+
+/// Sets a CSS custom property on the root element.
+///
+/// Throws if the property can not be set, see [try_set_variable].
+pub fn set_variable(name: &str, value: &str) {
+    try_set_variable(name, value).unwrap_or_else(|e| e.throw())
+}
+
+/// Removes a CSS custom property set by [set_variable].
+pub fn try_remove_variable(name: &str) -> Result<(), FomanticError> {
+    root()?
+        .style()
+        .remove_property(name)
+        .map(|_| ())
+        .map_err(FomanticError::from_js)
+}
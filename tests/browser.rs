@@ -0,0 +1,254 @@
+//! Tests of the callback wiring of the modules in a headless browser.
+//!
+//! Run with
+//! `wasm-pack test --headless --chrome --features test-support -- --test browser`.
+#![cfg(all(
+    target_arch = "wasm32",
+    feature = "test-support",
+    feature = "modal",
+    feature = "toast"
+))]
+
+use fomantic_ui::{
+    callbacks::CallbackRegistry,
+    jq::JQuery,
+    modules::{
+        modal::{
+            Modal,
+            ModalConfig,
+            ModalEvent,
+        },
+        toast::{
+            Toast,
+            ToastConfig,
+            ToastEvent,
+        },
+        FomanticModule,
+        InstanceGuard,
+    },
+    test_support::{
+        click,
+        load_fomantic,
+        next_event,
+        sleep,
+        CallCounter,
+        Fixture,
+    },
+    Action,
+    ActionKind,
+};
+use wasm_bindgen::{
+    JsCast,
+    JsValue,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn modal_calls_show_handlers() {
+    load_fomantic().await.unwrap();
+    let (show, visible) = (CallCounter::new(), CallCounter::new());
+    let mut config = ModalConfig::default().with_title("Show");
+    config.set_on_show(show.handler());
+    config.set_on_visible(visible.handler());
+    let modal = Modal::new(config);
+    modal.show();
+    assert_eq!(show.count(), 1);
+    sleep(1000).await;
+    assert_eq!(visible.count(), 1);
+    assert!(modal.is_active());
+    modal.remove();
+}
+
+#[wasm_bindgen_test]
+async fn modal_streams_events() {
+    load_fomantic().await.unwrap();
+    let mut config = ModalConfig::default().with_title("Events");
+    let mut events = config.events();
+    let modal = Modal::new(config);
+    modal.show();
+    assert_eq!(next_event(&mut events).await, Some(ModalEvent::Show));
+    assert_eq!(next_event(&mut events).await, Some(ModalEvent::Visible));
+    modal.hide();
+    assert_eq!(next_event(&mut events).await, Some(ModalEvent::Hide));
+    assert_eq!(next_event(&mut events).await, Some(ModalEvent::Hidden));
+    modal.remove();
+}
+
+#[wasm_bindgen_test]
+async fn modal_action_calls_click_and_approve_handlers() {
+    load_fomantic().await.unwrap();
+    let (clicked, approved) = (CallCounter::new(), CallCounter::new());
+    let click_handler = clicked.handler();
+    let mut config =
+        ModalConfig::default()
+            .with_title("Actions")
+            .with_actions(vec![Action::new()
+                .with_text("Ok")
+                .with_kind(ActionKind::Ok)
+                .click(click_handler)]);
+    let on_approve = approved.handler();
+    config.set_on_approve(move |_| on_approve());
+    let modal = Modal::new(config);
+    modal.show();
+    sleep(1000).await;
+    click(".ui.modal .actions .button");
+    assert_eq!(clicked.count(), 1);
+    assert_eq!(approved.count(), 1);
+    modal.remove();
+}
+
+#[wasm_bindgen_test]
+async fn modal_action_handles_change_the_rendered_buttons() {
+    load_fomantic().await.unwrap();
+    let mut config = ModalConfig::default().with_title("Handles");
+    let handles = config.set_actions(vec![
+        Action::new().with_text("Cancel"),
+        Action::new().with_text("Submit"),
+    ]);
+    let modal = Modal::new(config);
+    modal.show();
+    let buttons = modal.element().find(".actions > .button");
+    assert_eq!(buttons.length(), 2);
+
+    handles[1].disable();
+    assert!(!handles[1].is_enabled());
+    assert!(buttons.eq(1).has_class("disabled"));
+    assert!(!buttons.eq(0).has_class("disabled"));
+    handles[1].enable();
+    assert!(!buttons.eq(1).has_class("disabled"));
+    modal.remove();
+}
+
+#[wasm_bindgen_test]
+async fn modal_action_handles_stay_disabled_after_loading() {
+    load_fomantic().await.unwrap();
+    let mut config = ModalConfig::default().with_title("Loading");
+    let handles = config.set_actions(vec![Action::new().with_text("Submit")]);
+    let modal = Modal::new(config);
+    modal.show();
+    let button = modal.element().find(".actions > .button").element(0);
+    let disabled = || {
+        js_sys::Reflect::get(button.as_ref().unwrap(), &"disabled".into())
+            .unwrap()
+            .is_truthy()
+    };
+
+    handles[0].disable();
+    handles[0].set_loading(true);
+    assert!(disabled());
+    handles[0].set_loading(false);
+    assert!(disabled());
+    assert!(!handles[0].is_enabled());
+
+    handles[0].set_loading(true);
+    handles[0].enable();
+    assert!(disabled());
+    handles[0].set_loading(false);
+    assert!(!disabled());
+    modal.remove();
+}
+
+#[wasm_bindgen_test]
+async fn modal_replaces_handler_after_initialization() {
+    load_fomantic().await.unwrap();
+    let (first, second) = (CallCounter::new(), CallCounter::new());
+    let mut config = ModalConfig::default().with_title("Replace");
+    config.set_on_show(first.handler());
+    let modal = Modal::new(config);
+    let handler = second.handler();
+    assert!(modal.replace_handler("onShow", move |_| handler().into()));
+    assert!(!modal.replace_handler("onVisible", |_| JsValue::TRUE));
+    modal.show();
+    assert_eq!(first.count(), 0);
+    assert_eq!(second.count(), 1);
+    modal.remove();
+}
+
+#[wasm_bindgen_test]
+async fn toast_streams_events() {
+    load_fomantic().await.unwrap();
+    let mut config = ToastConfig::new().with_message("Saved");
+    let mut events = config.events();
+    let _toast = Toast::new(&config);
+    assert_eq!(next_event(&mut events).await, Some(ToastEvent::Show));
+    assert_eq!(next_event(&mut events).await, Some(ToastEvent::Visible));
+    click(".ui.toast");
+    assert_eq!(next_event(&mut events).await, Some(ToastEvent::Click));
+}
+
+#[wasm_bindgen_test]
+async fn toast_action_calls_click_handler() {
+    load_fomantic().await.unwrap();
+    let clicked = CallCounter::new();
+    let config = ToastConfig::new().with_message("Undo?").with_actions(vec![
+        Action::new().with_text("Undo").click(clicked.handler()),
+    ]);
+    let _toast = Toast::new(&config);
+    sleep(1000).await;
+    click(".ui.toast-container .actions .button");
+    assert_eq!(clicked.count(), 1);
+}
+
+/// Returns the size of the memory of the wasm module in bytes.
+///
+/// The memory only grows, so it stays the same as long as the allocator can
+/// reuse the memory that has been released.
+fn heap_size() -> u32 {
+    wasm_bindgen::memory()
+        .unchecked_into::<js_sys::WebAssembly::Memory>()
+        .buffer()
+        .unchecked_into::<js_sys::ArrayBuffer>()
+        .byte_length()
+}
+
+/// Mounts modules with handlers and unmounts them like a page of an app
+/// that is navigated away from.
+fn mount_and_unmount() {
+    let fixture = Fixture::new(
+        r#"<div class="ui selection dropdown">
+             <div class="menu"><div class="item">A</div></div>
+           </div>
+           <div class="ui checkbox"><input type="checkbox"><label></label></div>"#,
+    );
+    // large enough for a leaked handler to grow the memory within the
+    // cycles
+    let payload = vec![0u8; 64 * 1024];
+    let callbacks = CallbackRegistry::new();
+    let settings = js_sys::Object::new();
+    let on_change =
+        callbacks.set("onChange", move |_| JsValue::from(payload.len() as u32));
+    js_sys::Reflect::set(&settings, &"onChange".into(), &on_change).unwrap();
+    let dropdown = JQuery::from_element(&fixture.query(".ui.dropdown"));
+    dropdown.init("dropdown", &settings);
+    let dropdown = InstanceGuard::new(dropdown, "dropdown").owning(callbacks);
+    let modules =
+        fomantic_ui::init_all(&JQuery::from_element(fixture.element()));
+    assert_eq!(modules.len(), 1);
+
+    let mut config = ModalConfig::default();
+    let payload = vec![0u8; 64 * 1024];
+    config.set_on_show(move || !payload.is_empty());
+    config.set_title("Cycle");
+    let modal = Modal::new_guarded(config);
+    let element = modal.element();
+
+    drop((dropdown, modules, modal));
+    element.remove();
+    drop(fixture);
+}
+
+#[wasm_bindgen_test]
+async fn guards_release_handlers_across_mount_cycles() {
+    load_fomantic().await.unwrap();
+    // lets the allocator reach its working size
+    for _ in 0..10 {
+        mount_and_unmount();
+    }
+    let size = heap_size();
+    for _ in 0..200 {
+        mount_and_unmount();
+    }
+    assert_eq!(heap_size(), size);
+}
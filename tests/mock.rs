@@ -0,0 +1,349 @@
+//! Tests of the mock backend, run with `cargo test --features mock,models`.
+#![cfg(feature = "mock")]
+
+use fomantic_ui::{
+    callbacks::CallbackRegistry,
+    events::EventStream,
+    jq::JQuery,
+    mock::{
+        MockBackend,
+        MockCall,
+    },
+    modules::{
+        FomanticModule,
+        InstanceGuard,
+    },
+};
+use futures_core::Stream;
+use std::{
+    cell::Cell,
+    pin::Pin,
+    rc::Rc,
+    task::{
+        Context,
+        Poll,
+        Waker,
+    },
+};
+use wasm_bindgen::JsValue;
+
+/// A module that is not wrapped by the crate.
+struct Accordion {
+    element: JQuery,
+    callbacks: CallbackRegistry,
+}
+
+impl FomanticModule for Accordion {
+    fn module_name(&self) -> &'static str {
+        "accordion"
+    }
+
+    fn element(&self) -> JQuery {
+        self.element.clone()
+    }
+
+    fn callbacks(&self) -> Option<&CallbackRegistry> {
+        Some(&self.callbacks)
+    }
+}
+
+/// Returns the next event of the stream if one has been sent.
+fn poll_event<E>(stream: &mut EventStream<E>) -> Option<E> {
+    let mut context = Context::from_waker(Waker::noop());
+    match Pin::new(stream).poll_next(&mut context) {
+        Poll::Ready(event) => event,
+        Poll::Pending => None,
+    }
+}
+
+#[test]
+fn records_calls_on_selections() {
+    let mock = MockBackend::install();
+    let menu = JQuery::select(".ui.menu");
+    menu.find(".item").eq(1).toggle_class("active", true);
+    menu.attr("role", "menu");
+    menu.behavior("dropdown", "refresh");
+
+    assert!(mock.has_class(".ui.menu .item:eq(1)", "active"));
+    assert!(!menu.find(".item").eq(0).has_class("active"));
+    assert_eq!(mock.attr(".ui.menu", "role").as_deref(), Some("menu"));
+    assert_eq!(mock.behaviors(".ui.menu"), ["refresh"]);
+    assert_eq!(mock.calls().len(), 3);
+
+    mock.clear();
+    assert!(mock.calls().is_empty());
+    assert!(mock.has_class(".ui.menu .item:eq(1)", "active"));
+}
+
+#[test]
+fn answers_stubbed_behaviors() {
+    let mock = MockBackend::install();
+    let modal = JQuery::select("#confirm");
+    assert!(!modal.behavior_bool("modal", "is active"));
+    mock.stub("modal", "is active", true);
+    assert!(modal.behavior_bool("modal", "is active"));
+
+    assert!(!modal.is_empty());
+    mock.set_length("#missing", 0);
+    assert!(JQuery::select("#missing").is_empty());
+}
+
+#[test]
+fn routes_module_behaviors() {
+    let mock = MockBackend::install();
+    let accordion = Accordion {
+        element: JQuery::select(".ui.accordion"),
+        callbacks: CallbackRegistry::new(),
+    };
+    accordion.initialize(&JsValue::UNDEFINED);
+    accordion.behavior("open");
+    accordion.destroy();
+
+    assert_eq!(mock.behaviors(".ui.accordion"), ["open", "destroy"]);
+    assert_eq!(mock.settings(".ui.accordion", "accordion"), Some(None));
+    assert!(matches!(
+        &mock.calls()[0],
+        MockCall::Init { module, .. } if module == "accordion"
+    ));
+}
+
+#[test]
+fn routes_events_to_handlers() {
+    let _mock = MockBackend::install();
+    let (sender, mut events) = EventStream::channel();
+    let accordion = Accordion {
+        element: JQuery::select(".ui.accordion"),
+        callbacks: CallbackRegistry::new(),
+    };
+    let opened = sender.clone();
+    accordion.callbacks.set("onOpen", move |_| {
+        opened.send("open");
+        JsValue::TRUE
+    });
+
+    accordion.callbacks.dispatch("onOpen", JsValue::UNDEFINED);
+    assert_eq!(poll_event(&mut events), Some("open"));
+    assert_eq!(poll_event(&mut events), None);
+
+    assert!(accordion.replace_handler("onOpen", move |_| {
+        sender.send("replaced");
+        JsValue::TRUE
+    }));
+    assert!(!accordion.replace_handler("onClose", |_| JsValue::TRUE));
+    accordion.callbacks.dispatch("onOpen", JsValue::UNDEFINED);
+    assert_eq!(poll_event(&mut events), Some("replaced"));
+    assert!(accordion
+        .callbacks
+        .dispatch("onClose", JsValue::UNDEFINED)
+        .is_none());
+}
+
+#[cfg(all(feature = "models", feature = "dropdown"))]
+#[test]
+fn records_serialized_settings() {
+    use fomantic_ui::{
+        modules::settings::{
+            DropdownSettings,
+            ModuleSettings,
+        },
+        style::TransitionName,
+    };
+
+    let mock = MockBackend::install();
+    let settings = DropdownSettings {
+        clearable: Some(true),
+        transition: Some(TransitionName::SlideDown),
+        ..Default::default()
+    };
+    settings.init(&JQuery::select("#country"));
+
+    assert_eq!(
+        mock.settings("#country", "dropdown"),
+        Some(Some(serde_json::json!({
+            "clearable": true,
+            "transition": "slide down",
+        })))
+    );
+}
+
+#[cfg(all(feature = "checkbox", feature = "dropdown", feature = "popup"))]
+#[test]
+fn initializes_modules_below_scope() {
+    let mock = MockBackend::install();
+    mock.set_length("#app .ui.accordion", 0);
+    mock.set_length("#app .ui.dropdown", 2);
+    mock.set_length("#app [data-content], [data-html]", 0);
+
+    let modules = fomantic_ui::init_all(&JQuery::select("#app"));
+    assert_eq!(modules.len(), 3);
+    assert!(fomantic_ui::init_all(&JQuery::select("#app")).is_empty());
+    assert_eq!(
+        mock.settings("#app .ui.checkbox:eq(0)", "checkbox"),
+        Some(None)
+    );
+    assert_eq!(
+        mock.settings("#app .ui.dropdown:eq(0)", "dropdown"),
+        Some(None)
+    );
+    assert_eq!(
+        mock.settings("#app .ui.dropdown:eq(1)", "dropdown"),
+        Some(None)
+    );
+    assert_eq!(mock.settings("#app .ui.accordion:eq(0)", "accordion"), None);
+
+    drop(modules);
+    assert_eq!(mock.behaviors("#app .ui.dropdown:eq(1)"), ["destroy"]);
+    assert_eq!(fomantic_ui::init_all(&JQuery::select("#app")).len(), 3);
+}
+
+#[cfg(all(feature = "checkbox", feature = "dropdown"))]
+#[test]
+fn keeps_modules_initialized_before_a_failure() {
+    use fomantic_ui::FomanticError;
+
+    let mock = MockBackend::install();
+    mock.set_length("#app .ui.accordion", 0);
+    mock.remove_module("dropdown");
+
+    let Err((modules, e)) = fomantic_ui::try_init_all(&JQuery::select("#app"))
+    else {
+        panic!("the dropdown module is missing");
+    };
+    assert!(matches!(e, FomanticError::ModuleMissing(m) if m == "dropdown"));
+    assert_eq!(modules.len(), 1);
+    assert!(
+        JQuery::select("#app .ui.checkbox:eq(0)").is_initialized("checkbox")
+    );
+    assert_eq!(mock.settings("#app .ui.dropdown:eq(0)", "dropdown"), None);
+
+    drop(modules);
+    assert_eq!(mock.behaviors("#app .ui.checkbox:eq(0)"), ["destroy"]);
+}
+
+/// Records on drop whether the dropdown was still initialized.
+struct Handler {
+    dropped_alive: Rc<Cell<Option<bool>>>,
+}
+
+impl Drop for Handler {
+    fn drop(&mut self) {
+        let alive = JQuery::select("#country").is_initialized("dropdown");
+        self.dropped_alive.set(Some(alive));
+    }
+}
+
+#[test]
+fn guard_destroys_instance_before_releasing_handlers() {
+    let mock = MockBackend::install();
+    let dropped_alive = Rc::new(Cell::new(None));
+    let handler = Handler {
+        dropped_alive: Rc::clone(&dropped_alive),
+    };
+    let callbacks = CallbackRegistry::new();
+    callbacks.set("onChange", move |_| {
+        let _ = &handler;
+        JsValue::UNDEFINED
+    });
+    let dropdown = JQuery::select("#country");
+    dropdown.init("dropdown", &JsValue::UNDEFINED);
+    let guard = InstanceGuard::new(dropdown, "dropdown").owning(callbacks);
+    assert!(guard.element().is_initialized("dropdown"));
+
+    drop(guard);
+    assert_eq!(dropped_alive.get(), Some(false));
+    assert_eq!(mock.behaviors("#country"), ["destroy"]);
+
+    // instances destroyed before are not destroyed again
+    let accordion = Accordion {
+        element: JQuery::select(".ui.accordion"),
+        callbacks: CallbackRegistry::new(),
+    };
+    accordion.initialize(&JsValue::UNDEFINED);
+    accordion.destroy();
+    drop(accordion.into_guard());
+    assert_eq!(mock.behaviors(".ui.accordion"), ["destroy"]);
+}
+
+#[cfg(feature = "toast")]
+#[test]
+fn marks_toast_containers_with_a_custom_class_as_live_region() {
+    use fomantic_ui::modules::toast::{
+        AriaLive,
+        ToastPosition,
+        ToastRegion,
+    };
+
+    let mock = MockBackend::install();
+    let region = ToastRegion::new(ToastPosition::BottomLeft)
+        .context("#chat")
+        .container_class("chat-toasts");
+    AriaLive::Assertive.apply(&region.container());
+
+    let selector = "#chat > .ui.toast-container.chat-toasts.bottom.left";
+    assert_eq!(
+        mock.attr(selector, "aria-live").as_deref(),
+        Some("assertive")
+    );
+    assert_eq!(mock.attr(selector, "role").as_deref(), Some("alert"));
+}
+
+#[test]
+fn replaces_handlers_of_guarded_modules() {
+    let _mock = MockBackend::install();
+    let opened = Rc::new(Cell::new(""));
+    let callbacks = CallbackRegistry::new();
+    callbacks.set("onOpen", {
+        let opened = Rc::clone(&opened);
+        move |_| {
+            opened.set("before");
+            JsValue::UNDEFINED
+        }
+    });
+    let accordion = Accordion {
+        element: JQuery::select(".ui.accordion"),
+        callbacks,
+    };
+    accordion.initialize(&JsValue::UNDEFINED);
+    let guard = accordion.into_guard();
+
+    assert!(guard.replace_handler("onOpen", {
+        let opened = Rc::clone(&opened);
+        move |_| {
+            opened.set("after");
+            JsValue::UNDEFINED
+        }
+    }));
+    assert!(!guard.replace_handler("onClose", |_| JsValue::UNDEFINED));
+    guard.callbacks.dispatch("onOpen", JsValue::UNDEFINED);
+    assert_eq!(opened.get(), "after");
+
+    guard.destroy();
+    assert!(!guard.callbacks.contains("onOpen"));
+}
+
+#[test]
+fn replaces_handlers_owned_by_a_guard() {
+    let mock = MockBackend::install();
+    let changed = Rc::new(Cell::new(false));
+    let callbacks = CallbackRegistry::new();
+    callbacks.set("onChange", |_| JsValue::UNDEFINED);
+    let dropdown = JQuery::select("#country");
+    dropdown.init("dropdown", &JsValue::UNDEFINED);
+    let guard = InstanceGuard::new(dropdown, "dropdown").owning(callbacks);
+
+    assert!(guard.replace_handler("onChange", {
+        let changed = Rc::clone(&changed);
+        move |_| {
+            changed.set(true);
+            JsValue::UNDEFINED
+        }
+    }));
+    assert!(!guard.replace_handler("onHide", |_| JsValue::UNDEFINED));
+    let callbacks = guard.callbacks().unwrap();
+    callbacks.dispatch("onChange", JsValue::UNDEFINED);
+    assert!(changed.get());
+
+    guard.destroy();
+    assert!(!guard.callbacks().unwrap().contains("onChange"));
+    assert_eq!(mock.behaviors("#country"), ["destroy"]);
+}
@@ -0,0 +1,305 @@
+//! Tests of the models, run with `cargo test --features models`.
+#![cfg(feature = "models")]
+
+use fomantic_ui::models::{
+    contains_ignore_case,
+    DataProvider,
+    Filterable,
+    Identifiable,
+    MemoryProvider,
+    PageRequest,
+    PageResponse,
+    RangeModel,
+    SelectionSet,
+    SelectionState,
+    SortState,
+};
+use std::{
+    future::Future,
+    pin::pin,
+    task::{
+        Context,
+        Poll,
+        Waker,
+    },
+};
+
+#[test]
+fn snaps_to_the_steps_at_both_bounds() {
+    let mut range = RangeModel::new(10, 50).with_step(10);
+    range.set_value(4);
+    assert_eq!(range.value(), 10);
+    range.set_value(14);
+    assert_eq!(range.value(), 10);
+    range.set_value(15);
+    assert_eq!(range.value(), 20);
+    range.set_value(46);
+    assert_eq!(range.value(), 50);
+    range.set_value(90);
+    assert_eq!(range.value(), 50);
+}
+
+#[test]
+fn counts_steps_from_min() {
+    let mut range = RangeModel::new(-3.0, 3.0).with_step(2.0);
+    range.set_value(0.2);
+    assert_eq!(range.value(), 1.0);
+    range.set_value(-2.5);
+    assert_eq!(range.value(), -3.0);
+}
+
+#[test]
+fn keeps_steps_that_do_not_divide_the_range_within_it() {
+    let mut range = RangeModel::new(0, 10).with_step(4);
+    range.set_value(5);
+    assert_eq!(range.value(), 4);
+    range.set_value(9);
+    assert_eq!(range.value(), 8);
+    // the closest step 12 is outside of the range
+    range.set_value(10);
+    assert_eq!(range.value(), 10);
+    range.set_value(20);
+    assert_eq!(range.value(), 10);
+}
+
+#[test]
+fn snaps_the_selection_when_setting_the_step() {
+    let mut range = RangeModel::pair(0, 100);
+    range.set_low(33);
+    range.set_high(67);
+    let range = range.with_step(25);
+    assert_eq!((range.low(), range.high()), (25, 75));
+}
+
+#[test]
+fn ignores_steps_that_are_not_positive() {
+    let mut range = RangeModel::new(0.0, 1.0).with_step(0.0);
+    assert_eq!(range.step(), None);
+    range.set_value(0.33);
+    assert_eq!(range.value(), 0.33);
+    assert_eq!(RangeModel::new(0, 10).with_step(-2).step(), None);
+}
+
+#[test]
+fn swaps_bounds() {
+    let mut range = RangeModel::new(100, 0);
+    assert_eq!((range.min(), range.max()), (0, 100));
+    assert_eq!(range.value(), 0);
+    range.set_value(150);
+    assert_eq!(range.value(), 100);
+
+    let pair = RangeModel::pair(5.0, -5.0);
+    assert_eq!((pair.low(), pair.high()), (-5.0, 5.0));
+}
+
+#[test]
+fn selects_the_only_value_of_equal_bounds() {
+    let mut range = RangeModel::new(7, 7).with_step(3);
+    range.set_value(0);
+    assert_eq!(range.value(), 7);
+    range.set_value(20);
+    assert_eq!(range.value(), 7);
+    assert!(range.contains(7) && !range.contains(8));
+    assert_eq!(range.fraction(7), 0.0);
+}
+
+#[test]
+fn keeps_the_pair_ordered() {
+    let mut range = RangeModel::pair(0, 10);
+    range.set_low(6);
+    range.set_high(4);
+    assert_eq!((range.low(), range.high()), (6, 6));
+    range.set_high(8);
+    range.set_low(9);
+    assert_eq!((range.low(), range.high()), (8, 8));
+
+    range.set_pair(9, 2);
+    assert_eq!((range.low(), range.high()), (2, 9));
+    assert!(range.contains(2) && range.contains(9) && !range.contains(10));
+}
+
+#[test]
+fn sets_single_values_from_pairs() {
+    let mut range = RangeModel::new(0, 10);
+    assert!(!range.is_pair());
+    range.set_pair(8, 3);
+    assert_eq!(range.value(), 3);
+    assert!(range.contains(0) && range.contains(3) && !range.contains(4));
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct City {
+    name: &'static str,
+    country: &'static str,
+}
+
+impl Filterable for City {
+    fn matches(&self, query: &str) -> bool {
+        contains_ignore_case(self.name, query)
+            || contains_ignore_case(self.country, query)
+    }
+}
+
+impl Identifiable for City {
+    type Id = &'static str;
+
+    fn id(&self) -> Self::Id {
+        self.name
+    }
+}
+
+fn cities() -> MemoryProvider<City> {
+    let city = |name, country| City { name, country };
+    MemoryProvider::new(vec![
+        city("Lyon", "France"),
+        city("Berlin", "Germany"),
+        city("Paris", "France"),
+        city("Hamburg", "Germany"),
+        city("Madrid", "Spain"),
+    ])
+    .comparator(0, |a, b| a.name.cmp(b.name))
+    .comparator(1, |a, b| a.country.cmp(b.country))
+}
+
+/// Polls the future of the provider, which is ready immediately.
+fn fetch(
+    provider: &MemoryProvider<City>,
+    page: usize,
+    size: usize,
+    sort: Vec<SortState>,
+    filter: &str,
+) -> PageResponse<City> {
+    let request = PageRequest {
+        page,
+        size,
+        sort,
+        filter: filter.to_string(),
+    };
+    let future = pin!(provider.fetch(request));
+    match future.poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(response) => response,
+        Poll::Pending => panic!("the memory provider must not wait"),
+    }
+}
+
+fn names(response: &PageResponse<City>) -> Vec<&'static str> {
+    response.items.iter().map(|city| city.name).collect()
+}
+
+#[test]
+fn serves_pages_of_the_items() {
+    let provider = cities();
+    let first = fetch(&provider, 0, 2, vec![], "");
+    assert_eq!(names(&first), ["Lyon", "Berlin"]);
+    assert_eq!(first.total, 5);
+    let last = fetch(&provider, 2, 2, vec![], "");
+    assert_eq!(names(&last), ["Madrid"]);
+    assert_eq!(last.total, 5);
+    let beyond = fetch(&provider, 3, 2, vec![], "");
+    assert!(beyond.items.is_empty());
+    assert_eq!(beyond.total, 5);
+}
+
+#[test]
+fn sorts_by_the_requested_columns_in_order() {
+    let provider = cities();
+    let response = fetch(&provider, 0, 5, vec![SortState::ascending(0)], "");
+    assert_eq!(
+        names(&response),
+        ["Berlin", "Hamburg", "Lyon", "Madrid", "Paris"]
+    );
+    let response = fetch(
+        &provider,
+        0,
+        5,
+        vec![SortState::descending(1), SortState::ascending(0)],
+        "",
+    );
+    assert_eq!(
+        names(&response),
+        ["Madrid", "Berlin", "Hamburg", "Lyon", "Paris"]
+    );
+    // the page is taken after sorting
+    let response = fetch(&provider, 1, 2, vec![SortState::descending(0)], "");
+    assert_eq!(names(&response), ["Lyon", "Hamburg"]);
+}
+
+#[test]
+fn skips_columns_without_comparator_when_sorting() {
+    let provider = cities();
+    let response = fetch(
+        &provider,
+        0,
+        5,
+        vec![SortState::ascending(7), SortState::ascending(1)],
+        "",
+    );
+    assert_eq!(
+        names(&response),
+        ["Lyon", "Paris", "Berlin", "Hamburg", "Madrid"]
+    );
+    let provider = cities().comparator(1, |a, b| b.country.cmp(a.country));
+    let response = fetch(&provider, 0, 2, vec![SortState::ascending(1)], "");
+    assert_eq!(names(&response), ["Madrid", "Berlin"]);
+}
+
+#[test]
+fn filters_before_paging() {
+    let provider = cities();
+    let response =
+        fetch(&provider, 0, 1, vec![SortState::ascending(0)], " france ");
+    assert_eq!(names(&response), ["Lyon"]);
+    assert_eq!(response.total, 2);
+    let response =
+        fetch(&provider, 1, 1, vec![SortState::ascending(0)], "FRANCE");
+    assert_eq!(names(&response), ["Paris"]);
+    let response = fetch(&provider, 0, 5, vec![], "rome");
+    assert!(response.items.is_empty());
+    assert_eq!(response.total, 0);
+}
+
+#[test]
+fn selects_and_deselects_keys() {
+    let mut selection = SelectionSet::new();
+    assert!(selection.is_empty());
+    assert!(selection.select(1));
+    assert!(!selection.select(1));
+    assert!(selection.toggle(2));
+    assert!(!selection.toggle(1));
+    assert!(!selection.is_selected(&1) && selection.is_selected(&2));
+    assert!(!selection.deselect(&1));
+    assert!(selection.deselect(&2));
+    assert!(selection.is_empty());
+}
+
+#[test]
+fn selects_groups_of_keys() {
+    let mut selection = SelectionSet::from_iter([9]);
+    let page = [1, 2, 3];
+    assert_eq!(selection.state_of(&page), SelectionState::None);
+    selection.select_all(page);
+    assert_eq!(selection.count(), 4);
+    assert_eq!(selection.count_of(&page), 3);
+    assert_eq!(selection.state_of(&page), SelectionState::All);
+    selection.deselect(&2);
+    assert_eq!(selection.state_of(&page), SelectionState::Some);
+    selection.deselect_all(&page);
+    assert_eq!(selection.iter().collect::<Vec<_>>(), [&9]);
+    assert_eq!(selection.state_of(&[]), SelectionState::None);
+    selection.clear();
+    assert!(selection.is_empty());
+}
+
+#[test]
+fn selects_items_by_their_id() {
+    let lyon = City {
+        name: "Lyon",
+        country: "France",
+    };
+    let mut selection = SelectionSet::new();
+    assert!(selection.toggle_item(&lyon));
+    assert!(selection.is_item_selected(&lyon));
+    assert!(selection.is_selected(&"Lyon"));
+    assert!(!selection.toggle_item(&lyon));
+    assert!(!selection.is_item_selected(&lyon));
+}